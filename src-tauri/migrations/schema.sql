@@ -86,6 +86,61 @@ CREATE TABLE IF NOT EXISTS reports (
     FOREIGN KEY(broadcast_id) REFERENCES broadcast_sessions(id) ON DELETE CASCADE
 );
 
+--------------------------------------------------------------------
+-- Table: stats_snapshots
+-- 역할: 실시간 통계(StatsMatrix)를 일정 간격으로 샘플링해 저장합니다.
+--       방송 종료 후 실시간 그래프를 다시 보거나 리포트에서 재사용합니다.
+--------------------------------------------------------------------
+CREATE TABLE IF NOT EXISTS stats_snapshots (
+    id              INTEGER PRIMARY KEY AUTOINCREMENT,
+    broadcast_id    INTEGER NOT NULL,
+    stats_type      TEXT NOT NULL, -- 'ChatPerMinute', 'ActiveViewer' 등 StatsMatrix의 type
+    payload         TEXT NOT NULL, -- StatsMatrix의 payload (JSON)
+    timestamp       DATETIME NOT NULL,
+    FOREIGN KEY(broadcast_id) REFERENCES broadcast_sessions(id) ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS idx_stats_snapshots_broadcast
+    ON stats_snapshots(broadcast_id, stats_type, timestamp);
+
+--------------------------------------------------------------------
+-- Table: chat_toxicity
+-- 역할: 유해 표현 분류 모델의 채팅별 점수를 저장합니다.
+--       채팅 로그는 버퍼를 거쳐 나중에 저장될 수 있으므로 외래 키 없이 id로 연결합니다.
+--------------------------------------------------------------------
+CREATE TABLE IF NOT EXISTS chat_toxicity (
+    chat_log_id     TEXT PRIMARY KEY, -- chat_logs.id
+    scores          TEXT NOT NULL, -- 라벨별 점수 (JSON)
+    max_label       TEXT NOT NULL,
+    max_score       REAL NOT NULL
+);
+
+--------------------------------------------------------------------
+-- Table: chat_sentiment
+-- 역할: 채팅별 감정 분석 결과를 저장합니다. (실시간 분석 및 과거 방송 소급 분석)
+--       chat_toxicity와 같이 외래 키 없이 id로 연결합니다.
+--------------------------------------------------------------------
+CREATE TABLE IF NOT EXISTS chat_sentiment (
+    chat_log_id     TEXT PRIMARY KEY, -- chat_logs.id
+    sentiment       TEXT NOT NULL, -- 'Positive', 'Negative', 'Neutral'
+    score           REAL NOT NULL, -- -2.0 ~ 2.0
+    method          TEXT NOT NULL, -- 'Model', 'Lexicon'
+    model_version   TEXT -- 모델로 분석한 경우 name@version
+);
+
+--------------------------------------------------------------------
+-- Table: sentiment_backfill_jobs
+-- 역할: 과거 방송의 감정 소급 분석 작업 상태를 저장합니다.
+--------------------------------------------------------------------
+CREATE TABLE IF NOT EXISTS sentiment_backfill_jobs (
+    broadcast_id        INTEGER PRIMARY KEY,
+    status              TEXT NOT NULL DEFAULT 'PENDING', -- PENDING, RUNNING, COMPLETED, FAILED, CANCELLED
+    progress_percentage REAL, -- 진행률 (0.0 ~ 100.0)
+    error_message       TEXT, -- 에러 메시지 (실패시)
+    updated_at          DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    FOREIGN KEY(broadcast_id) REFERENCES broadcast_sessions(id) ON DELETE CASCADE
+);
+
 --------------------------------------------------------------------
 -- Virtual Table: chat_logs_fts
 -- 역할: 채팅 메시지의 빠른 전문 검색(Full-Text Search)을 위한
@@ -1,10 +1,9 @@
 use tauri::State;
 
-use crate::models::events::{
-    EVENT_TYPE_CHAT, EVENT_TYPE_DONATION, EVENT_TYPE_KICK, EVENT_TYPE_METADATA_UPDATE,
-    EVENT_TYPE_MUTE,
+use crate::services::csv_exporter::csv_exporter::{
+    CSVExporter, CsvExportOptions, EXPORTABLE_EVENT_TYPES, EXPORT_EVENT_TYPE_ALL,
+    EXPORT_FORMAT_CSV, EXPORT_FORMAT_JSONL, EXPORT_FORMAT_PARQUET, EXPORT_FORMAT_SQLITE,
 };
-use crate::services::csv_exporter::csv_exporter::{CSVExporter, CsvExportOptions};
 use crate::state::AppState;
 
 #[tauri::command]
@@ -27,12 +26,41 @@ pub async fn export_events_to_csv(
 }
 
 #[tauri::command]
-pub async fn get_supported_event_types() -> Result<Vec<String>, String> {
+pub async fn export_events(
+    options: CsvExportOptions,
+    app_state: State<'_, AppState>,
+) -> Result<String, String> {
+    let csv_exporter = CSVExporter::new(app_state.db.as_ref().clone());
+
+    match csv_exporter.export_events(options).await {
+        Ok(result) => {
+            println!("Export successful: {}", result);
+            Ok(result)
+        }
+        Err(error) => {
+            println!("Export failed: {}", error);
+            Err(error)
+        }
+    }
+}
+
+#[tauri::command]
+pub async fn get_supported_export_formats() -> Result<Vec<String>, String> {
     Ok(vec![
-        EVENT_TYPE_CHAT.to_string(),
-        EVENT_TYPE_DONATION.to_string(),
-        EVENT_TYPE_MUTE.to_string(),
-        EVENT_TYPE_KICK.to_string(),
-        EVENT_TYPE_METADATA_UPDATE.to_string(),
+        EXPORT_FORMAT_CSV.to_string(),
+        EXPORT_FORMAT_JSONL.to_string(),
+        EXPORT_FORMAT_PARQUET.to_string(),
+        EXPORT_FORMAT_SQLITE.to_string(),
     ])
 }
+
+#[tauri::command]
+pub async fn get_supported_event_types() -> Result<Vec<String>, String> {
+    let mut event_types: Vec<String> = EXPORTABLE_EVENT_TYPES
+        .iter()
+        .map(|event_type| event_type.to_string())
+        .collect();
+    event_types.push(EXPORT_EVENT_TYPE_ALL.to_string());
+
+    Ok(event_types)
+}
@@ -3,8 +3,12 @@ pub mod channel;
 pub mod chat_history;
 pub mod csv_export;
 pub mod main_controller;
+pub mod report_export;
 pub mod reports;
 pub mod reset_app;
+pub mod sentiment_backfill;
 pub mod soop_api;
 pub mod splash;
+pub mod stats;
+pub mod subtitle_export;
 pub mod utils;
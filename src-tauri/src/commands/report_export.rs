@@ -0,0 +1,33 @@
+use tauri::State;
+
+use crate::services::report_exporter::exporter::{
+    ReportExportOptions, ReportExporter, REPORT_EXPORT_FORMAT_HTML, REPORT_EXPORT_FORMAT_MARKDOWN,
+};
+use crate::state::AppState;
+
+#[tauri::command]
+pub async fn export_report(
+    options: ReportExportOptions,
+    app_state: State<'_, AppState>,
+) -> Result<String, String> {
+    let report_exporter = ReportExporter::new(app_state.db.as_ref().clone());
+
+    match report_exporter.export_report(options).await {
+        Ok(result) => {
+            println!("Report Export successful: {}", result);
+            Ok(result)
+        }
+        Err(error) => {
+            println!("Report Export failed: {}", error);
+            Err(error)
+        }
+    }
+}
+
+#[tauri::command]
+pub async fn get_supported_report_formats() -> Result<Vec<String>, String> {
+    Ok(vec![
+        REPORT_EXPORT_FORMAT_HTML.to_string(),
+        REPORT_EXPORT_FORMAT_MARKDOWN.to_string(),
+    ])
+}
@@ -0,0 +1,91 @@
+use chrono::{DateTime, Utc};
+use tauri::State;
+
+use crate::{
+    services::{
+        ai::SentimentAnalyzer,
+        db::commands::{BroadcastSessionSearchFilters, PaginationParams, SentimentBackfillJobInfo},
+    },
+    state::AppState,
+};
+
+/// 기간 단위로 방송을 조회할 때의 페이지 크기
+const BACKFILL_SESSION_PAGE_SIZE: i64 = 100;
+
+#[tauri::command]
+pub async fn start_sentiment_backfill(
+    broadcast_id: i64,
+    state: State<'_, AppState>,
+) -> Result<(), String> {
+    state.sentiment_backfill_queue.enqueue(broadcast_id).await
+}
+
+/**
+ * 기간 내 종료된 방송을 모두 소급 분석 대기열에 추가합니다.
+ * 이미 대기 중인 방송은 건너뛰며, 추가된 방송 ID 목록을 반환합니다.
+ */
+#[tauri::command]
+pub async fn start_sentiment_backfill_range(
+    channel_id: Option<String>,
+    start_date: DateTime<Utc>,
+    end_date: DateTime<Utc>,
+    state: State<'_, AppState>,
+) -> Result<Vec<i64>, String> {
+    let filters = BroadcastSessionSearchFilters {
+        channel_id,
+        start_date: Some(start_date),
+        end_date: Some(end_date),
+    };
+
+    let mut queued = Vec::new();
+    let mut page = 1;
+
+    loop {
+        let result = state
+            .db
+            .search_broadcast_sessions(
+                filters.clone(),
+                PaginationParams {
+                    page,
+                    page_size: BACKFILL_SESSION_PAGE_SIZE,
+                },
+            )
+            .await?;
+
+        for session in result.broadcast_sessions {
+            // 진행 중인 방송은 실시간 분석 결과가 저장되므로 제외합니다.
+            if session.ended_at.is_none() || state.sentiment_backfill_queue.is_pending(session.id) {
+                continue;
+            }
+            state.sentiment_backfill_queue.enqueue(session.id).await?;
+            queued.push(session.id);
+        }
+
+        if page >= result.total_pages {
+            break;
+        }
+        page += 1;
+    }
+
+    Ok(queued)
+}
+
+#[tauri::command]
+pub async fn cancel_sentiment_backfill(
+    broadcast_id: i64,
+    state: State<'_, AppState>,
+) -> Result<(), String> {
+    state.sentiment_backfill_queue.cancel(broadcast_id)
+}
+
+#[tauri::command]
+pub async fn get_sentiment_backfill_status(
+    broadcast_id: i64,
+    state: State<'_, AppState>,
+) -> Result<Option<SentimentBackfillJobInfo>, String> {
+    let model_version = SentimentAnalyzer::global().map(SentimentAnalyzer::model_version);
+    state
+        .db
+        .get_sentiment_backfill_job(broadcast_id, model_version)
+        .await
+}
@@ -1,5 +1,11 @@
+use crate::services::addons::data_enrichment::reaction_rules::ReactionRuleStore;
+use crate::services::addons::data_enrichment::token_dictionary::TokenDictionaryStore;
+use crate::services::ai::model_manifest::{resolve_models, ModelManifest, USER_MODELS_DIR_NAME};
 use crate::services::db::commands::TargetUser;
 use crate::services::db::service::DBService;
+use crate::services::report_queue::ReportQueue;
+use crate::services::sentiment_backfill::SentimentBackfillQueue;
+use crate::services::stats::config::StatsConfigStore;
 use crate::state::AppState;
 use anyhow::{Context, Result as AnyhowResult};
 use std::path::PathBuf;
@@ -30,6 +36,19 @@ pub async fn connect_database(app_handle: AppHandle) -> Result<(), String> {
     let db_path = initialize_app_paths(&app_handle).map_err(|e| e.to_string())?;
     println!("Database path: {:?}", db_path);
 
+    // 통계 설정 불러오기 (실패 시 기본값 사용)
+    if let Some(app_dir) = db_path.parent() {
+        if let Err(e) = StatsConfigStore::load(app_dir.to_path_buf()) {
+            eprintln!("Failed to load stats config: {}", e);
+        }
+        if let Err(e) = ReactionRuleStore::load(app_dir.to_path_buf()) {
+            eprintln!("Failed to load reaction rules: {}", e);
+        }
+        if let Err(e) = TokenDictionaryStore::load(app_dir.to_path_buf()) {
+            eprintln!("Failed to load token dictionary: {}", e);
+        }
+    }
+
     // 데이터베이스 서비스 초기화
     let db_service = initialize_db_service(&db_path).await?;
 
@@ -81,14 +100,62 @@ pub async fn setup_ai(app_handle: AppHandle) -> Result<(), String> {
             .map_err(|e| format!("ONNX Runtime 초기화 실패: {}", e))?;
     }
 
-    // 감정분석 모델 초기화
-    crate::services::ai::SentimentAnalyzer::initialize(model_dir)
-        .map_err(|e| format!("감정분석 모델 초기화 실패: {}", e))?;
+    // 사용자가 앱 데이터 폴더(models/<종류>)에 넣은 모델이 있으면 번들 모델보다 우선합니다.
+    let user_models_dir = app_handle
+        .path()
+        .app_data_dir()
+        .ok()
+        .map(|dir| dir.join(USER_MODELS_DIR_NAME));
+
+    // 감정분석 모델 초기화 (불러온 모델이 없으면 사전 기반 분석을 사용)
+    let sentiment_models = resolve_models(
+        user_models_dir.as_deref(),
+        &model_dir,
+        "sentiment",
+        ModelManifest::bundled_sentiment(),
+    );
+    if !initialize_model(
+        sentiment_models,
+        "감정분석",
+        crate::services::ai::SentimentAnalyzer::initialize,
+    ) {
+        println!("감정분석 모델 없음 - 사전 기반 분석 사용");
+    }
+
+    // 유해 표현 분류 모델 초기화 (선택 모델이므로 없으면 건너뜀)
+    let toxicity_models = resolve_models(
+        user_models_dir.as_deref(),
+        &model_dir.join("toxicity"),
+        "toxicity",
+        ModelManifest::bundled_toxicity(),
+    );
+    if !initialize_model(
+        toxicity_models,
+        "유해 표현 분류",
+        crate::services::ai::ToxicityClassifier::initialize,
+    ) {
+        println!("유해 표현 분류 모델 없음 - 건너뜀");
+    }
 
     println!("AI 설정 완료");
     Ok(())
 }
 
+/// 후보 모델을 순서대로 불러옵니다. 불러오지 못한 모델은 로그를 남기고 다음 후보를 시도합니다.
+fn initialize_model(
+    candidates: Vec<(PathBuf, ModelManifest)>,
+    name: &str,
+    initialize: fn(PathBuf, ModelManifest) -> AnyhowResult<()>,
+) -> bool {
+    for (dir, manifest) in candidates {
+        match initialize(dir.clone(), manifest) {
+            Ok(()) => return true,
+            Err(e) => eprintln!("{} 모델 초기화 실패 ({}): {}", name, dir.display(), e),
+        }
+    }
+    false
+}
+
 #[tauri::command]
 pub async fn setup_app_state(app_handle: AppHandle) -> Result<(), String> {
     println!("App State 설정 시작...");
@@ -101,10 +168,19 @@ pub async fn setup_app_state(app_handle: AppHandle) -> Result<(), String> {
         crate::controllers::main_controller::MainController::new(),
     ));
 
+    // 리포트 생성 대기열 초기화
+    let report_queue = Arc::new(ReportQueue::new(app_handle.clone(), db_service.clone()));
+    let sentiment_backfill_queue = Arc::new(SentimentBackfillQueue::new(
+        app_handle.clone(),
+        db_service.clone(),
+    ));
+
     // AppState 생성
     let app_state = AppState {
         db: db_service,
         main_controller,
+        report_queue,
+        sentiment_backfill_queue,
     };
 
     // 글로벌 상태에 AppState 저장
@@ -0,0 +1,59 @@
+use tauri::State;
+
+use crate::{
+    services::{
+        addons::data_enrichment::{
+            reaction_rules::{ReactionRuleStore, ReactionRules},
+            token_dictionary::{TokenDictionary, TokenDictionaryStore},
+        },
+        db::commands::StatsSnapshotResult,
+        stats::config::{StatsConfig, StatsConfigStore},
+    },
+    state::AppState,
+};
+
+#[tauri::command]
+pub async fn get_stats_config() -> Result<StatsConfig, String> {
+    Ok(StatsConfigStore::global().get())
+}
+
+/// 설정을 저장하고, 실행 중인 통계 서비스에는 다음 사이클부터 반영됩니다.
+#[tauri::command]
+pub async fn update_stats_config(config: StatsConfig) -> Result<(), String> {
+    StatsConfigStore::global().update(config)
+}
+
+#[tauri::command]
+pub async fn get_reaction_rules() -> Result<ReactionRules, String> {
+    Ok(ReactionRuleStore::global().get())
+}
+
+/// 반응 분류 규칙을 저장하고, 다음 채팅부터 반영됩니다.
+#[tauri::command]
+pub async fn update_reaction_rules(rules: ReactionRules) -> Result<(), String> {
+    ReactionRuleStore::global().update(rules)
+}
+
+#[tauri::command]
+pub async fn get_token_dictionary() -> Result<TokenDictionary, String> {
+    Ok(TokenDictionaryStore::global().get())
+}
+
+/// 사용자 사전과 불용어를 저장하고, 다음 채팅과 리포트 생성부터 반영됩니다.
+#[tauri::command]
+pub async fn update_token_dictionary(dictionary: TokenDictionary) -> Result<(), String> {
+    // 사전을 다시 불러오는 작업은 무거우므로 블로킹 스레드에서 수행합니다.
+    tauri::async_runtime::spawn_blocking(move || TokenDictionaryStore::global().update(dictionary))
+        .await
+        .map_err(|e| e.to_string())?
+}
+
+/// 방송 중 저장된 실시간 통계를 시간순으로 조회합니다. (stats_type이 없으면 전체)
+#[tauri::command]
+pub async fn get_stats_snapshots(
+    broadcast_id: i64,
+    stats_type: Option<String>,
+    state: State<'_, AppState>,
+) -> Result<Vec<StatsSnapshotResult>, String> {
+    state.db.get_stats_snapshots(broadcast_id, stats_type).await
+}
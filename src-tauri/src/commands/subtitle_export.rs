@@ -0,0 +1,35 @@
+use tauri::State;
+
+use crate::services::subtitle_exporter::exporter::{
+    SubtitleExportOptions, SubtitleExporter, SUBTITLE_EXPORT_FORMAT_ASS,
+    SUBTITLE_EXPORT_FORMAT_SRT, SUBTITLE_EXPORT_FORMAT_VTT,
+};
+use crate::state::AppState;
+
+#[tauri::command]
+pub async fn export_subtitle(
+    options: SubtitleExportOptions,
+    app_state: State<'_, AppState>,
+) -> Result<String, String> {
+    let subtitle_exporter = SubtitleExporter::new(app_state.db.as_ref().clone());
+
+    match subtitle_exporter.export_subtitle(options).await {
+        Ok(result) => {
+            println!("Subtitle Export successful: {}", result);
+            Ok(result)
+        }
+        Err(error) => {
+            println!("Subtitle Export failed: {}", error);
+            Err(error)
+        }
+    }
+}
+
+#[tauri::command]
+pub async fn get_supported_subtitle_formats() -> Result<Vec<String>, String> {
+    Ok(vec![
+        SUBTITLE_EXPORT_FORMAT_SRT.to_string(),
+        SUBTITLE_EXPORT_FORMAT_ASS.to_string(),
+        SUBTITLE_EXPORT_FORMAT_VTT.to_string(),
+    ])
+}
@@ -1,6 +1,8 @@
 use tauri::{AppHandle, Manager};
 use tauri_plugin_opener::OpenerExt;
 
+use crate::services::ai::{model_manifest::LoadedModelInfo, SentimentAnalyzer, ToxicityClassifier};
+
 #[tauri::command]
 pub async fn open_app_data_dir(app_handle: AppHandle) -> Result<(), String> {
     let app_data_dir = app_handle
@@ -15,3 +17,12 @@ pub async fn open_app_data_dir(app_handle: AppHandle) -> Result<(), String> {
 
     Ok(())
 }
+
+/// 현재 로드된 AI 모델의 이름과 버전을 반환합니다. (모델이 없으면 빈 목록)
+#[tauri::command]
+pub async fn get_loaded_ai_models() -> Result<Vec<LoadedModelInfo>, String> {
+    let sentiment = SentimentAnalyzer::global().map(SentimentAnalyzer::info);
+    let toxicity = ToxicityClassifier::global().map(ToxicityClassifier::info);
+
+    Ok(sentiment.into_iter().chain(toxicity).collect())
+}
@@ -9,10 +9,18 @@ use crate::commands::{
     },
     channel::{delete_channel, get_channels, upsert_channel},
     chat_history::{get_user_log_dates, search_chat_logs, search_event_logs, search_user_logs},
-    csv_export::{export_events_to_csv, get_supported_event_types},
+    csv_export::{
+        export_events, export_events_to_csv, get_supported_event_types,
+        get_supported_export_formats,
+    },
     main_controller::{get_main_controller_context, start_main_controller, stop_main_controller},
+    report_export::{export_report, get_supported_report_formats},
     reports::{create_report, delete_report, get_report, get_report_status},
     reset_app::reset_app,
+    sentiment_backfill::{
+        cancel_sentiment_backfill, get_sentiment_backfill_status, start_sentiment_backfill,
+        start_sentiment_backfill_range,
+    },
     soop_api::{
         fetch_streamer_emoticon, fetch_streamer_live, fetch_streamer_station,
         fetch_streamer_vod_detail, fetch_streamer_vod_list,
@@ -21,7 +29,12 @@ use crate::commands::{
         add_target_user, check_for_updates, connect_database, get_target_users, remove_target_user,
         setup_ai, setup_app_state, show_main_window,
     },
-    utils::open_app_data_dir,
+    stats::{
+        get_reaction_rules, get_stats_config, get_stats_snapshots, get_token_dictionary,
+        update_reaction_rules, update_stats_config, update_token_dictionary,
+    },
+    subtitle_export::{export_subtitle, get_supported_subtitle_formats},
+    utils::{get_loaded_ai_models, open_app_data_dir},
 };
 
 mod commands;
@@ -127,13 +140,31 @@ pub fn run() {
             delete_report,
             get_report,
             get_report_status,
+            start_sentiment_backfill,
+            start_sentiment_backfill_range,
+            cancel_sentiment_backfill,
+            get_sentiment_backfill_status,
             setup_ai,
             setup_app_state,
             show_main_window,
             export_events_to_csv,
+            export_events,
+            get_supported_export_formats,
             get_supported_event_types,
+            export_report,
+            get_supported_report_formats,
+            export_subtitle,
+            get_supported_subtitle_formats,
+            get_stats_config,
+            update_stats_config,
+            get_stats_snapshots,
+            get_reaction_rules,
+            update_reaction_rules,
+            get_token_dictionary,
+            update_token_dictionary,
             reset_app,
-            open_app_data_dir
+            open_app_data_dir,
+            get_loaded_ai_models
         ])
         .run(tauri::generate_context!())
         .expect("error while running tauri application");
@@ -143,7 +143,7 @@ pub struct MissionEvent {
     pub mission_type: MissionType,
 }
 
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct MissionTotalEvent {
     pub id: uuid::Uuid,
@@ -153,7 +153,7 @@ pub struct MissionTotalEvent {
     pub amount: u32,
 }
 
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ChallengeMissionResultEvent {
     pub id: uuid::Uuid,
@@ -163,7 +163,7 @@ pub struct ChallengeMissionResultEvent {
     pub title: String,
 }
 
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct BattleMissionResultEvent {
     pub id: uuid::Uuid,
@@ -186,7 +186,7 @@ pub struct SubscribeEvent {
     pub renew: u32,
 }
 
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct NotificationEvent {
     pub id: uuid::Uuid,
     pub timestamp: DateTime<Utc>,
@@ -203,7 +203,7 @@ pub struct UserEvent {
     pub user: User,
 }
 
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct SimplifiedUserEvent {
     pub id: uuid::Uuid,
@@ -212,7 +212,7 @@ pub struct SimplifiedUserEvent {
     pub user_id: String,
 }
 
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct FreezeEvent {
     pub id: uuid::Uuid,
@@ -238,7 +238,7 @@ pub struct MuteEvent {
     pub superuser_type: String,
 }
 
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SlowEvent {
     pub id: uuid::Uuid,
     pub timestamp: DateTime<Utc>,
@@ -1,3 +1,4 @@
 pub mod connect;
 pub mod events;
 pub mod reports;
+pub mod sentiment_backfill;
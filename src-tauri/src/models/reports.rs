@@ -1,6 +1,9 @@
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use soup_sdk::chat::types::User;
+use std::collections::HashMap;
+
+use crate::services::ai::sentiment_analyzer::SentimentMethod;
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum ReportStatus {
@@ -55,7 +58,31 @@ pub struct ReportData {
     pub user_analysis: UserAnalysis,
     pub chat_analysis: ChatAnalysis,
     pub event_analysis: EventAnalysis,
+    #[serde(default)]
+    pub donation_analysis: DonationAnalysis,
     pub moderation_analysis: ModerationAnalysis,
+    /**
+     * 분석된 채팅이 없으면 None 입니다. 감정 분석 모델이 없으면 사전 기반으로 분석합니다. (method 참고)
+     */
+    #[serde(default)]
+    pub sentiment_analysis: Option<SentimentAnalysis>,
+    #[serde(default)]
+    pub emoticon_analysis: EmoticonAnalysis,
+    /**
+     * 유해 표현 분류 모델이 없거나 방송 중 분류된 채팅이 없으면 analyzed_count가 0입니다.
+     */
+    #[serde(default)]
+    pub toxicity_analysis: ToxicityAnalysis,
+    /**
+     * 채팅 단어로 나눈 방송 구간별 대화 주제입니다. 채팅이 없으면 segments가 비어 있습니다.
+     */
+    #[serde(default)]
+    pub topic_analysis: TopicAnalysis,
+    /**
+     * 주제 구간별 대표 채팅입니다. 네트워크 없이 채팅 로그만으로 추출합니다.
+     */
+    #[serde(default)]
+    pub chat_summary: ChatSummary,
     pub chunks: Vec<ReportChunk>,
 }
 
@@ -68,7 +95,15 @@ pub struct ReportChunk {
     pub chat: ChatVital,
     pub event: EventVital,
     pub moderation: ModerationVital,
+    #[serde(default)]
+    pub sentiment: Option<SentimentVital>,
     pub viewer_count: Option<u64>,
+    /**
+     * 실시간 통계에서 저장한 활성 시청자(최근 채팅 참여자) 수입니다.
+     * 통계 스냅샷이 없는 방송은 None 입니다.
+     */
+    #[serde(default)]
+    pub active_viewer_count: Option<u32>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -89,6 +124,11 @@ pub struct ChatAnalysis {
     pub total_count: u64,
     pub top_chatters: Vec<ChatterRank>,
     pub popular_words: Vec<WordCount>,
+    /**
+     * 반응 분류(웃음, 놀람 등)별 합계입니다. 리포트 생성 시점의 규칙 순서를 따릅니다.
+     */
+    #[serde(default)]
+    pub reactions: Vec<ReactionSummary>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -127,6 +167,11 @@ pub struct Matrix {
 pub struct ChatVital {
     pub total_count: usize,
     pub lol_score: usize,
+    /**
+     * 반응 분류 ID별 채팅 수입니다.
+     */
+    #[serde(default)]
+    pub reaction_counts: HashMap<String, u32>,
     #[serde(skip)]
     pub top_chatters: Vec<ChatterRank>,
     #[serde(skip)]
@@ -142,6 +187,100 @@ pub struct EventVital {
     pub mission_donation_amount: u64,
     pub subscribe_count: u32,
     pub subscribe_renew_count: u32,
+    #[serde(default)]
+    pub sticker_count: u32,
+    #[serde(default)]
+    pub sticker_amount: u64,
+    #[serde(default)]
+    pub gift_count: u32,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DonationAnalysis {
+    /**
+     * 별풍선, 애드벌룬, VOD 별풍선 등 후원 종류별 합계입니다.
+     */
+    pub by_type: Vec<DonationTypeBreakdown>,
+    pub sticker: StickerBreakdown,
+    pub gifts: Vec<GiftTypeBreakdown>,
+    /**
+     * 후원 1건당 금액 분포입니다. (미션 후원 포함)
+     */
+    pub amount_distribution: Vec<AmountBucket>,
+    pub donor_retention: DonorRetention,
+    pub fan_club_joins: Vec<FanClubJoin>,
+    /**
+     * 후원 직후 채팅으로 연결된 후원 메시지입니다.
+     */
+    pub donation_messages: Vec<DonationMessage>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DonationTypeBreakdown {
+    pub donation_type: String,
+    pub count: u64,
+    pub amount: u64,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StickerBreakdown {
+    pub count: u64,
+    pub amount: u64,
+    pub unique_sender_count: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GiftTypeBreakdown {
+    pub gift_type: String,
+    pub count: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AmountBucket {
+    pub min: u32,
+    /**
+     * None인 경우 상한이 없는 마지막 구간입니다.
+     */
+    pub max: Option<u32>,
+    pub count: u64,
+    pub amount: u64,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DonorRetention {
+    /**
+     * 같은 채널의 이전 방송에서 후원한 기록이 없는 후원자입니다.
+     */
+    pub first_time_count: u64,
+    pub first_time_amount: u64,
+    pub repeat_count: u64,
+    pub repeat_amount: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FanClubJoin {
+    pub user_id: String,
+    pub user_label: String,
+    pub ordinal: u32,
+    pub become_top_fan: bool,
+    pub timestamp: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DonationMessage {
+    pub user_id: String,
+    pub user_label: String,
+    pub amount: u32,
+    pub message: String,
+    pub timestamp: DateTime<Utc>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -151,6 +290,14 @@ pub struct ModerationVital {
     pub mute_histories: Vec<UserHistory>,
     pub kick_count: u32,
     pub kick_histories: Vec<UserHistory>,
+    #[serde(default)]
+    pub kick_cancel_count: u32,
+    #[serde(default)]
+    pub black_count: u32,
+    #[serde(default)]
+    pub freeze_count: u32,
+    #[serde(default)]
+    pub slow_count: u32,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -168,6 +315,94 @@ pub struct ModerationAnalysis {
     pub total_mute_histories: Vec<UserHistory>,
     pub total_kick_count: u32,
     pub total_kick_histories: Vec<UserHistory>,
+    #[serde(default)]
+    pub stats: ModerationStats,
+    /// 블랙(강제 퇴장 + 재입장 차단) 처리된 사용자 목록
+    #[serde(default)]
+    pub black_list: Vec<BlackHistory>,
+    #[serde(default)]
+    pub freeze_intervals: Vec<FreezeInterval>,
+    #[serde(default)]
+    pub slow_intervals: Vec<SlowInterval>,
+    /// 관리자(매니저/BJ)별 조치 횟수
+    #[serde(default)]
+    pub moderator_actions: Vec<ModeratorActionCount>,
+    /// 두 번 이상 조치를 받은 사용자
+    #[serde(default)]
+    pub repeat_offenders: Vec<RepeatOffender>,
+    /// 시간순 조치 기록과 조치 직전의 채팅
+    #[serde(default)]
+    pub actions: Vec<ModerationAction>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BlackHistory {
+    pub user_id: String,
+    /// 방송 중 채팅 기록이 있으면 마지막 닉네임, 없으면 None
+    pub user_label: Option<String>,
+    pub timestamp: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FreezeInterval {
+    pub started_at: DateTime<Utc>,
+    /// 방송 종료까지 해제되지 않았다면 None
+    pub ended_at: Option<DateTime<Utc>>,
+    pub duration_seconds: u64,
+    pub limit_subscription_month: u32,
+    pub limit_balloons: u32,
+    pub targets: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SlowInterval {
+    pub started_at: DateTime<Utc>,
+    /// 방송 종료까지 해제되지 않았다면 None
+    pub ended_at: Option<DateTime<Utc>>,
+    pub duration_seconds: u64,
+    /// 채팅 간 최소 간격(초)
+    pub slow_seconds: u32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ModeratorActionCount {
+    pub moderator: String,
+    pub mute_count: u32,
+    pub total_mute_seconds: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RepeatOffender {
+    pub user_id: String,
+    pub user_label: Option<String>,
+    pub mute_count: u32,
+    pub kick_count: u32,
+    pub black_count: u32,
+    pub total_count: u32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ModerationAction {
+    /// 'Mute', 'Kick', 'KickCancel', 'Black'
+    pub action_type: String,
+    pub user_id: String,
+    pub user_label: Option<String>,
+    pub by: Option<String>,
+    pub timestamp: DateTime<Utc>,
+    pub preceding_chats: Vec<PrecedingChat>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PrecedingChat {
+    pub message: String,
+    pub timestamp: DateTime<Utc>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -188,6 +423,19 @@ pub struct AnalysisSummary {
     pub total: f32,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReactionSummary {
+    pub id: String,
+    pub label: String,
+    pub total_count: u32,
+    /**
+     * 가장 많았던 청크의 채팅 수와 시각입니다.
+     */
+    pub peak_count: u32,
+    pub peak_timestamp: Option<DateTime<Utc>>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ChatterRank {
@@ -195,13 +443,63 @@ pub struct ChatterRank {
     pub message_count: u64,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SentimentVital {
+    pub positive_count: u32,
+    pub negative_count: u32,
+    pub neutral_count: u32,
+    pub positive_ratio: f64,
+    pub negative_ratio: f64,
+    pub neutral_ratio: f64,
+    pub average_score: f64,
+    #[serde(default)]
+    pub method: SentimentMethod,
+    /**
+     * 청크에서 점수가 가장 높은/낮은 채팅입니다. 저장된 리포트에서 감정 하이라이트를 다시 만들 때 사용합니다.
+     */
+    #[serde(default)]
+    pub most_positive_message: Option<String>,
+    #[serde(default)]
+    pub most_negative_message: Option<String>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct SentimentAnalysis {
     pub positive_ratio: f64,
     pub negative_ratio: f64,
     pub neutral_ratio: f64,
+    pub average_score: f64,
+    /**
+     * Positive, Negative, Neutral 중 하나입니다.
+     */
     pub overall_sentiment: String,
+    /**
+     * 감정 분석 방식입니다. 사전 기반(Lexicon) 결과는 모델 결과보다 정확도가 낮습니다.
+     */
+    #[serde(default)]
+    pub method: SentimentMethod,
+    /**
+     * 모델로 분석한 경우 해당 모델 버전(name@version)입니다.
+     */
+    #[serde(default)]
+    pub model_version: Option<String>,
+    pub most_positive_moments: Vec<SentimentMoment>,
+    pub most_negative_moments: Vec<SentimentMoment>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SentimentMoment {
+    pub timestamp: DateTime<Utc>,
+    pub relative_timestamp: String,
+    pub average_score: f64,
+    pub analyzed_count: u32,
+    /**
+     * 해당 구간에서 점수가 가장 극단적인 채팅입니다.
+     */
+    pub message: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -220,12 +518,182 @@ pub struct DonatorRank {
     pub mission_amount: u64,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct ModerationStats {
     pub kicks: u64,
+    pub kick_cancels: u64,
     pub mutes: u64,
     pub bans: u64,
     pub freezes: u64,
+    pub slows: u64,
+    /// 얼리기가 유지된 총 시간(초)
+    pub freeze_seconds: u64,
+    /// 저속 모드가 유지된 총 시간(초)
+    pub slow_seconds: u64,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EmoticonAnalysis {
+    pub total_signature_count: u64,
+    pub total_ogq_count: u64,
+    pub signature_emoticons: Vec<SignatureEmoticonUsage>,
+    pub ogq_stickers: Vec<OgqStickerUsage>,
+    /// 스트리머의 시그니처 이모티콘 중 방송에서 한 번도 쓰이지 않은 이모티콘
+    pub unused_signature_emoticons: Vec<SignatureEmoticonUsage>,
+    /// 스트리머 이모티콘 목록을 불러오지 못하면 false이며, 이때 시그니처 이모티콘은 집계되지 않습니다.
+    pub catalog_loaded: bool,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ToxicityAnalysis {
+    /**
+     * 방송 중 유해 표현 분류를 거친 채팅 수입니다. (부하가 높으면 표본만 분류됩니다)
+     */
+    pub analyzed_count: u64,
+    pub flagged_count: u64,
+    /**
+     * 리포트 생성 시점의 경고 기준입니다.
+     */
+    pub threshold: f32,
+    pub labels: Vec<ToxicityLabelSummary>,
+    /// 점수가 높은 순서의 경고 채팅
+    pub top_messages: Vec<ToxicMessage>,
+    /// 경고 채팅이 많은 사용자 순위
+    pub top_users: Vec<ToxicUserRank>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ToxicityLabelSummary {
+    pub label: String,
+    /**
+     * 이 라벨 점수가 경고 기준 이상인 채팅 수입니다.
+     */
+    pub flagged_count: u64,
+    pub max_score: f32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ToxicMessage {
+    pub user: User,
+    pub message: String,
+    pub label: String,
+    pub score: f32,
+    pub timestamp: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ToxicUserRank {
+    pub user: User,
+    pub flagged_count: u64,
+    pub max_score: f32,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TopicAnalysis {
+    /**
+     * 주제를 나누는 최소 단위 구간의 길이(초)입니다.
+     */
+    pub window_seconds: u32,
+    /// 시간순으로 정렬된 주제 구간
+    pub segments: Vec<TopicSegment>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TopicSegment {
+    /**
+     * 같은 주제로 묶인 구간은 같은 번호를 가집니다. (방송 후반에 같은 주제로 돌아온 경우)
+     */
+    pub topic_id: u32,
+    pub start_time: DateTime<Utc>,
+    pub end_time: DateTime<Utc>,
+    /**
+     * 방송 시작 기준 구간 시작/종료 시점(초)입니다.
+     */
+    pub start_offset_seconds: u64,
+    pub end_offset_seconds: u64,
+    pub chat_count: u32,
+    /// TF-IDF 점수가 높은 순서의 핵심 단어
+    pub key_terms: Vec<TopicTerm>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TopicTerm {
+    pub word: String,
+    pub score: f64,
+    /**
+     * 구간에서 이 단어가 포함된 채팅 수입니다.
+     */
+    pub count: u32,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChatSummary {
+    /// 주제 구간과 같은 범위로 나눈 요약 (대표 채팅이 없는 구간은 제외합니다)
+    pub segments: Vec<ChatSummarySegment>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChatSummarySegment {
+    pub start_time: DateTime<Utc>,
+    pub end_time: DateTime<Utc>,
+    pub start_offset_seconds: u64,
+    pub end_offset_seconds: u64,
+    pub chat_count: u32,
+    /// 점수가 높은 순서의 대표 채팅
+    pub messages: Vec<SummaryMessage>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SummaryMessage {
+    pub user: User,
+    pub message: String,
+    pub timestamp: DateTime<Utc>,
+    /**
+     * 구간 내 다른 채팅과의 단어 겹침(중심성)에 뒤따른 반응 수를 반영한 점수입니다. (0 ~ 1로 정규화)
+     */
+    pub score: f64,
+    /**
+     * 같은 내용으로 판단되어 합쳐진 채팅을 보낸 사용자 수입니다. (같은 사용자의 도배는 한 번만 셉니다)
+     */
+    pub user_count: u32,
+    /**
+     * 채팅 후 일정 시간 안에 다른 사용자가 보낸 반응 채팅 수입니다.
+     */
+    pub reaction_count: u32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SignatureEmoticonUsage {
+    pub title: String,
+    pub tier: u8,
+    pub image_url: String,
+    pub count: u64,
+    pub user_count: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OgqStickerUsage {
+    pub id: String,
+    pub number: String,
+    pub ext: String,
+    pub version: String,
+    pub image_url: String,
+    pub count: u64,
+    pub user_count: u64,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
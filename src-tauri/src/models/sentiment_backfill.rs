@@ -0,0 +1,40 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub enum SentimentBackfillStatus {
+    #[serde(rename = "PENDING")]
+    Pending,
+    #[serde(rename = "RUNNING")]
+    Running,
+    #[serde(rename = "COMPLETED")]
+    Completed,
+    #[serde(rename = "FAILED")]
+    Failed,
+    #[serde(rename = "CANCELLED")]
+    Cancelled,
+}
+
+impl From<String> for SentimentBackfillStatus {
+    fn from(s: String) -> Self {
+        match s.as_str() {
+            "PENDING" => SentimentBackfillStatus::Pending,
+            "RUNNING" => SentimentBackfillStatus::Running,
+            "COMPLETED" => SentimentBackfillStatus::Completed,
+            "FAILED" => SentimentBackfillStatus::Failed,
+            "CANCELLED" => SentimentBackfillStatus::Cancelled,
+            _ => SentimentBackfillStatus::Pending,
+        }
+    }
+}
+
+impl From<SentimentBackfillStatus> for String {
+    fn from(status: SentimentBackfillStatus) -> Self {
+        match status {
+            SentimentBackfillStatus::Pending => "PENDING".to_string(),
+            SentimentBackfillStatus::Running => "RUNNING".to_string(),
+            SentimentBackfillStatus::Completed => "COMPLETED".to_string(),
+            SentimentBackfillStatus::Failed => "FAILED".to_string(),
+            SentimentBackfillStatus::Cancelled => "CANCELLED".to_string(),
+        }
+    }
+}
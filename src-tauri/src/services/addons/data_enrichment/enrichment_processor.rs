@@ -1,35 +1,66 @@
+use soup_sdk::SoopHttpClient;
+use std::collections::HashMap;
+use std::sync::{Arc, RwLock};
+use std::time::{Duration, Instant};
+
+use super::reaction_rules::{ReactionRuleStore, LAUGH_REACTION_ID};
 use super::token_analyzer::TokenAnalyzer;
 use crate::models::events::*;
-use crate::services::ai::SentimentAnalyzer;
+use crate::services::ai::{lexicon_sentiment, SentimentAnalyzer};
 use crate::services::stats::models::*;
+use crate::util::emoticon::{SignatureEmoticonCatalog, SignatureEmoticonInfo};
+
+/// 시그니처 이모티콘 목록을 불러오지 못했을 때 다시 시도하기까지 기다리는 시간
+const EMOTICON_CATALOG_RETRY_DELAY: Duration = Duration::from_secs(60);
+
+/// 채널별 시그니처 이모티콘 목록의 불러오기 상태
+enum EmoticonCatalogState {
+    Loading,
+    Loaded(Arc<SignatureEmoticonCatalog>),
+    /** 실패한 시각 (EMOTICON_CATALOG_RETRY_DELAY가 지나면 다시 불러옵니다) */
+    Failed(Instant),
+}
+
+type EmoticonCatalogCache = Arc<RwLock<HashMap<String, EmoticonCatalogState>>>;
 
 pub struct EnrichmentProcessor {
     token_analyzer: &'static TokenAnalyzer,
-    sentiment_analyzer: Option<&'static SentimentAnalyzer>,
+    emoticon_catalogs: EmoticonCatalogCache,
 }
 
 impl EnrichmentProcessor {
     pub fn new() -> Self {
         Self {
             token_analyzer: TokenAnalyzer::global(),
-            sentiment_analyzer: SentimentAnalyzer::global(),
+            emoticon_catalogs: Arc::new(RwLock::new(HashMap::new())),
         }
     }
 
     pub async fn process_chat_event(&self, event: &ChatEvent) -> Option<EnrichedChatData> {
-        // 만약 매니저 채팅이거나 BJ라면 생략
-        if event.user.status.is_bj || event.user.status.is_manager {
+        let reaction_rules = ReactionRuleStore::global().get();
+
+        // 설정에 따라 BJ, 매니저 채팅은 생략
+        if reaction_rules.should_skip(event.user.status.is_bj, event.user.status.is_manager) {
             return None;
         }
 
-        let tokens = self.token_analyzer.tokenize(&event.comment);
+        let tokens = self
+            .token_analyzer
+            .tokenize_for_channel(&event.channel_id, &event.comment);
         let word_count = tokens.len();
         let character_count = event.comment.chars().count();
 
-        // 감정 분석 수행 (실시간이므로 성능 문제 없음)
-        let sentiment_analysis = self
-            .sentiment_analyzer
-            .and_then(|analyzer| analyzer.analyze(&event.comment).ok());
+        let reactions = reaction_rules.detect(&event.comment);
+        let is_lol = reactions.iter().any(|id| id == LAUGH_REACTION_ID);
+
+        // 감정 분석 모델이 있으면 InferenceWorker에서 채우고, 없으면 사전 기반으로 바로 분석합니다.
+        let sentiment_analysis = match SentimentAnalyzer::global() {
+            Some(_) => None,
+            None => Some(lexicon_sentiment::analyze(
+                self.token_analyzer,
+                &event.comment,
+            )),
+        };
 
         Some(EnrichedChatData {
             event_id: event.id,
@@ -40,8 +71,14 @@ impl EnrichmentProcessor {
             tokens,
             word_count,
             character_count,
-            is_lol: self.is_lol(&event.comment),
+            is_lol,
+            reactions,
             sentiment_analysis,
+            // 유해 표현 분류는 InferenceWorker에서 비동기로 채웁니다.
+            toxicity: None,
+            signature_emoticons: self
+                .extract_signature_emoticons(&event.channel_id, &event.comment),
+            ogq: event.ogq.clone(),
         })
     }
 
@@ -52,7 +89,10 @@ impl EnrichmentProcessor {
         let message_tokens = event
             .message
             .as_ref()
-            .map(|msg| self.token_analyzer.tokenize(msg))
+            .map(|msg| {
+                self.token_analyzer
+                    .tokenize_for_channel(&event.channel_id, msg)
+            })
             .unwrap_or_default();
 
         Some(EnrichedDonationData {
@@ -67,7 +107,77 @@ impl EnrichmentProcessor {
         })
     }
 
-    fn is_lol(&self, message: &str) -> bool {
-        message.contains("ㅋ")
+    /**
+     * 채널의 시그니처 이모티콘 목록과 대조해 메시지 속 이모티콘을 찾습니다.
+     * 목록은 채널별로 처음 한 번만 불러오며, 불러오기 전까지는 빈 목록을 반환합니다.
+     * 불러오기에 실패하면 일정 시간 뒤 다음 채팅에서 다시 시도합니다.
+     */
+    fn extract_signature_emoticons(
+        &self,
+        channel_id: &str,
+        message: &str,
+    ) -> Vec<SignatureEmoticonInfo> {
+        if let Ok(catalogs) = self.emoticon_catalogs.read() {
+            match catalogs.get(channel_id) {
+                Some(EmoticonCatalogState::Loaded(catalog)) => {
+                    return catalog
+                        .extract(message)
+                        .iter()
+                        .filter_map(|title| catalog.get(title).cloned())
+                        .collect();
+                }
+                Some(state) if !Self::should_load(state) => return Vec::new(),
+                _ => {}
+            }
+        }
+
+        self.load_emoticon_catalog(channel_id.to_string());
+        Vec::new()
+    }
+
+    fn load_emoticon_catalog(&self, channel_id: String) {
+        match self.emoticon_catalogs.write() {
+            Ok(mut catalogs) => {
+                if !catalogs.get(&channel_id).map_or(true, Self::should_load) {
+                    return;
+                }
+                catalogs.insert(channel_id.clone(), EmoticonCatalogState::Loading);
+            }
+            Err(_) => return,
+        }
+
+        let catalogs = self.emoticon_catalogs.clone();
+        tauri::async_runtime::spawn(async move {
+            match SoopHttpClient::new()
+                .get_signature_emoticon(&channel_id)
+                .await
+            {
+                Ok(data) => {
+                    let catalog = SignatureEmoticonCatalog::new(&channel_id, &data);
+                    if let Ok(mut catalogs) = catalogs.write() {
+                        catalogs
+                            .insert(channel_id, EmoticonCatalogState::Loaded(Arc::new(catalog)));
+                    }
+                }
+                Err(e) => {
+                    eprintln!(
+                        "[EnrichmentProcessor] Failed to load signature emoticons for {}: {}",
+                        channel_id, e
+                    );
+                    if let Ok(mut catalogs) = catalogs.write() {
+                        catalogs.insert(channel_id, EmoticonCatalogState::Failed(Instant::now()));
+                    }
+                }
+            }
+        });
+    }
+
+    fn should_load(state: &EmoticonCatalogState) -> bool {
+        match state {
+            EmoticonCatalogState::Failed(failed_at) => {
+                failed_at.elapsed() >= EMOTICON_CATALOG_RETRY_DELAY
+            }
+            _ => false,
+        }
     }
 }
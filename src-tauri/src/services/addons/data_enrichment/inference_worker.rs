@@ -0,0 +1,264 @@
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+use std::sync::{Arc, RwLock};
+use tokio::sync::mpsc;
+use tokio::task::JoinHandle;
+use tokio::time::{timeout_at, Duration, Instant};
+
+use crate::services::ai::{SentimentAnalyzer, ToxicityClassifier};
+use crate::services::db::{
+    commands::{ChatSentimentData, ChatToxicityData},
+    service::DBService,
+};
+use crate::services::stats::core_stats_service::CoreStatsService;
+use crate::services::stats::models::EnrichedChatData;
+
+/// 분석을 기다리는 채팅의 최대 수
+const QUEUE_CAPACITY: usize = 512;
+
+/// 분석 대기 중인 채팅이 이만큼 쌓이면 일부 채팅만 표본으로 분석합니다.
+const SAMPLING_THRESHOLD: usize = QUEUE_CAPACITY / 2;
+
+/// 표본 분석 중에는 N개 중 1개만 분석합니다.
+const SAMPLING_RATE: u64 = 4;
+
+/// 한 번의 추론에 묶을 최대 분석 대상 채팅 수
+const MAX_BATCH_SIZE: usize = 32;
+
+/// 배치를 채우기 위해 다음 채팅을 기다리는 최대 시간
+const BATCH_WAIT_MS: u64 = 30;
+
+/// 건너뛴 채팅이 이만큼 쌓일 때마다 로그를 남깁니다.
+const SKIP_LOG_INTERVAL: u64 = 1000;
+
+/// 감정 분석, 유해 표현 점수를 저장할 DB (애드온 컨텍스트에서 전달받습니다)
+type SharedDB = Arc<RwLock<Option<Arc<DBService>>>>;
+
+/// 워커에서 사용할 모델 (둘 중 하나만 있을 수도 있습니다)
+#[derive(Clone, Copy)]
+struct Models {
+    sentiment: Option<&'static SentimentAnalyzer>,
+    toxicity: Option<&'static ToxicityClassifier>,
+}
+
+/// 대기열 항목. 분석하지 않는 채팅도 순서를 지키기 위해 같은 대기열을 거칩니다.
+struct Job {
+    chat: EnrichedChatData,
+    analyze: bool,
+}
+
+/**
+ * 채팅 감정 분석과 유해 표현 분류를 애드온 처리 흐름과 분리해 별도 작업에서 배치로 수행합니다.
+ * 분석이 끝난 채팅은 결과를 채워 통계 서비스에 기록하고, 유해 표현 점수는 DB에 저장합니다.
+ * 분석이 밀리면 표본만 분석하고, 가득 차면 분석 없이 기록합니다.
+ * 통계 윈도우는 시간순 입력을 가정하므로 분석하지 않는 채팅도 앞선 채팅 뒤에 순서대로 기록합니다.
+ */
+pub struct InferenceWorker {
+    sender: mpsc::UnboundedSender<Job>,
+    core_stats_service: Arc<CoreStatsService>,
+    db: SharedDB,
+    /** 대기열에 있거나 분석 중인 분석 대상 채팅 수 */
+    pending: Arc<AtomicUsize>,
+    received: AtomicU64,
+    skipped: AtomicU64,
+    task: JoinHandle<()>,
+}
+
+impl InferenceWorker {
+    /// 사용할 모델이 하나도 없으면 (AI 기능 비활성화) None을 반환합니다.
+    pub fn start(core_stats_service: Arc<CoreStatsService>) -> Option<Self> {
+        let models = Models {
+            sentiment: SentimentAnalyzer::global(),
+            toxicity: ToxicityClassifier::global(),
+        };
+        if models.sentiment.is_none() && models.toxicity.is_none() {
+            return None;
+        }
+
+        let db: SharedDB = Arc::new(RwLock::new(None));
+        let pending = Arc::new(AtomicUsize::new(0));
+        let (sender, receiver) = mpsc::unbounded_channel();
+        let task = tokio::spawn(Self::run(
+            models,
+            receiver,
+            core_stats_service.clone(),
+            db.clone(),
+            pending.clone(),
+        ));
+
+        Some(Self {
+            sender,
+            core_stats_service,
+            db,
+            pending,
+            received: AtomicU64::new(0),
+            skipped: AtomicU64::new(0),
+            task,
+        })
+    }
+
+    /// 감정 분석, 유해 표현 점수를 저장할 DB를 지정합니다.
+    pub fn bind_db(&self, db: &Arc<DBService>) {
+        if let Ok(mut guard) = self.db.write() {
+            if guard.is_none() {
+                *guard = Some(db.clone());
+            }
+        }
+    }
+
+    /// 채팅을 대기열에 넣습니다. 분석이 밀려 있으면 분석하지 않고 순서만 지켜 기록합니다.
+    pub async fn submit(&self, chat: EnrichedChatData) {
+        let sequence = self.received.fetch_add(1, Ordering::Relaxed);
+        let pending = self.pending.load(Ordering::Relaxed);
+
+        let analyze = pending < QUEUE_CAPACITY
+            && (pending < SAMPLING_THRESHOLD || sequence % SAMPLING_RATE == 0);
+        if analyze {
+            self.pending.fetch_add(1, Ordering::Relaxed);
+        } else {
+            let skipped = self.skipped.fetch_add(1, Ordering::Relaxed) + 1;
+            if skipped % SKIP_LOG_INTERVAL == 0 {
+                eprintln!(
+                    "[InferenceWorker] Overloaded - {} chats recorded without analysis",
+                    skipped
+                );
+            }
+        }
+
+        // 워커가 종료된 경우에만 실패하므로 바로 기록합니다.
+        if let Err(mpsc::error::SendError(job)) = self.sender.send(Job { chat, analyze }) {
+            self.core_stats_service.record_chat_data(job.chat).await;
+        }
+    }
+
+    pub fn stop(&self) {
+        self.task.abort();
+    }
+
+    async fn run(
+        models: Models,
+        mut receiver: mpsc::UnboundedReceiver<Job>,
+        core_stats_service: Arc<CoreStatsService>,
+        db: SharedDB,
+        pending: Arc<AtomicUsize>,
+    ) {
+        while let Some(first) = receiver.recv().await {
+            // 분석하지 않는 채팅은 앞선 배치가 모두 기록된 뒤이므로 바로 기록합니다.
+            if !first.analyze {
+                core_stats_service.record_chat_data(first.chat).await;
+                continue;
+            }
+
+            let mut jobs = vec![first];
+            let mut analyze_count = 1;
+
+            // 첫 채팅부터 최대 BATCH_WAIT_MS 동안 기다리며 배치를 채웁니다.
+            // 사이에 들어온 분석하지 않는 채팅도 순서를 지키기 위해 함께 모읍니다.
+            let deadline = Instant::now() + Duration::from_millis(BATCH_WAIT_MS);
+            while analyze_count < MAX_BATCH_SIZE {
+                match timeout_at(deadline, receiver.recv()).await {
+                    Ok(Some(job)) => {
+                        if job.analyze {
+                            analyze_count += 1;
+                        }
+                        jobs.push(job);
+                    }
+                    _ => break,
+                }
+            }
+
+            // 추론은 CPU 작업이므로 blocking 스레드에서 수행
+            let analyzed = tokio::task::spawn_blocking(move || {
+                let mut targets: Vec<&mut EnrichedChatData> = jobs
+                    .iter_mut()
+                    .filter(|job| job.analyze)
+                    .map(|job| &mut job.chat)
+                    .collect();
+                Self::analyze_batch(models, &mut targets);
+                jobs
+            })
+            .await;
+            pending.fetch_sub(analyze_count, Ordering::Relaxed);
+
+            let batch: Vec<EnrichedChatData> = match analyzed {
+                Ok(jobs) => jobs.into_iter().map(|job| job.chat).collect(),
+                Err(e) => {
+                    eprintln!("[InferenceWorker] Analysis task failed: {}", e);
+                    continue;
+                }
+            };
+
+            let toxicity_items: Vec<ChatToxicityData> = batch
+                .iter()
+                .filter_map(|chat| {
+                    chat.toxicity.as_ref().map(|result| ChatToxicityData {
+                        chat_log_id: chat.event_id.to_string(),
+                        result: result.clone(),
+                    })
+                })
+                .collect();
+            let model_version = models.sentiment.map(SentimentAnalyzer::model_version);
+            let sentiment_items: Vec<ChatSentimentData> = batch
+                .iter()
+                .filter_map(|chat| {
+                    chat.sentiment_analysis
+                        .as_ref()
+                        .map(|result| ChatSentimentData {
+                            chat_log_id: chat.event_id.to_string(),
+                            result: result.clone(),
+                            model_version: model_version.clone(),
+                        })
+                })
+                .collect();
+
+            for chat in batch {
+                core_stats_service.record_chat_data(chat).await;
+            }
+
+            let db = db.read().ok().and_then(|guard| guard.clone());
+            let Some(db) = db else {
+                continue;
+            };
+            if !toxicity_items.is_empty() {
+                if let Err(e) = db.insert_chat_toxicity(toxicity_items).await {
+                    eprintln!("[InferenceWorker] Failed to save toxicity scores: {}", e);
+                }
+            }
+            if !sentiment_items.is_empty() {
+                if let Err(e) = db.insert_chat_sentiment(sentiment_items).await {
+                    eprintln!("[InferenceWorker] Failed to save sentiment scores: {}", e);
+                }
+            }
+        }
+    }
+
+    fn analyze_batch(models: Models, batch: &mut [&mut EnrichedChatData]) {
+        let messages: Vec<&str> = batch.iter().map(|c| c.message.as_str()).collect();
+
+        let sentiments = models
+            .sentiment
+            .map(|analyzer| analyzer.analyze_batch(&messages));
+        let toxicities = models
+            .toxicity
+            .map(|classifier| classifier.classify_batch(&messages));
+
+        match sentiments {
+            Some(Ok(results)) => {
+                for (chat, result) in batch.iter_mut().zip(results) {
+                    chat.sentiment_analysis = Some(result);
+                }
+            }
+            Some(Err(e)) => eprintln!("[InferenceWorker] Sentiment analysis failed: {}", e),
+            None => {}
+        }
+
+        match toxicities {
+            Some(Ok(results)) => {
+                for (chat, result) in batch.iter_mut().zip(results) {
+                    chat.toxicity = Some(result);
+                }
+            }
+            Some(Err(e)) => eprintln!("[InferenceWorker] Toxicity classification failed: {}", e),
+            None => {}
+        }
+    }
+}
@@ -7,26 +7,45 @@ use crate::services::stats::core_stats_service::CoreStatsService;
 use tauri::AppHandle;
 
 pub mod enrichment_processor;
+pub mod inference_worker;
+pub mod reaction_rules;
 pub mod token_analyzer;
+pub mod token_dictionary;
 
 use enrichment_processor::EnrichmentProcessor;
+use inference_worker::InferenceWorker;
 
 pub struct DataEnrichmentAddon {
     core_stats_service: Arc<CoreStatsService>,
     enrichment_processor: EnrichmentProcessor,
+    // AI 기능이 비활성화된 경우 None
+    inference_worker: Option<InferenceWorker>,
 }
 
 impl DataEnrichmentAddon {
     pub fn new(app_handle: AppHandle) -> Self {
-        let core_service = Arc::new(CoreStatsService::new(2, app_handle));
+        let core_service = Arc::new(CoreStatsService::new(app_handle));
 
         core_service.clone().start_stats_scheduler();
 
         Self {
-            core_stats_service: core_service, // 2분 윈도우
+            inference_worker: InferenceWorker::start(core_service.clone()),
+            core_stats_service: core_service,
             enrichment_processor: EnrichmentProcessor::new(),
         }
     }
+
+    /// 방송 정보가 있으면 통계 스냅샷을 해당 방송에 저장하도록 연결합니다.
+    async fn bind_broadcast(&self, ctx: &AddonContext) {
+        if let Some(worker) = &self.inference_worker {
+            worker.bind_db(&ctx.db);
+        }
+        if let Some(metadata) = &ctx.broadcast_metadata {
+            self.core_stats_service
+                .bind_broadcast(ctx.db.clone(), metadata)
+                .await;
+        }
+    }
 }
 
 #[async_trait]
@@ -35,15 +54,22 @@ impl Addon for DataEnrichmentAddon {
         "data-enrichment"
     }
 
-    async fn on_chat(&self, _ctx: &AddonContext, event: &ChatEvent) {
+    async fn on_chat(&self, ctx: &AddonContext, event: &ChatEvent) {
+        self.bind_broadcast(ctx).await;
         if let Some(enriched_data) = self.enrichment_processor.process_chat_event(event).await {
-            self.core_stats_service
-                .record_chat_data(enriched_data)
-                .await;
+            match &self.inference_worker {
+                Some(worker) => worker.submit(enriched_data).await,
+                None => {
+                    self.core_stats_service
+                        .record_chat_data(enriched_data)
+                        .await
+                }
+            }
         }
     }
 
-    async fn on_donation(&self, _ctx: &AddonContext, event: &DonationEvent) {
+    async fn on_donation(&self, ctx: &AddonContext, event: &DonationEvent) {
+        self.bind_broadcast(ctx).await;
         if let Some(enriched_data) = self
             .enrichment_processor
             .process_donation_event(event)
@@ -57,6 +83,9 @@ impl Addon for DataEnrichmentAddon {
 
     async fn stop(&self, _ctx: &AddonContext) {
         println!("[DataEnrichmentAddon] Stopping addon - cleaning up resources");
+        if let Some(worker) = &self.inference_worker {
+            worker.stop();
+        }
         self.core_stats_service.stop().await;
         println!("[DataEnrichmentAddon] Cleanup completed");
     }
@@ -0,0 +1,238 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::path::PathBuf;
+use std::sync::{OnceLock, RwLock};
+
+const REACTION_RULES_FILE_NAME: &str = "reaction_rules.json";
+
+/// LOL 통계(is_lol)에 사용하는 반응 분류 ID
+pub const LAUGH_REACTION_ID: &str = "laugh";
+
+static GLOBAL_REACTION_RULES: OnceLock<ReactionRuleStore> = OnceLock::new();
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReactionRules {
+    /**
+     * BJ 채팅을 통계에서 제외할지 여부입니다.
+     */
+    pub skip_bj: bool,
+    /**
+     * 매니저 채팅을 통계에서 제외할지 여부입니다.
+     */
+    pub skip_manager: bool,
+    pub categories: Vec<ReactionCategory>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReactionCategory {
+    pub id: String,
+    pub label: String,
+    pub enabled: bool,
+    /**
+     * 하나라도 일치하면 해당 반응으로 분류합니다.
+     */
+    pub patterns: Vec<ReactionPattern>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReactionPattern {
+    pub text: String,
+    /**
+     * text가 연속으로 최소 몇 번 반복되어야 하는지를 의미합니다. (ㅋ, 2 => ㅋㅋ 이상)
+     */
+    pub min_repeat: u32,
+}
+
+impl ReactionPattern {
+    fn new(text: &str, min_repeat: u32) -> Self {
+        Self {
+            text: text.to_string(),
+            min_repeat,
+        }
+    }
+
+    fn matches(&self, message: &str) -> bool {
+        max_repeat(message, &self.text.to_lowercase()) >= self.min_repeat
+    }
+}
+
+impl Default for ReactionRules {
+    fn default() -> Self {
+        let category = |id: &str, label: &str, patterns: Vec<ReactionPattern>| ReactionCategory {
+            id: id.to_string(),
+            label: label.to_string(),
+            enabled: true,
+            patterns,
+        };
+
+        Self {
+            skip_bj: true,
+            skip_manager: true,
+            categories: vec![
+                category(
+                    LAUGH_REACTION_ID,
+                    "웃음",
+                    vec![
+                        ReactionPattern::new("ㅋ", 2),
+                        ReactionPattern::new("ㅎ", 3),
+                        ReactionPattern::new("lol", 1),
+                    ],
+                ),
+                category(
+                    "surprise",
+                    "놀람",
+                    vec![
+                        ReactionPattern::new("ㄷ", 2),
+                        ReactionPattern::new("헐", 1),
+                        ReactionPattern::new("?", 3),
+                    ],
+                ),
+                category(
+                    "sad",
+                    "슬픔",
+                    vec![ReactionPattern::new("ㅠ", 2), ReactionPattern::new("ㅜ", 2)],
+                ),
+                category(
+                    "hype",
+                    "환호",
+                    vec![
+                        ReactionPattern::new("!", 3),
+                        ReactionPattern::new("와", 2),
+                        ReactionPattern::new("가즈아", 1),
+                    ],
+                ),
+            ],
+        }
+    }
+}
+
+impl ReactionRules {
+    pub fn validate(&self) -> Result<(), String> {
+        let mut ids = HashSet::new();
+
+        for category in &self.categories {
+            if category.id.trim().is_empty() {
+                return Err("Reaction category id must not be empty".to_string());
+            }
+            if !ids.insert(category.id.as_str()) {
+                return Err(format!("Duplicated reaction category: {}", category.id));
+            }
+            for pattern in &category.patterns {
+                if pattern.text.is_empty() {
+                    return Err(format!("Empty reaction pattern: {}", category.id));
+                }
+                if pattern.min_repeat == 0 {
+                    return Err(format!("min_repeat must be positive: {}", category.id));
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// 통계에서 제외할 사용자인지 확인합니다.
+    pub fn should_skip(&self, is_bj: bool, is_manager: bool) -> bool {
+        (self.skip_bj && is_bj) || (self.skip_manager && is_manager)
+    }
+
+    /// 메시지가 해당하는 반응 분류 ID 목록을 반환합니다.
+    pub fn detect(&self, message: &str) -> Vec<String> {
+        let message = message.to_lowercase();
+
+        self.categories
+            .iter()
+            .filter(|category| category.enabled)
+            .filter(|category| category.patterns.iter().any(|p| p.matches(&message)))
+            .map(|category| category.id.clone())
+            .collect()
+    }
+
+    pub fn enabled_categories(&self) -> impl Iterator<Item = &ReactionCategory> {
+        self.categories.iter().filter(|category| category.enabled)
+    }
+}
+
+/// message 안에서 pattern이 끊기지 않고 연속으로 반복된 최대 횟수를 셉니다.
+fn max_repeat(message: &str, pattern: &str) -> u32 {
+    let mut best = 0;
+    let mut current = 0;
+    let mut rest = message;
+
+    while let Some(index) = rest.find(pattern) {
+        if index == 0 {
+            current += 1;
+        } else {
+            current = 1;
+        }
+        best = best.max(current);
+        rest = &rest[index + pattern.len()..];
+    }
+
+    best
+}
+
+/**
+ * 반응 분류 규칙을 보관하고 파일로 저장합니다.
+ * 변경된 규칙은 다음 채팅부터 바로 적용됩니다.
+ */
+pub struct ReactionRuleStore {
+    path: RwLock<Option<PathBuf>>,
+    rules: RwLock<ReactionRules>,
+}
+
+impl ReactionRuleStore {
+    pub fn global() -> &'static ReactionRuleStore {
+        GLOBAL_REACTION_RULES.get_or_init(|| ReactionRuleStore {
+            path: RwLock::new(None),
+            rules: RwLock::new(ReactionRules::default()),
+        })
+    }
+
+    /// 앱 데이터 폴더에서 저장된 규칙을 불러옵니다.
+    pub fn load(app_dir: PathBuf) -> Result<(), String> {
+        let store = Self::global();
+        let path = app_dir.join(REACTION_RULES_FILE_NAME);
+
+        if path.exists() {
+            let content = std::fs::read_to_string(&path)
+                .map_err(|e| format!("Failed to read reaction rules: {}", e))?;
+            match serde_json::from_str::<ReactionRules>(&content) {
+                Ok(rules) if rules.validate().is_ok() => store.replace(rules),
+                _ => eprintln!("[ReactionRuleStore] Invalid reaction rules, using defaults"),
+            }
+        }
+
+        *store.path.write().map_err(|e| e.to_string())? = Some(path);
+        Ok(())
+    }
+
+    pub fn get(&self) -> ReactionRules {
+        self.rules
+            .read()
+            .map(|rules| rules.clone())
+            .unwrap_or_default()
+    }
+
+    pub fn update(&self, rules: ReactionRules) -> Result<(), String> {
+        rules.validate()?;
+
+        if let Some(path) = self.path.read().map_err(|e| e.to_string())?.as_ref() {
+            let content = serde_json::to_string_pretty(&rules)
+                .map_err(|e| format!("Failed to serialize reaction rules: {}", e))?;
+            std::fs::write(path, content)
+                .map_err(|e| format!("Failed to write reaction rules: {}", e))?;
+        }
+
+        self.replace(rules);
+        Ok(())
+    }
+
+    fn replace(&self, rules: ReactionRules) {
+        if let Ok(mut guard) = self.rules.write() {
+            *guard = rules;
+        }
+    }
+}
@@ -1,12 +1,37 @@
 use lazy_static::lazy_static;
-use lindera::dictionary::load_dictionary_from_kind;
+use lindera::dictionary::{
+    load_dictionary_from_kind, load_user_dictionary_from_csv, DictionaryKind,
+};
 use lindera::mode::Mode;
 use lindera::segmenter::Segmenter;
 use lindera::tokenizer::Tokenizer;
 use lindera::LinderaResult;
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
+use std::sync::RwLock;
+
+use super::token_dictionary::TokenDictionary;
 
 pub struct TokenAnalyzer {
-    tokenizer: Tokenizer,
+    tokenizer: RwLock<Tokenizer>,
+    stopwords: RwLock<Stopwords>,
+}
+
+/// 집계에서 제외할 단어 (전체 채널 공통 + 채널별)
+#[derive(Default)]
+struct Stopwords {
+    common: HashSet<String>,
+    channels: HashMap<String, HashSet<String>>,
+}
+
+impl Stopwords {
+    fn contains(&self, channel_id: &str, word: &str) -> bool {
+        self.common.contains(word)
+            || self
+                .channels
+                .get(channel_id)
+                .is_some_and(|words| words.contains(word))
+    }
 }
 
 lazy_static! {
@@ -16,19 +41,59 @@ lazy_static! {
 
 impl TokenAnalyzer {
     pub fn new() -> LinderaResult<Self> {
-        let dic = load_dictionary_from_kind(lindera::dictionary::DictionaryKind::KoDic)?;
-        let segmenter = Segmenter::new(Mode::Normal, dic, None);
-        let tokenizer = Tokenizer::new(segmenter);
-
-        Ok(Self { tokenizer })
+        Ok(Self {
+            tokenizer: RwLock::new(Self::create_tokenizer(None)?),
+            stopwords: RwLock::new(Stopwords::default()),
+        })
     }
 
     pub fn global() -> &'static TokenAnalyzer {
         &GLOBAL_TOKEN_ANALYZER
     }
 
+    /// user_dictionary_csv가 있으면 ko-dic에 사용자 사전을 더해 분석기를 생성합니다.
+    fn create_tokenizer(user_dictionary_csv: Option<&Path>) -> LinderaResult<Tokenizer> {
+        let dic = load_dictionary_from_kind(DictionaryKind::KoDic)?;
+        let user_dic = user_dictionary_csv
+            .map(|path| load_user_dictionary_from_csv(DictionaryKind::KoDic, path))
+            .transpose()?;
+        let segmenter = Segmenter::new(Mode::Normal, dic, user_dic);
+
+        Ok(Tokenizer::new(segmenter))
+    }
+
+    /**
+     * 사용자 사전과 불용어를 반영합니다.
+     * 사용자 사전은 user_dictionary_csv에 저장된 파일을 다시 불러와 분석기를 교체합니다.
+     */
+    pub fn apply_dictionary(
+        &self,
+        dictionary: &TokenDictionary,
+        user_dictionary_csv: Option<&Path>,
+    ) -> Result<(), String> {
+        let tokenizer = Self::create_tokenizer(user_dictionary_csv)
+            .map_err(|e| format!("Failed to load user dictionary: {}", e))?;
+        *self.tokenizer.write().map_err(|e| e.to_string())? = tokenizer;
+
+        let to_set = |words: &[String]| words.iter().cloned().collect::<HashSet<_>>();
+        *self.stopwords.write().map_err(|e| e.to_string())? = Stopwords {
+            common: to_set(&dictionary.stopwords),
+            channels: dictionary
+                .channel_stopwords
+                .iter()
+                .map(|(channel_id, words)| (channel_id.clone(), to_set(words)))
+                .collect(),
+        };
+
+        Ok(())
+    }
+
     pub fn tokenize(&self, text: &str) -> Vec<String> {
-        match self.tokenizer.tokenize(text) {
+        let Ok(tokenizer) = self.tokenizer.read() else {
+            return Vec::new();
+        };
+
+        match tokenizer.tokenize(text) {
             Ok(mut tokens) => {
                 tokens
                     .iter_mut()
@@ -49,4 +114,37 @@ impl TokenAnalyzer {
             Err(_) => Vec::new(),
         }
     }
+
+    /// 모든 형태소를 (표면형, 품사) 쌍으로 반환합니다. (사전 기반 감정 분석용)
+    pub fn morphemes(&self, text: &str) -> Vec<(String, String)> {
+        let Ok(tokenizer) = self.tokenizer.read() else {
+            return Vec::new();
+        };
+
+        match tokenizer.tokenize(text) {
+            Ok(mut tokens) => tokens
+                .iter_mut()
+                .map(|token| {
+                    let pos = token
+                        .details()
+                        .first()
+                        .map(|pos| pos.to_string())
+                        .unwrap_or_default();
+                    (token.text.to_string(), pos)
+                })
+                .collect(),
+            Err(_) => Vec::new(),
+        }
+    }
+
+    /// 채널의 불용어를 제외하고 명사를 추출합니다. (단어 집계용)
+    pub fn tokenize_for_channel(&self, channel_id: &str, text: &str) -> Vec<String> {
+        let mut tokens = self.tokenize(text);
+
+        if let Ok(stopwords) = self.stopwords.read() {
+            tokens.retain(|token| !stopwords.contains(channel_id, token));
+        }
+
+        tokens
+    }
 }
@@ -0,0 +1,159 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::{OnceLock, RwLock};
+
+use super::token_analyzer::TokenAnalyzer;
+
+const TOKEN_DICTIONARY_FILE_NAME: &str = "token_dictionary.json";
+
+/// lindera에 전달할 사용자 사전 파일 (단어,품사,읽기 형식의 CSV)
+const USER_DICTIONARY_CSV_FILE_NAME: &str = "user_dictionary.csv";
+
+/// 사용자 사전 단어는 고유명사로 등록합니다.
+const USER_WORD_POS: &str = "NNP";
+
+static GLOBAL_TOKEN_DICTIONARY: OnceLock<TokenDictionaryStore> = OnceLock::new();
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TokenDictionary {
+    /**
+     * 하나의 단어로 인식할 단어 목록입니다. (스트리머 이름, 게임 용어, 밈 등)
+     */
+    pub user_words: Vec<String>,
+    /**
+     * 모든 채널의 단어 집계에서 제외할 단어입니다.
+     */
+    pub stopwords: Vec<String>,
+    /**
+     * 채널 ID별로 단어 집계에서 제외할 단어입니다.
+     */
+    pub channel_stopwords: HashMap<String, Vec<String>>,
+}
+
+impl TokenDictionary {
+    /// 앞뒤 공백은 normalize에서 제거되므로 잘라낸 단어를 검사합니다.
+    pub fn validate(&self) -> Result<(), String> {
+        for word in self.user_words.iter().map(|word| word.trim()) {
+            if word.is_empty() {
+                return Err("User dictionary word must not be empty".to_string());
+            }
+            if word.contains([',', '"', '\n', '\r']) || word.chars().any(char::is_whitespace) {
+                return Err(format!("Invalid user dictionary word: {}", word));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// 공백을 정리하고 중복을 제거합니다.
+    fn normalize(mut self) -> Self {
+        let normalize_words = |words: &mut Vec<String>| {
+            let mut seen = std::collections::HashSet::new();
+            words.retain_mut(|word| {
+                *word = word.trim().to_string();
+                !word.is_empty() && seen.insert(word.clone())
+            });
+        };
+
+        normalize_words(&mut self.user_words);
+        normalize_words(&mut self.stopwords);
+        for words in self.channel_stopwords.values_mut() {
+            normalize_words(words);
+        }
+        self.channel_stopwords.retain(|_, words| !words.is_empty());
+
+        self
+    }
+
+    fn to_user_dictionary_csv(&self) -> String {
+        self.user_words
+            .iter()
+            .map(|word| format!("{},{},{}\n", word, USER_WORD_POS, word))
+            .collect()
+    }
+}
+
+/**
+ * 사용자 사전과 불용어를 보관하고 파일로 저장합니다.
+ * 변경된 내용은 TokenAnalyzer에 바로 반영되어 다음 채팅부터 적용됩니다.
+ */
+pub struct TokenDictionaryStore {
+    app_dir: RwLock<Option<PathBuf>>,
+    dictionary: RwLock<TokenDictionary>,
+}
+
+impl TokenDictionaryStore {
+    pub fn global() -> &'static TokenDictionaryStore {
+        GLOBAL_TOKEN_DICTIONARY.get_or_init(|| TokenDictionaryStore {
+            app_dir: RwLock::new(None),
+            dictionary: RwLock::new(TokenDictionary::default()),
+        })
+    }
+
+    /// 앱 데이터 폴더에서 저장된 사전을 불러와 TokenAnalyzer에 반영합니다.
+    pub fn load(app_dir: PathBuf) -> Result<(), String> {
+        let store = Self::global();
+        let path = app_dir.join(TOKEN_DICTIONARY_FILE_NAME);
+        *store.app_dir.write().map_err(|e| e.to_string())? = Some(app_dir.clone());
+
+        if !path.exists() {
+            return Ok(());
+        }
+
+        let content = std::fs::read_to_string(&path)
+            .map_err(|e| format!("Failed to read token dictionary: {}", e))?;
+        match serde_json::from_str::<TokenDictionary>(&content) {
+            Ok(dictionary) if dictionary.validate().is_ok() => {
+                store.apply(&app_dir, dictionary.normalize())
+            }
+            _ => {
+                eprintln!("[TokenDictionaryStore] Invalid token dictionary, using defaults");
+                Ok(())
+            }
+        }
+    }
+
+    pub fn get(&self) -> TokenDictionary {
+        self.dictionary
+            .read()
+            .map(|dictionary| dictionary.clone())
+            .unwrap_or_default()
+    }
+
+    pub fn update(&self, dictionary: TokenDictionary) -> Result<(), String> {
+        dictionary.validate()?;
+        let dictionary = dictionary.normalize();
+
+        let app_dir = self.app_dir.read().map_err(|e| e.to_string())?.clone();
+        let Some(app_dir) = app_dir else {
+            return Err("Token dictionary is not loaded".to_string());
+        };
+
+        let content = serde_json::to_string_pretty(&dictionary)
+            .map_err(|e| format!("Failed to serialize token dictionary: {}", e))?;
+        std::fs::write(app_dir.join(TOKEN_DICTIONARY_FILE_NAME), content)
+            .map_err(|e| format!("Failed to write token dictionary: {}", e))?;
+
+        self.apply(&app_dir, dictionary)
+    }
+
+    fn apply(&self, app_dir: &Path, dictionary: TokenDictionary) -> Result<(), String> {
+        let csv_path = app_dir.join(USER_DICTIONARY_CSV_FILE_NAME);
+        let user_dictionary_csv = if dictionary.user_words.is_empty() {
+            None
+        } else {
+            std::fs::write(&csv_path, dictionary.to_user_dictionary_csv())
+                .map_err(|e| format!("Failed to write user dictionary: {}", e))?;
+            Some(csv_path.as_path())
+        };
+
+        TokenAnalyzer::global().apply_dictionary(&dictionary, user_dictionary_csv)?;
+
+        if let Ok(mut guard) = self.dictionary.write() {
+            *guard = dictionary;
+        }
+        Ok(())
+    }
+}
@@ -1,8 +1,9 @@
 use chrono::{DateTime, Utc};
 use std::sync::Arc;
+use tauri::Manager;
 use tokio::sync::Mutex;
 
-use crate::services::addons::interface::AddonContext;
+use crate::{services::addons::interface::AddonContext, state::AppState};
 
 pub struct SessionManager {
     current_broadcast_id: Arc<Mutex<Option<i64>>>,
@@ -81,8 +82,21 @@ impl SessionManager {
 
         if let Some(broadcast_id) = broadcast_id {
             ctx.db.end_broadcast_session(broadcast_id, ended_at).await?;
-            let mut broadcast_id_guard = self.current_broadcast_id.lock().await;
-            *broadcast_id_guard = None;
+            {
+                let mut broadcast_id_guard = self.current_broadcast_id.lock().await;
+                *broadcast_id_guard = None;
+            }
+
+            // 방송이 끝나면 리포트를 자동으로 생성합니다.
+            if let Some(app_state) = ctx.app_handle.try_state::<AppState>() {
+                if let Err(e) = app_state
+                    .report_queue
+                    .enqueue_on_session_end(broadcast_id)
+                    .await
+                {
+                    eprintln!("[SessionManager] Failed to queue report: {}", e);
+                }
+            }
         }
 
         Ok(())
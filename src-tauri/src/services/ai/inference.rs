@@ -0,0 +1,97 @@
+use ndarray::{Array, Array2, Ix2};
+use ort::session::Session;
+use ort::value::Value;
+use tokenizers::Tokenizer;
+
+use super::model_manifest::ModelInputs;
+
+/**
+ * 여러 텍스트를 BERT 계열 모델 입력으로 변환합니다.
+ * 가장 긴 문장 길이에 맞춰 패딩하고, 패딩 위치는 attention mask를 0으로 둡니다.
+ * 입력 텐서 이름은 모델 manifest를 따릅니다.
+ */
+pub fn encode_batch(
+    tokenizer: &Tokenizer,
+    texts: &[&str],
+    names: &ModelInputs,
+) -> Result<Vec<(String, Value)>, String> {
+    let encodings = tokenizer
+        .encode_batch(texts.to_vec(), true)
+        .map_err(|e| format!("Failed to encode text: {}", e))?;
+    let pad_id = tokenizer
+        .get_padding()
+        .map(|padding| padding.pad_id)
+        .or_else(|| tokenizer.token_to_id("[PAD]"))
+        .unwrap_or(0);
+
+    let batch_size = encodings.len();
+    let seq_len = encodings
+        .iter()
+        .map(|encoding| encoding.get_ids().len())
+        .max()
+        .unwrap_or(0);
+
+    let mut ids = vec![pad_id as i64; batch_size * seq_len];
+    let mut mask = vec![0i64; batch_size * seq_len];
+    let mut type_ids = vec![0i64; batch_size * seq_len];
+    for (row, encoding) in encodings.iter().enumerate() {
+        let offset = row * seq_len;
+        for (col, ((&id, &attention), &type_id)) in encoding
+            .get_ids()
+            .iter()
+            .zip(encoding.get_attention_mask())
+            .zip(encoding.get_type_ids())
+            .enumerate()
+        {
+            ids[offset + col] = id as i64;
+            mask[offset + col] = attention as i64;
+            type_ids[offset + col] = type_id as i64;
+        }
+    }
+
+    let to_value = |data: Vec<i64>| -> Result<Value, String> {
+        let array = Array::from_vec(data)
+            .into_shape_with_order((batch_size, seq_len))
+            .map_err(|e| e.to_string())?;
+        Value::from_array(array)
+            .map(|tensor| tensor.into_dyn())
+            .map_err(|e| e.to_string())
+    };
+
+    let mut inputs = vec![
+        (names.input_ids.clone(), to_value(ids)?),
+        (names.attention_mask.clone(), to_value(mask)?),
+    ];
+    if let Some(name) = &names.token_type_ids {
+        inputs.push((name.clone(), to_value(type_ids)?));
+    }
+
+    Ok(inputs)
+}
+
+/// 모델을 실행하고 output 텐서를 (배치 크기, 라벨 수) 형태의 logits로 반환합니다.
+pub fn run_logits(
+    session: &mut Session,
+    inputs: Vec<(String, Value)>,
+    output: &str,
+) -> Result<Array2<f32>, String> {
+    // 모델 추론 실행
+    let outputs = session
+        .run(inputs)
+        .map_err(|e| format!("Model inference failed: {}", e))?;
+
+    let tensor = outputs
+        .get(output)
+        .ok_or_else(|| format!("Model has no output named '{}'", output))?;
+    let (shape, data) = tensor
+        .try_extract_tensor::<f32>()
+        .map_err(|e| format!("Failed to extract '{}' tensor: {}", output, e))?;
+
+    let shape_usize: Vec<usize> = shape.iter().map(|&d| d as usize).collect();
+    let array = Array::from_shape_vec(shape_usize.as_slice(), data.to_vec())
+        .map_err(|e| format!("Failed to create ndarray from tensor data: {}", e))?;
+
+    array
+        .into_dimensionality::<Ix2>()
+        .map_err(|e| format!("Failed to convert ndarray to 2D: {}", e))
+}
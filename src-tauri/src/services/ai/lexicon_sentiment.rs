@@ -0,0 +1,178 @@
+use crate::services::addons::data_enrichment::token_analyzer::TokenAnalyzer;
+
+use super::sentiment_analyzer::{AnalysisResult, Sentiment, SentimentMethod};
+
+/// 사전 기반 점수(-2 ~ 2)가 이 값 이상(이하)이면 긍정(부정)으로 판단합니다.
+/// 감정 단어 하나(가중치 1.0)만 있어도 분류되도록 모델 기준보다 낮게 잡았습니다.
+pub const LEXICON_SENTIMENT_THRESHOLD: f32 = 0.9;
+
+/// 긍정 형태소와 가중치 (용언은 어간 기준)
+const POSITIVE_WORDS: [(&str, f32); 28] = [
+    ("좋", 1.0),
+    ("최고", 1.5),
+    ("대박", 1.0),
+    ("사랑", 1.5),
+    ("감사", 1.0),
+    ("고맙", 1.0),
+    ("축하", 1.0),
+    ("귀엽", 1.0),
+    ("예쁘", 1.0),
+    ("이쁘", 1.0),
+    ("멋있", 1.0),
+    ("멋지", 1.0),
+    ("재밌", 1.0),
+    ("재미", 0.5),
+    ("웃기", 0.5),
+    ("굿", 1.0),
+    ("짱", 1.0),
+    ("행복", 1.5),
+    ("기쁘", 1.0),
+    ("훌륭", 1.0),
+    ("천재", 1.0),
+    ("레전드", 1.0),
+    ("잘하", 1.0),
+    ("신나", 1.0),
+    ("응원", 1.0),
+    ("존잼", 1.5),
+    ("개꿀", 1.0),
+    ("나이스", 1.0),
+];
+
+/// 부정 형태소와 가중치 (용언은 어간 기준)
+const NEGATIVE_WORDS: [(&str, f32); 22] = [
+    ("싫", -1.0),
+    ("별로", -1.0),
+    ("노잼", -1.5),
+    ("최악", -1.5),
+    ("짜증", -1.5),
+    ("실망", -1.5),
+    ("아쉽", -0.5),
+    ("화나", -1.0),
+    ("슬프", -1.0),
+    ("답답", -1.0),
+    ("지루", -1.0),
+    ("못하", -0.5),
+    ("쓰레기", -1.5),
+    ("극혐", -1.5),
+    ("혐오", -1.0),
+    ("나쁘", -1.0),
+    ("무섭", -0.5),
+    ("불쌍", -0.5),
+    ("억까", -1.0),
+    ("망하", -1.0),
+    ("구리", -1.0),
+    ("후회", -1.0),
+];
+
+/// 다음 감정 단어를 뒤집는 부정 부사 (안 좋다, 못 보겠다)
+const PRE_NEGATORS: [&str; 2] = ["안", "못"];
+
+/// 바로 앞 감정 단어를 뒤집는 부정 용언 (좋지 않다, 재미 없다)
+const POST_NEGATORS: [&str; 2] = ["않", "없"];
+
+/// 부정 용언이 앞 감정 단어에 영향을 주는 최대 형태소 거리
+const POST_NEGATION_DISTANCE: usize = 3;
+
+/// 메시지 전체에서 한 번만 반영하는 이모티콘 단서와 가중치
+const EMOTICON_CUES: [(&str, f32); 22] = [
+    ("ㅋㅋ", 0.5),
+    ("ㅎㅎ", 0.5),
+    ("ㅠㅠ", -0.5),
+    ("ㅜㅜ", -0.5),
+    ("👍", 1.0),
+    ("👏", 1.0),
+    ("❤", 1.0),
+    ("♥", 1.0),
+    ("💕", 1.0),
+    ("😍", 1.0),
+    ("🥰", 1.0),
+    ("😊", 1.0),
+    ("😂", 0.5),
+    ("🤣", 0.5),
+    ("👎", -1.0),
+    ("😡", -1.0),
+    ("😠", -1.0),
+    ("🤬", -1.5),
+    ("💢", -1.0),
+    ("😢", -0.5),
+    ("😭", -0.5),
+    ("😞", -1.0),
+];
+
+/**
+ * AI 모델이 없을 때 사용하는 감정 분석입니다.
+ * 형태소 단위로 감정 사전을 찾고 부정 표현(안, 못, 않, 없)을 반영한 뒤,
+ * ㅋㅋ, ㅠㅠ, 👍 같은 이모티콘 단서를 더해 모델과 같은 -2 ~ 2 범위의 점수로 변환합니다.
+ */
+pub fn analyze(token_analyzer: &TokenAnalyzer, message: &str) -> AnalysisResult {
+    let morphemes = token_analyzer.morphemes(message);
+    let raw_score = score_morphemes(&morphemes) + score_emoticons(message);
+
+    let score = ((raw_score / 2.0).tanh() * 2.0 * 100.0).round() / 100.0;
+    let sentiment = if score >= LEXICON_SENTIMENT_THRESHOLD {
+        Sentiment::Positive
+    } else if score <= -LEXICON_SENTIMENT_THRESHOLD {
+        Sentiment::Negative
+    } else {
+        Sentiment::Neutral
+    };
+
+    AnalysisResult {
+        sentiment,
+        score,
+        method: SentimentMethod::Lexicon,
+    }
+}
+
+fn score_morphemes(morphemes: &[(String, String)]) -> f32 {
+    let mut total = 0.0;
+    let mut negate_next = false;
+    // 부정 용언이 뒤집을 수 있도록 마지막 감정 단어의 (위치, 가중치)를 기억합니다.
+    let mut last_hit: Option<(usize, f32)> = None;
+
+    for (index, (surface, pos)) in morphemes.iter().enumerate() {
+        let surface = surface.as_str();
+
+        if pos == "MAG" && PRE_NEGATORS.contains(&surface) {
+            negate_next = true;
+            continue;
+        }
+
+        if POST_NEGATORS.contains(&surface) {
+            if let Some((hit_index, weight)) = last_hit.take() {
+                if index - hit_index <= POST_NEGATION_DISTANCE {
+                    total -= weight * 2.0;
+                }
+            }
+            continue;
+        }
+
+        let Some(mut weight) = word_weight(surface) else {
+            continue;
+        };
+        if negate_next {
+            weight = -weight;
+            negate_next = false;
+        }
+        total += weight;
+        last_hit = Some((index, weight));
+    }
+
+    total
+}
+
+fn word_weight(surface: &str) -> Option<f32> {
+    POSITIVE_WORDS
+        .iter()
+        .chain(NEGATIVE_WORDS.iter())
+        .find(|(word, _)| *word == surface)
+        .map(|(_, weight)| *weight)
+}
+
+fn score_emoticons(message: &str) -> f32 {
+    EMOTICON_CUES
+        .iter()
+        .filter(|(cue, _)| message.contains(cue))
+        .map(|(_, weight)| weight)
+        .sum()
+}
@@ -1,3 +1,9 @@
+#[cfg(feature = "ai")]
+mod inference;
+pub mod lexicon_sentiment;
+pub mod model_manifest;
 pub mod sentiment_analyzer;
+pub mod toxicity_classifier;
 
 pub use sentiment_analyzer::SentimentAnalyzer;
+pub use toxicity_classifier::ToxicityClassifier;
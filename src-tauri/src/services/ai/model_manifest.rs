@@ -0,0 +1,227 @@
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+pub const MANIFEST_FILE_NAME: &str = "manifest.json";
+
+/// 앱 데이터 폴더에서 사용자가 넣은 모델을 찾는 하위 폴더
+pub const USER_MODELS_DIR_NAME: &str = "models";
+
+/**
+ * 모델 폴더의 manifest.json 내용입니다.
+ * 입력/출력 텐서 이름과 라벨 순서, 판단 기준을 모델과 함께 배포해
+ * 코드 변경 없이 모델을 교체할 수 있도록 합니다.
+ */
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ModelManifest {
+    pub name: String,
+    pub version: String,
+    #[serde(default = "default_model_file")]
+    pub model_file: String,
+    #[serde(default = "default_tokenizer_file")]
+    pub tokenizer_file: String,
+    #[serde(default)]
+    pub inputs: ModelInputs,
+    /**
+     * (배치 크기, 라벨 수) 형태의 logits를 출력하는 텐서 이름입니다.
+     */
+    #[serde(default = "default_output")]
+    pub output: String,
+    /**
+     * logits의 인덱스 순서대로 나열한 라벨입니다.
+     * 비어 있으면 모델 폴더의 config.json(id2label)을 사용합니다.
+     */
+    #[serde(default)]
+    pub labels: Vec<String>,
+    #[serde(default)]
+    pub sentiment: Option<SentimentMapping>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ModelInputs {
+    pub input_ids: String,
+    pub attention_mask: String,
+    /**
+     * 모델이 token_type_ids를 받지 않으면 생략합니다.
+     */
+    #[serde(default)]
+    pub token_type_ids: Option<String>,
+}
+
+/**
+ * 감정 분석 모델의 라벨 해석 방법입니다.
+ * 긍정/부정 logit 차이를 -2 ~ 2 범위로 변환한 점수가 threshold 이상(이하)이면 긍정(부정)입니다.
+ */
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SentimentMapping {
+    pub positive_label: String,
+    pub negative_label: String,
+    pub threshold: f32,
+}
+
+/// 로드된 모델의 정보 (프론트엔드 표시용)
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LoadedModelInfo {
+    pub kind: String,
+    pub name: String,
+    pub version: String,
+    pub labels: Vec<String>,
+    pub model_dir: PathBuf,
+}
+
+fn default_model_file() -> String {
+    "model.onnx".to_string()
+}
+
+fn default_tokenizer_file() -> String {
+    "tokenizer.json".to_string()
+}
+
+fn default_output() -> String {
+    "logits".to_string()
+}
+
+impl Default for ModelInputs {
+    fn default() -> Self {
+        Self {
+            input_ids: "input_ids".to_string(),
+            attention_mask: "attention_mask".to_string(),
+            token_type_ids: Some("token_type_ids".to_string()),
+        }
+    }
+}
+
+impl ModelManifest {
+    /// manifest.json이 없는 기존 번들 감정 분석 모델의 설정 ([neg, pos] logits, 기준 1.7)
+    pub fn bundled_sentiment() -> Self {
+        Self {
+            name: "bundled-sentiment".to_string(),
+            version: "1".to_string(),
+            model_file: default_model_file(),
+            tokenizer_file: default_tokenizer_file(),
+            inputs: ModelInputs::default(),
+            output: default_output(),
+            labels: vec!["negative".to_string(), "positive".to_string()],
+            sentiment: Some(SentimentMapping {
+                positive_label: "positive".to_string(),
+                negative_label: "negative".to_string(),
+                threshold: 1.7,
+            }),
+        }
+    }
+
+    /// manifest.json이 없는 기존 번들 유해 표현 모델의 설정 (라벨은 config.json에서 읽습니다)
+    pub fn bundled_toxicity() -> Self {
+        Self {
+            name: "bundled-toxicity".to_string(),
+            version: "1".to_string(),
+            model_file: default_model_file(),
+            tokenizer_file: default_tokenizer_file(),
+            inputs: ModelInputs::default(),
+            output: default_output(),
+            labels: Vec::new(),
+            sentiment: None,
+        }
+    }
+
+    /// 모델 폴더의 manifest.json을 읽습니다. 파일이 없으면 fallback을 사용합니다.
+    pub fn load(model_dir: &Path, kind: &str, fallback: ModelManifest) -> Result<Self, String> {
+        Ok(Self::read(model_dir, kind)?.unwrap_or(fallback))
+    }
+
+    /// 모델 폴더의 manifest.json을 읽습니다. 파일이 없으면 None을 반환합니다.
+    pub fn read(model_dir: &Path, kind: &str) -> Result<Option<Self>, String> {
+        let path = model_dir.join(MANIFEST_FILE_NAME);
+        if !path.exists() {
+            return Ok(None);
+        }
+
+        let content = std::fs::read_to_string(&path)
+            .map_err(|e| format!("Failed to read model manifest: {}", e))?;
+        let manifest: ModelManifest = serde_json::from_str(&content)
+            .map_err(|e| format!("Invalid model manifest {}: {}", path.display(), e))?;
+        manifest.validate(kind)?;
+        Ok(Some(manifest))
+    }
+
+    pub fn validate(&self, kind: &str) -> Result<(), String> {
+        if self.name.trim().is_empty() || self.version.trim().is_empty() {
+            return Err("Model manifest requires name and version".to_string());
+        }
+
+        if kind == "sentiment" && self.sentiment.is_none() {
+            return Err("Sentiment model manifest requires sentiment mapping".to_string());
+        }
+
+        if let Some(mapping) = &self.sentiment {
+            for label in [&mapping.positive_label, &mapping.negative_label] {
+                if !self.labels.contains(label) {
+                    return Err(format!("Sentiment label not in labels: {}", label));
+                }
+            }
+            if !(0.0..=2.0).contains(&mapping.threshold) {
+                return Err("Sentiment threshold must be between 0 and 2".to_string());
+            }
+        }
+
+        Ok(())
+    }
+
+    /// 결과에 기록할 모델 버전 (name@version)
+    pub fn model_version(&self) -> String {
+        format!("{}@{}", self.name, self.version)
+    }
+
+    #[cfg(feature = "ai")]
+    pub fn label_index(&self, label: &str) -> Option<usize> {
+        self.labels.iter().position(|l| l == label)
+    }
+
+    /// 모델 폴더에 manifest가 가리키는 모델과 토크나이저 파일이 있는지 확인합니다.
+    pub fn has_files(&self, model_dir: &Path) -> bool {
+        model_dir.join(&self.model_file).exists() && model_dir.join(&self.tokenizer_file).exists()
+    }
+}
+
+/**
+ * 불러올 모델 폴더와 manifest를 우선순위대로 나열합니다.
+ * 앱 데이터 폴더(models/<kind>)에 사용자가 넣은 모델이 있으면 번들 모델보다 앞에 둡니다.
+ * 사용자 모델은 결과에 기록할 이름과 버전이 필요하므로 manifest.json이 있어야 합니다.
+ * 앞선 모델을 불러오지 못하면 다음 모델을 사용하며, 둘 다 없으면 빈 목록을 반환합니다.
+ */
+pub fn resolve_models(
+    user_models_dir: Option<&Path>,
+    bundled_dir: &Path,
+    kind: &str,
+    fallback: ModelManifest,
+) -> Vec<(PathBuf, ModelManifest)> {
+    let mut candidates = Vec::new();
+
+    if let Some(user_dir) = user_models_dir.map(|dir| dir.join(kind)) {
+        match ModelManifest::read(&user_dir, kind) {
+            Ok(Some(manifest)) if manifest.has_files(&user_dir) => {
+                candidates.push((user_dir, manifest))
+            }
+            Ok(Some(_)) => {}
+            Ok(None) if user_dir.exists() => eprintln!(
+                "[ModelManifest] Ignoring user model '{}': {} is required",
+                kind, MANIFEST_FILE_NAME
+            ),
+            Ok(None) => {}
+            Err(e) => eprintln!("[ModelManifest] Ignoring user model '{}': {}", kind, e),
+        }
+    }
+
+    match ModelManifest::load(bundled_dir, kind, fallback) {
+        Ok(manifest) if manifest.has_files(bundled_dir) => {
+            candidates.push((bundled_dir.to_path_buf(), manifest))
+        }
+        Ok(_) => {}
+        Err(e) => eprintln!("[ModelManifest] Ignoring bundled model '{}': {}", kind, e),
+    }
+
+    candidates
+}
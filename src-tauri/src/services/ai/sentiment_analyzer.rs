@@ -1,25 +1,40 @@
 use anyhow::Result;
 use serde::Deserialize;
 use std::path::PathBuf;
-use std::sync::{Mutex, OnceLock};
-use tokenizers::Tokenizer;
+use std::sync::OnceLock;
+
+use super::model_manifest::{LoadedModelInfo, ModelManifest};
 
 #[cfg(feature = "ai")]
-use ndarray::{Array, Ix2};
+use super::inference::{encode_batch, run_logits};
 #[cfg(feature = "ai")]
 use ort::execution_providers::CPUExecutionProvider;
 #[cfg(feature = "ai")]
 use ort::session::Session;
 #[cfg(feature = "ai")]
-use ort::value::Value;
-
-pub const SENTIMENT_THRESHOLD: f32 = 1.7;
+use std::sync::Mutex;
+#[cfg(feature = "ai")]
+use tokenizers::Tokenizer;
 
 /// 분석 결과를 프론트엔드로 전달하기 위한 구조체입니다.
 #[derive(serde::Serialize, Clone, Deserialize, Debug)]
 pub struct AnalysisResult {
     pub sentiment: Sentiment,
     pub score: f32,
+    /**
+     * 결과를 만든 분석 방식입니다. 모델과 사전 기반 결과를 구분하기 위해 사용합니다.
+     */
+    #[serde(default)]
+    pub method: SentimentMethod,
+}
+
+#[derive(serde::Serialize, Clone, Copy, Debug, Default, Deserialize, PartialEq, Eq)]
+pub enum SentimentMethod {
+    /// ONNX 감정 분석 모델
+    #[default]
+    Model,
+    /// 모델이 없을 때 사용하는 형태소 사전 + 이모티콘 기반 점수
+    Lexicon,
 }
 
 #[derive(serde::Serialize, Clone, Copy, Debug, Deserialize)]
@@ -29,11 +44,39 @@ pub enum Sentiment {
     Neutral,
 }
 
+// DB 저장용 문자열 변환 (변형 이름 그대로 저장합니다)
+impl From<&str> for Sentiment {
+    fn from(s: &str) -> Self {
+        match s {
+            "Positive" => Sentiment::Positive,
+            "Negative" => Sentiment::Negative,
+            _ => Sentiment::Neutral,
+        }
+    }
+}
+
+impl From<&str> for SentimentMethod {
+    fn from(s: &str) -> Self {
+        match s {
+            "Lexicon" => SentimentMethod::Lexicon,
+            _ => SentimentMethod::Model,
+        }
+    }
+}
+
 /// ONNX 세션과 토크나이저를 함께 보관할 구조체입니다.
 pub struct SentimentAnalyzer {
     #[cfg(feature = "ai")]
     session: Mutex<Session>,
+    #[cfg(feature = "ai")]
     tokenizer: Mutex<Tokenizer>,
+    manifest: ModelManifest,
+    model_dir: PathBuf,
+    /// logits에서 (부정, 긍정) 라벨의 위치
+    #[cfg(feature = "ai")]
+    label_indices: (usize, usize),
+    #[cfg(feature = "ai")]
+    threshold: f32,
 }
 
 static GLOBAL_SENTIMENT_ANALYZER: OnceLock<SentimentAnalyzer> = OnceLock::new();
@@ -41,22 +84,37 @@ static GLOBAL_SENTIMENT_ANALYZER: OnceLock<SentimentAnalyzer> = OnceLock::new();
 impl SentimentAnalyzer {
     /// 글로벌 인스턴스 초기화 (splash.rs에서 호출)
     #[cfg(feature = "ai")]
-    pub fn initialize(model_dir: PathBuf) -> Result<()> {
+    pub fn initialize(model_dir: PathBuf, manifest: ModelManifest) -> Result<()> {
         println!(
-            "Loading ONNX model and tokenizer from '{}'...",
+            "Loading sentiment model {} from '{}'...",
+            manifest.model_version(),
             model_dir.display()
         );
 
+        let mapping = manifest
+            .sentiment
+            .as_ref()
+            .ok_or_else(|| anyhow::anyhow!("Model manifest has no sentiment mapping"))?;
+        let label_indices = manifest
+            .label_index(&mapping.negative_label)
+            .zip(manifest.label_index(&mapping.positive_label))
+            .ok_or_else(|| anyhow::anyhow!("Sentiment labels not found in manifest"))?;
+        let threshold = mapping.threshold;
+
         let session = Session::builder()?
             .with_execution_providers([CPUExecutionProvider::default().into()])?
-            .commit_from_file(model_dir.join("model.onnx"))?;
+            .commit_from_file(model_dir.join(&manifest.model_file))?;
 
-        let tokenizer = Tokenizer::from_file(model_dir.join("tokenizer.json"))
+        let tokenizer = Tokenizer::from_file(model_dir.join(&manifest.tokenizer_file))
             .map_err(|e| anyhow::anyhow!("Failed to load tokenizer: {}", e))?;
 
         let analyzer = SentimentAnalyzer {
             session: Mutex::new(session),
             tokenizer: Mutex::new(tokenizer),
+            manifest,
+            model_dir,
+            label_indices,
+            threshold,
         };
 
         GLOBAL_SENTIMENT_ANALYZER
@@ -69,7 +127,7 @@ impl SentimentAnalyzer {
 
     /// AI feature가 비활성화된 경우의 더미 초기화
     #[cfg(not(feature = "ai"))]
-    pub fn initialize(_model_dir: PathBuf) -> Result<()> {
+    pub fn initialize(_model_dir: PathBuf, _manifest: ModelManifest) -> Result<()> {
         println!("AI feature disabled - skipping model initialization");
         Ok(())
     }
@@ -79,97 +137,85 @@ impl SentimentAnalyzer {
         GLOBAL_SENTIMENT_ANALYZER.get()
     }
 
-    /// 텍스트 감정 분석 수행
+    /// 결과에 기록할 모델 버전 (name@version)
+    pub fn model_version(&self) -> String {
+        self.manifest.model_version()
+    }
+
+    pub fn info(&self) -> LoadedModelInfo {
+        LoadedModelInfo {
+            kind: "sentiment".to_string(),
+            name: self.manifest.name.clone(),
+            version: self.manifest.version.clone(),
+            labels: self.manifest.labels.clone(),
+            model_dir: self.model_dir.clone(),
+        }
+    }
+
+    /// 여러 텍스트를 한 번의 추론으로 감정 분석합니다.
     #[cfg(feature = "ai")]
-    pub fn analyze(&self, text: &str) -> Result<AnalysisResult, String> {
-        let tokenizer = self
-            .tokenizer
-            .lock()
-            .map_err(|e| format!("Failed to lock tokenizer: {}", e))?;
+    pub fn analyze_batch(&self, texts: &[&str]) -> Result<Vec<AnalysisResult>, String> {
+        if texts.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let inputs = {
+            let tokenizer = self
+                .tokenizer
+                .lock()
+                .map_err(|e| format!("Failed to lock tokenizer: {}", e))?;
+            encode_batch(&tokenizer, texts, &self.manifest.inputs)?
+        };
+
         let mut session = self
             .session
             .lock()
             .map_err(|e| format!("Failed to lock session: {}", e))?;
-
-        let encoding = tokenizer
-            .encode(text, true)
-            .map_err(|e| format!("Failed to encode text: {}", e))?;
-        let input_ids = encoding.get_ids();
-        let attention_mask = encoding.get_attention_mask();
-        let token_type_ids = encoding.get_type_ids();
-        let seq_len = input_ids.len();
-
-        let ids_array = Array::from_vec(input_ids.iter().map(|&x| x as i64).collect())
-            .into_shape_with_order((1, seq_len))
-            .map_err(|e| e.to_string())?;
-        let mask_array = Array::from_vec(attention_mask.iter().map(|&x| x as i64).collect())
-            .into_shape_with_order((1, seq_len))
-            .map_err(|e| e.to_string())?;
-        let type_ids_array = Array::from_vec(token_type_ids.iter().map(|&x| x as i64).collect())
-            .into_shape_with_order((1, seq_len))
-            .map_err(|e| e.to_string())?;
-
-        let inputs = vec![
-            (
-                "input_ids".to_string(),
-                Value::from_array(ids_array).map_err(|e| e.to_string())?,
-            ),
-            (
-                "attention_mask".to_string(),
-                Value::from_array(mask_array).map_err(|e| e.to_string())?,
-            ),
-            (
-                "token_type_ids".to_string(),
-                Value::from_array(type_ids_array).map_err(|e| e.to_string())?,
-            ),
-        ];
-
-        // 모델 추론 실행
-        let outputs = session
-            .run(inputs)
-            .map_err(|e| format!("Model inference failed: {}", e))?;
-
-        let logits_tensor = outputs["logits"]
-            .try_extract_tensor::<f32>()
-            .map_err(|e| format!("Failed to extract 'logits' tensor: {}", e))?;
-
-        let (shape, data) = logits_tensor;
-
-        let shape_usize: Vec<usize> = shape.iter().map(|&d| d as usize).collect();
-        let array = Array::from_shape_vec(shape_usize.as_slice(), data.to_vec())
-            .map_err(|e| format!("Failed to create ndarray from tensor data: {}", e))?;
-
-        let array_2d = array
-            .into_dimensionality::<Ix2>()
-            .map_err(|e| format!("Failed to convert ndarray to 2D: {}", e))?;
-
-        let neg_logit = array_2d[[0, 0]];
-        let pos_logit = array_2d[[0, 1]];
-
-        let logit_diff = pos_logit - neg_logit;
-        let score = logit_diff.tanh() * 2.0;
-        let final_score = (score * 100.0).round() / 100.0;
-
-        let sentiment = if final_score >= SENTIMENT_THRESHOLD {
-            Sentiment::Positive
-        } else if final_score <= -SENTIMENT_THRESHOLD {
-            Sentiment::Negative
-        } else {
-            Sentiment::Neutral
-        };
-
-        Ok(AnalysisResult {
-            sentiment,
-            score: final_score,
-        })
+        let logits = run_logits(&mut session, inputs, &self.manifest.output)?;
+
+        let (negative, positive) = self.label_indices;
+        logits
+            .rows()
+            .into_iter()
+            .map(|row| match (row.get(negative), row.get(positive)) {
+                (Some(neg), Some(pos)) => Ok(to_analysis_result(*neg, *pos, self.threshold)),
+                _ => Err(format!("Unexpected logits length: {}", row.len())),
+            })
+            .collect()
     }
 
     /// AI feature가 비활성화된 경우의 더미 분석
     #[cfg(not(feature = "ai"))]
-    pub fn analyze(&self, _text: &str) -> Result<AnalysisResult, String> {
-        Ok(AnalysisResult {
-            sentiment: Sentiment::Neutral,
-            score: 0.0,
-        })
+    pub fn analyze_batch(&self, texts: &[&str]) -> Result<Vec<AnalysisResult>, String> {
+        Ok(texts
+            .iter()
+            .map(|_| AnalysisResult {
+                sentiment: Sentiment::Neutral,
+                score: 0.0,
+                method: SentimentMethod::Model,
+            })
+            .collect())
+    }
+}
+
+/// 부정/긍정 logit을 -2 ~ 2 범위의 점수와 감정으로 변환합니다.
+#[cfg(feature = "ai")]
+fn to_analysis_result(neg_logit: f32, pos_logit: f32, threshold: f32) -> AnalysisResult {
+    let logit_diff = pos_logit - neg_logit;
+    let score = logit_diff.tanh() * 2.0;
+    let final_score = (score * 100.0).round() / 100.0;
+
+    let sentiment = if final_score >= threshold {
+        Sentiment::Positive
+    } else if final_score <= -threshold {
+        Sentiment::Negative
+    } else {
+        Sentiment::Neutral
+    };
+
+    AnalysisResult {
+        sentiment,
+        score: final_score,
+        method: SentimentMethod::Model,
     }
 }
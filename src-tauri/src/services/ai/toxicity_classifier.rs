@@ -0,0 +1,219 @@
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+use std::sync::OnceLock;
+
+use super::model_manifest::{LoadedModelInfo, ModelManifest};
+
+#[cfg(feature = "ai")]
+use super::inference::{encode_batch, run_logits};
+#[cfg(feature = "ai")]
+use ort::execution_providers::CPUExecutionProvider;
+#[cfg(feature = "ai")]
+use ort::session::Session;
+#[cfg(feature = "ai")]
+use std::path::Path;
+#[cfg(feature = "ai")]
+use std::sync::Mutex;
+#[cfg(feature = "ai")]
+use tokenizers::Tokenizer;
+
+/// 모델 폴더에 config.json(id2label)이 없을 때 출력 순서대로 붙일 라벨
+#[cfg(feature = "ai")]
+const DEFAULT_LABELS: [&str; 3] = ["toxicity", "hate", "sexual"];
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ToxicityScore {
+    pub label: String,
+    /**
+     * 0 ~ 1 사이의 확률입니다. (라벨마다 독립적으로 계산)
+     */
+    pub score: f32,
+}
+
+/// 메시지 하나의 라벨별 점수입니다.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ToxicityResult {
+    pub scores: Vec<ToxicityScore>,
+}
+
+impl ToxicityResult {
+    /// 가장 점수가 높은 라벨
+    pub fn max(&self) -> Option<&ToxicityScore> {
+        self.scores
+            .iter()
+            .max_by(|a, b| a.score.total_cmp(&b.score))
+    }
+}
+
+/**
+ * 유해 표현(욕설, 혐오, 선정성 등) 분류 모델입니다.
+ * SentimentAnalyzer와 같은 model.onnx + tokenizer.json 구성이면 어떤 모델이든 사용할 수 있으며,
+ * 라벨 이름은 config.json의 id2label에서 읽습니다.
+ */
+pub struct ToxicityClassifier {
+    #[cfg(feature = "ai")]
+    session: Mutex<Session>,
+    #[cfg(feature = "ai")]
+    tokenizer: Mutex<Tokenizer>,
+    /// manifest의 라벨 (비어 있으면 config.json에서 읽은 라벨)
+    labels: Vec<String>,
+    manifest: ModelManifest,
+    model_dir: PathBuf,
+}
+
+static GLOBAL_TOXICITY_CLASSIFIER: OnceLock<ToxicityClassifier> = OnceLock::new();
+
+#[cfg(feature = "ai")]
+#[derive(Deserialize)]
+struct ModelConfig {
+    #[serde(default)]
+    id2label: std::collections::BTreeMap<String, String>,
+}
+
+impl ToxicityClassifier {
+    /// 글로벌 인스턴스 초기화 (splash.rs에서 호출)
+    #[cfg(feature = "ai")]
+    pub fn initialize(model_dir: PathBuf, manifest: ModelManifest) -> Result<()> {
+        println!(
+            "Loading toxicity model {} from '{}'...",
+            manifest.model_version(),
+            model_dir.display()
+        );
+
+        let session = Session::builder()?
+            .with_execution_providers([CPUExecutionProvider::default().into()])?
+            .commit_from_file(model_dir.join(&manifest.model_file))?;
+
+        let tokenizer = Tokenizer::from_file(model_dir.join(&manifest.tokenizer_file))
+            .map_err(|e| anyhow::anyhow!("Failed to load tokenizer: {}", e))?;
+
+        let labels = if manifest.labels.is_empty() {
+            load_labels(&model_dir)
+        } else {
+            manifest.labels.clone()
+        };
+
+        let classifier = ToxicityClassifier {
+            session: Mutex::new(session),
+            tokenizer: Mutex::new(tokenizer),
+            labels,
+            manifest,
+            model_dir,
+        };
+
+        GLOBAL_TOXICITY_CLASSIFIER
+            .set(classifier)
+            .map_err(|_| anyhow::anyhow!("ToxicityClassifier already initialized"))?;
+
+        println!("Toxicity model loaded successfully.");
+        Ok(())
+    }
+
+    /// AI feature가 비활성화된 경우의 더미 초기화
+    #[cfg(not(feature = "ai"))]
+    pub fn initialize(_model_dir: PathBuf, _manifest: ModelManifest) -> Result<()> {
+        println!("AI feature disabled - skipping toxicity model initialization");
+        Ok(())
+    }
+
+    /// 글로벌 인스턴스 반환 (모델이 없으면 None)
+    pub fn global() -> Option<&'static ToxicityClassifier> {
+        GLOBAL_TOXICITY_CLASSIFIER.get()
+    }
+
+    pub fn info(&self) -> LoadedModelInfo {
+        LoadedModelInfo {
+            kind: "toxicity".to_string(),
+            name: self.manifest.name.clone(),
+            version: self.manifest.version.clone(),
+            labels: self.labels.clone(),
+            model_dir: self.model_dir.clone(),
+        }
+    }
+
+    /// 여러 텍스트를 한 번의 추론으로 분류합니다.
+    #[cfg(feature = "ai")]
+    pub fn classify_batch(&self, texts: &[&str]) -> Result<Vec<ToxicityResult>, String> {
+        if texts.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let inputs = {
+            let tokenizer = self
+                .tokenizer
+                .lock()
+                .map_err(|e| format!("Failed to lock tokenizer: {}", e))?;
+            encode_batch(&tokenizer, texts, &self.manifest.inputs)?
+        };
+
+        let mut session = self
+            .session
+            .lock()
+            .map_err(|e| format!("Failed to lock session: {}", e))?;
+        let logits = run_logits(&mut session, inputs, &self.manifest.output)?;
+
+        Ok(logits
+            .rows()
+            .into_iter()
+            .map(|row| ToxicityResult {
+                scores: row
+                    .iter()
+                    .enumerate()
+                    .map(|(index, logit)| ToxicityScore {
+                        label: self.label(index),
+                        score: sigmoid(*logit),
+                    })
+                    .collect(),
+            })
+            .collect())
+    }
+
+    /// AI feature가 비활성화된 경우의 더미 분류
+    #[cfg(not(feature = "ai"))]
+    pub fn classify_batch(&self, texts: &[&str]) -> Result<Vec<ToxicityResult>, String> {
+        Ok(texts
+            .iter()
+            .map(|_| ToxicityResult { scores: Vec::new() })
+            .collect())
+    }
+
+    #[cfg(feature = "ai")]
+    fn label(&self, index: usize) -> String {
+        self.labels
+            .get(index)
+            .cloned()
+            .unwrap_or_else(|| format!("label_{}", index))
+    }
+}
+
+/// config.json의 id2label을 인덱스 순서대로 읽습니다.
+#[cfg(feature = "ai")]
+fn load_labels(model_dir: &Path) -> Vec<String> {
+    let config = std::fs::read_to_string(model_dir.join("config.json"))
+        .ok()
+        .and_then(|content| serde_json::from_str::<ModelConfig>(&content).ok());
+
+    match config {
+        Some(config) if !config.id2label.is_empty() => {
+            let mut labels: Vec<(usize, String)> = config
+                .id2label
+                .into_iter()
+                .filter_map(|(id, label)| id.parse().ok().map(|id| (id, label)))
+                .collect();
+            labels.sort_by_key(|(id, _)| *id);
+            labels.into_iter().map(|(_, label)| label).collect()
+        }
+        _ => DEFAULT_LABELS
+            .iter()
+            .map(|label| label.to_string())
+            .collect(),
+    }
+}
+
+#[cfg(feature = "ai")]
+fn sigmoid(x: f32) -> f32 {
+    1.0 / (1.0 + (-x).exp())
+}
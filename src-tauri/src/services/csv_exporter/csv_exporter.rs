@@ -1,18 +1,66 @@
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
 
 use crate::models::events::{
-    ChatEvent, DonationEvent, MetadataEvent, MuteEvent, UserEvent, EVENT_TYPE_CHAT,
-    EVENT_TYPE_DONATION, EVENT_TYPE_KICK, EVENT_TYPE_METADATA_UPDATE, EVENT_TYPE_MUTE,
+    ChatEvent, DonationEvent, MetadataEvent, MuteEvent, UserEvent,
+    EVENT_TYPE_BATTLE_MISSION_RESULT, EVENT_TYPE_BLACK, EVENT_TYPE_CHALLENGE_MISSION_RESULT,
+    EVENT_TYPE_CHAT, EVENT_TYPE_DONATION, EVENT_TYPE_FREEZE, EVENT_TYPE_GIFT, EVENT_TYPE_KICK,
+    EVENT_TYPE_KICK_CANCEL, EVENT_TYPE_METADATA_UPDATE, EVENT_TYPE_MISSION_DONATION,
+    EVENT_TYPE_MISSION_TOTAL, EVENT_TYPE_MUTE, EVENT_TYPE_NOTIFICATION, EVENT_TYPE_SLOW,
+    EVENT_TYPE_STICKER, EVENT_TYPE_SUBSCRIBE,
 };
-use crate::services::csv_exporter::csv_schemas::{
-    ChatCsvRow, DonationCsvRow, KickCsvRow, MetadataUpdateCsvRow, MuteCsvRow,
+use crate::services::csv_exporter::{
+    csv_schemas::{
+        to_all_events_record, BattleMissionResultCsvRow, ChallengeMissionResultCsvRow, ChatCsvRow,
+        DonationCsvRow, FreezeCsvRow, GiftCsvRow, KickCsvRow, MetadataUpdateCsvRow,
+        MissionDonationCsvRow, MissionTotalCsvRow, MuteCsvRow, NotificationCsvRow,
+        SimplifiedUserCsvRow, SlowCsvRow, StickerCsvRow, SubscribeCsvRow, ALL_EVENTS_CSV_HEADERS,
+    },
+    export_record::ExportRecord,
+    jsonl_writer::JsonlWriter,
+    parquet_writer::ParquetRecordWriter,
 };
 use crate::services::db::{
-    commands::{ChatSearchFilters, EventSearchFilters, PaginationParams},
+    commands::{
+        ChatLogResult, ChatSearchFilters, DatabaseSubsetFilters, EventLogResult,
+        EventSearchFilters, PaginationParams,
+    },
     service::DBService,
 };
 
+pub const EXPORT_FORMAT_CSV: &str = "CSV";
+pub const EXPORT_FORMAT_JSONL: &str = "JSONL";
+pub const EXPORT_FORMAT_PARQUET: &str = "PARQUET";
+pub const EXPORT_FORMAT_SQLITE: &str = "SQLITE";
+
+/// 채팅과 모든 이벤트를 시간순으로 합쳐 내보내는 이벤트 타입
+pub const EXPORT_EVENT_TYPE_ALL: &str = "All";
+
+/// 이벤트 타입별로 내보낼 수 있는 타입 (DBLoggerAddon이 저장하는 모든 이벤트)
+pub const EXPORTABLE_EVENT_TYPES: &[&str] = &[
+    EVENT_TYPE_CHAT,
+    EVENT_TYPE_DONATION,
+    EVENT_TYPE_SUBSCRIBE,
+    EVENT_TYPE_STICKER,
+    EVENT_TYPE_GIFT,
+    EVENT_TYPE_MISSION_DONATION,
+    EVENT_TYPE_MISSION_TOTAL,
+    EVENT_TYPE_CHALLENGE_MISSION_RESULT,
+    EVENT_TYPE_BATTLE_MISSION_RESULT,
+    EVENT_TYPE_MUTE,
+    EVENT_TYPE_KICK,
+    EVENT_TYPE_KICK_CANCEL,
+    EVENT_TYPE_BLACK,
+    EVENT_TYPE_FREEZE,
+    EVENT_TYPE_SLOW,
+    EVENT_TYPE_NOTIFICATION,
+    EVENT_TYPE_METADATA_UPDATE,
+];
+
+/// 내보내기에서 한 번에 조회할 로그 수
+const EXPORT_PAGE_SIZE: i64 = 1000;
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct CsvExportOptions {
@@ -22,6 +70,90 @@ pub struct CsvExportOptions {
     pub channel_id: Option<String>,
     pub broadcast_id: Option<i64>,
     pub output_path: String,
+    /**
+     * CSV, JSONL, PARQUET, SQLITE 중 하나입니다. 생략하면 CSV로 내보냅니다.
+     * SQLITE는 event_type과 관계없이 대상 방송의 모든 데이터를 같은 스키마로 복사합니다.
+     */
+    #[serde(default = "default_export_format")]
+    pub format: String,
+}
+
+fn default_export_format() -> String {
+    EXPORT_FORMAT_CSV.to_string()
+}
+
+/// 이벤트 타입별 CSV 행 (열 구성은 타입마다 다릅니다)
+#[derive(Debug, Serialize)]
+#[serde(untagged)]
+enum EventCsvRow {
+    Chat(ChatCsvRow),
+    Donation(DonationCsvRow),
+    Subscribe(SubscribeCsvRow),
+    Sticker(StickerCsvRow),
+    Gift(GiftCsvRow),
+    MissionDonation(MissionDonationCsvRow),
+    MissionTotal(MissionTotalCsvRow),
+    ChallengeMissionResult(ChallengeMissionResultCsvRow),
+    BattleMissionResult(BattleMissionResultCsvRow),
+    Mute(MuteCsvRow),
+    Kick(KickCsvRow),
+    SimplifiedUser(SimplifiedUserCsvRow),
+    Freeze(FreezeCsvRow),
+    Slow(SlowCsvRow),
+    Notification(NotificationCsvRow),
+    MetadataUpdate(MetadataUpdateCsvRow),
+}
+
+/// 채팅 로그 또는 이벤트 로그 한 건
+enum LogEntry {
+    Chat(ChatLogResult),
+    Event(EventLogResult),
+}
+
+impl LogEntry {
+    fn timestamp(&self) -> DateTime<Utc> {
+        match self {
+            LogEntry::Chat(chat) => chat.timestamp,
+            LogEntry::Event(event) => event.timestamp,
+        }
+    }
+}
+
+impl From<LogEntry> for ExportRecord {
+    fn from(entry: LogEntry) -> Self {
+        match entry {
+            LogEntry::Chat(chat) => ExportRecord::from(chat),
+            LogEntry::Event(event) => ExportRecord::from(event),
+        }
+    }
+}
+
+/// 한 종류의 로그를 페이지 단위로 조회해 쌓아 두는 버퍼
+struct LogPager {
+    buffer: VecDeque<LogEntry>,
+    page: i64,
+    exhausted: bool,
+}
+
+impl LogPager {
+    fn new(enabled: bool) -> Self {
+        Self {
+            buffer: VecDeque::new(),
+            page: 1,
+            exhausted: !enabled,
+        }
+    }
+
+    fn needs_page(&self) -> bool {
+        self.buffer.is_empty() && !self.exhausted
+    }
+
+    fn push_page(&mut self, entries: Vec<LogEntry>) {
+        // 마지막 페이지인지 확인
+        self.exhausted = entries.len() < EXPORT_PAGE_SIZE as usize;
+        self.page += 1;
+        self.buffer.extend(entries);
+    }
 }
 
 #[derive(Debug)]
@@ -34,15 +166,25 @@ impl CSVExporter {
         Self { db_service }
     }
 
-    pub async fn export_events_to_csv(&self, options: CsvExportOptions) -> Result<String, String> {
-        if options.broadcast_id.is_none() && options.channel_id.is_none() {
-            let error = "Either broadcast_id or channel_id must be provided".to_string();
-            println!("CSVExporter: Validation failed: {}", error);
-            return Err(error);
+    /// options.format에 맞는 형식으로 내보냅니다.
+    pub async fn export_events(&self, options: CsvExportOptions) -> Result<String, String> {
+        match options.format.as_str() {
+            EXPORT_FORMAT_CSV => self.export_events_to_csv(options).await,
+            EXPORT_FORMAT_JSONL => self.export_events_to_jsonl(options).await,
+            EXPORT_FORMAT_PARQUET => self.export_events_to_parquet(options).await,
+            EXPORT_FORMAT_SQLITE => self.export_database_subset(options).await,
+            _ => Err(format!("Unsupported export format: {}", options.format)),
         }
+    }
+
+    pub async fn export_events_to_csv(&self, options: CsvExportOptions) -> Result<String, String> {
+        validate_options(&options)?;
+        validate_event_type(&options)?;
 
         // Chat과 다른 이벤트를 구분해서 처리
-        if options.event_type == EVENT_TYPE_CHAT {
+        if options.event_type == EXPORT_EVENT_TYPE_ALL {
+            return self.export_all_events_to_csv(options).await;
+        } else if options.event_type == EVENT_TYPE_CHAT {
             return self.export_chat_logs_to_csv(options).await;
         } else {
             return self.export_event_logs_to_csv(options).await;
@@ -93,21 +235,7 @@ impl CSVExporter {
 
             // 채팅 로그를 CSV 행으로 변환
             for chat_log in result.chat_logs {
-                let chat_type = match chat_log.message_type.as_str() {
-                    "TEXT" => soup_sdk::chat::types::ChatType::Common,
-                    "EMOTICON" => soup_sdk::chat::types::ChatType::Emoticon,
-                    "MANAGER" => soup_sdk::chat::types::ChatType::Manager,
-                    _ => soup_sdk::chat::types::ChatType::Common,
-                };
-
-                let row = ChatCsvRow::from_chat_event(
-                    chat_log.timestamp,
-                    chat_log.channel_id.clone(),
-                    chat_log.message,
-                    chat_type,
-                    chat_log.user,
-                    false,
-                );
+                let row = chat_log_csv_row(chat_log);
                 writer
                     .serialize(&row)
                     .map_err(|e| format!("Failed to write CSV row: {}", e))?;
@@ -196,89 +324,377 @@ impl CSVExporter {
         Ok(options.output_path)
     }
 
-    fn write_csv_row(
-        &self,
-        writer: &mut csv::Writer<std::fs::File>,
-        event_type: &str,
-        payload: &str,
-    ) -> Result<(), String> {
-        match event_type {
-            EVENT_TYPE_CHAT => {
-                let event: ChatEvent = serde_json::from_str(payload)
-                    .map_err(|e| format!("Failed to parse chat event: {}", e))?;
-                let row = ChatCsvRow::from_chat_event(
-                    event.timestamp,
-                    event.channel_id,
-                    event.comment,
-                    event.chat_type,
-                    event.user,
-                    event.is_admin,
-                );
+    /// 채팅과 모든 이벤트를 시간순으로 합쳐 ALL_EVENTS_CSV_HEADERS 열로 내보냅니다.
+    async fn export_all_events_to_csv(&self, options: CsvExportOptions) -> Result<String, String> {
+        let mut writer = csv::Writer::from_path(&options.output_path)
+            .map_err(|e| format!("Failed to create CSV writer: {}", e))?;
+        writer
+            .write_record(ALL_EVENTS_CSV_HEADERS)
+            .map_err(|e| format!("Failed to write CSV header: {}", e))?;
+
+        self.for_each_log_page(&options, |entries| {
+            for entry in entries {
+                let record = match entry {
+                    LogEntry::Chat(chat_log) => {
+                        to_all_events_record(EVENT_TYPE_CHAT, &chat_log_csv_row(chat_log))?
+                    }
+                    LogEntry::Event(event_log) => to_all_events_record(
+                        &event_log.event_type,
+                        &event_csv_row(&event_log.event_type, &event_log.payload)?,
+                    )?,
+                };
                 writer
-                    .serialize(&row)
+                    .write_record(&record)
                     .map_err(|e| format!("Failed to write CSV row: {}", e))?;
             }
-            EVENT_TYPE_DONATION => {
-                let event: DonationEvent = serde_json::from_str(payload)
-                    .map_err(|e| format!("Failed to parse donation event: {}", e))?;
-                let row = DonationCsvRow::from_donation_event(
-                    event.timestamp,
-                    event.channel_id,
-                    event.from,
-                    event.from_label,
-                    event.amount,
-                    event.donation_type,
-                    event.message,
-                    event.become_top_fan,
-                    event.fan_club_ordinal,
-                );
-                writer
-                    .serialize(&row)
-                    .map_err(|e| format!("Failed to write CSV row: {}", e))?;
+            Ok(())
+        })
+        .await?;
+
+        writer
+            .flush()
+            .map_err(|e| format!("Failed to flush CSV writer: {}", e))?;
+
+        Ok(options.output_path)
+    }
+
+    async fn export_events_to_jsonl(&self, options: CsvExportOptions) -> Result<String, String> {
+        validate_options(&options)?;
+        validate_event_type(&options)?;
+
+        let mut writer = JsonlWriter::create(&options.output_path)?;
+        self.for_each_record_page(&options, |records| {
+            records.iter().try_for_each(|record| writer.write(record))
+        })
+        .await?;
+        writer.finish()?;
+
+        Ok(options.output_path)
+    }
+
+    async fn export_events_to_parquet(&self, options: CsvExportOptions) -> Result<String, String> {
+        validate_options(&options)?;
+        validate_event_type(&options)?;
+
+        let mut writer = ParquetRecordWriter::create(&options.output_path)?;
+        self.for_each_record_page(&options, |records| writer.write(&records))
+            .await?;
+        writer.finish()?;
+
+        Ok(options.output_path)
+    }
+
+    /**
+     * 대상 채널/방송의 데이터만 담은 SQLite 파일을 만듭니다. (기간은 방송 시작 시각 기준)
+     * 복사 중에도 다른 DB 명령이 처리되도록 로그는 방송 하나씩 나눠 복사합니다.
+     */
+    async fn export_database_subset(&self, options: CsvExportOptions) -> Result<String, String> {
+        validate_options(&options)?;
+
+        let broadcast_ids = self
+            .db_service
+            .create_database_subset(
+                DatabaseSubsetFilters {
+                    broadcast_id: options.broadcast_id,
+                    channel_id: options.channel_id.clone(),
+                    start_date: options.start_date,
+                    end_date: options.end_date,
+                },
+                options.output_path.clone(),
+            )
+            .await?;
+
+        for broadcast_id in broadcast_ids {
+            if let Err(e) = self
+                .db_service
+                .copy_database_subset_broadcast(options.output_path.clone(), broadcast_id)
+                .await
+            {
+                let _ = std::fs::remove_file(&options.output_path);
+                return Err(e);
             }
-            EVENT_TYPE_MUTE => {
-                let event: MuteEvent = serde_json::from_str(payload)
-                    .map_err(|e| format!("Failed to parse mute event: {}", e))?;
-                let row = MuteCsvRow::from_mute_event(
-                    event.timestamp,
-                    event.channel_id,
-                    event.user,
-                    event.seconds,
-                    event.message,
-                    event.by,
-                    event.counts,
-                    event.superuser_type,
-                );
-                writer
-                    .serialize(&row)
-                    .map_err(|e| format!("Failed to write CSV row: {}", e))?;
+        }
+
+        Ok(options.output_path)
+    }
+
+    /// 채팅 또는 이벤트 로그를 페이지 단위로 조회해 write_page에 전달합니다.
+    async fn for_each_record_page<F>(
+        &self,
+        options: &CsvExportOptions,
+        mut write_page: F,
+    ) -> Result<(), String>
+    where
+        F: FnMut(Vec<ExportRecord>) -> Result<(), String>,
+    {
+        self.for_each_log_page(options, |entries| {
+            write_page(entries.into_iter().map(ExportRecord::from).collect())
+        })
+        .await
+    }
+
+    /**
+     * 대상 로그를 조회 순서(최신순)대로 EXPORT_PAGE_SIZE씩 write_page에 전달합니다.
+     * 모든 이벤트를 내보낼 때는 채팅과 이벤트 로그를 각각 페이지 단위로 조회하며 시간순으로 합칩니다.
+     */
+    async fn for_each_log_page<F>(
+        &self,
+        options: &CsvExportOptions,
+        mut write_page: F,
+    ) -> Result<(), String>
+    where
+        F: FnMut(Vec<LogEntry>) -> Result<(), String>,
+    {
+        let is_all = options.event_type == EXPORT_EVENT_TYPE_ALL;
+        let is_chat = options.event_type == EVENT_TYPE_CHAT;
+
+        let mut chat_logs = LogPager::new(is_all || is_chat);
+        let mut event_logs = LogPager::new(!is_chat);
+        let mut page: Vec<LogEntry> = Vec::with_capacity(EXPORT_PAGE_SIZE as usize);
+
+        loop {
+            if chat_logs.needs_page() {
+                let pagination = PaginationParams {
+                    page: chat_logs.page,
+                    page_size: EXPORT_PAGE_SIZE,
+                };
+                let entries = self
+                    .db_service
+                    .search_chat_logs(chat_search_filters(options), pagination)
+                    .await
+                    .map_err(|e| format!("Failed to search chat logs: {}", e))?
+                    .chat_logs
+                    .into_iter()
+                    .map(LogEntry::Chat)
+                    .collect();
+                chat_logs.push_page(entries);
             }
-            EVENT_TYPE_KICK => {
-                let event: UserEvent = serde_json::from_str(payload)
-                    .map_err(|e| format!("Failed to parse kick event: {}", e))?;
-                let row =
-                    KickCsvRow::from_kick_event(event.timestamp, event.channel_id, event.user);
-                writer
-                    .serialize(&row)
-                    .map_err(|e| format!("Failed to write CSV row: {}", e))?;
+
+            if event_logs.needs_page() {
+                let pagination = PaginationParams {
+                    page: event_logs.page,
+                    page_size: EXPORT_PAGE_SIZE,
+                };
+                let entries = self
+                    .db_service
+                    .search_event_logs(event_search_filters(options), pagination)
+                    .await
+                    .map_err(|e| format!("Failed to search event logs: {}", e))?
+                    .event_logs
+                    .into_iter()
+                    .map(LogEntry::Event)
+                    .collect();
+                event_logs.push_page(entries);
             }
-            EVENT_TYPE_METADATA_UPDATE => {
-                let event: MetadataEvent = serde_json::from_str(payload)
-                    .map_err(|e| format!("Failed to parse metadata event: {}", e))?;
-                let row = MetadataUpdateCsvRow::from_metadata_event(
-                    event.timestamp,
-                    event.channel_id,
-                    event.title,
-                    event.started_at,
-                    event.viewer_count,
-                );
-                writer
-                    .serialize(&row)
-                    .map_err(|e| format!("Failed to write CSV row: {}", e))?;
+
+            let is_chat_next = match (chat_logs.buffer.front(), event_logs.buffer.front()) {
+                (Some(chat), Some(event)) => chat.timestamp() >= event.timestamp(),
+                (Some(_), None) => true,
+                (None, Some(_)) => false,
+                (None, None) => break,
+            };
+            let entry = if is_chat_next {
+                chat_logs.buffer.pop_front()
+            } else {
+                event_logs.buffer.pop_front()
+            };
+            page.extend(entry);
+
+            if page.len() >= EXPORT_PAGE_SIZE as usize {
+                write_page(std::mem::take(&mut page))?;
             }
-            _ => return Err(format!("Unsupported event type: {}", event_type)),
+        }
+
+        if !page.is_empty() {
+            write_page(page)?;
         }
 
         Ok(())
     }
+
+    fn write_csv_row(
+        &self,
+        writer: &mut csv::Writer<std::fs::File>,
+        event_type: &str,
+        payload: &str,
+    ) -> Result<(), String> {
+        let row = event_csv_row(event_type, payload)?;
+        writer
+            .serialize(&row)
+            .map_err(|e| format!("Failed to write CSV row: {}", e))
+    }
+}
+
+/// 채팅 로그를 CSV 행으로 변환합니다.
+fn chat_log_csv_row(chat_log: ChatLogResult) -> ChatCsvRow {
+    let chat_type = match chat_log.message_type.as_str() {
+        "TEXT" => soup_sdk::chat::types::ChatType::Common,
+        "EMOTICON" => soup_sdk::chat::types::ChatType::Emoticon,
+        "MANAGER" => soup_sdk::chat::types::ChatType::Manager,
+        _ => soup_sdk::chat::types::ChatType::Common,
+    };
+
+    ChatCsvRow::from_chat_event(
+        chat_log.timestamp,
+        chat_log.channel_id,
+        chat_log.message,
+        chat_type,
+        chat_log.user,
+        false,
+    )
+}
+
+/// 저장된 이벤트 payload를 이벤트 타입에 맞는 CSV 행으로 변환합니다.
+fn event_csv_row(event_type: &str, payload: &str) -> Result<EventCsvRow, String> {
+    fn parse<'a, T: Deserialize<'a>>(payload: &'a str, name: &str) -> Result<T, String> {
+        serde_json::from_str(payload).map_err(|e| format!("Failed to parse {} event: {}", name, e))
+    }
+
+    let row = match event_type {
+        EVENT_TYPE_CHAT => {
+            let event: ChatEvent = parse(payload, "chat")?;
+            EventCsvRow::Chat(ChatCsvRow::from_chat_event(
+                event.timestamp,
+                event.channel_id,
+                event.comment,
+                event.chat_type,
+                event.user,
+                event.is_admin,
+            ))
+        }
+        EVENT_TYPE_DONATION => {
+            let event: DonationEvent = parse(payload, "donation")?;
+            EventCsvRow::Donation(DonationCsvRow::from_donation_event(
+                event.timestamp,
+                event.channel_id,
+                event.from,
+                event.from_label,
+                event.amount,
+                event.donation_type,
+                event.message,
+                event.become_top_fan,
+                event.fan_club_ordinal,
+            ))
+        }
+        EVENT_TYPE_SUBSCRIBE => EventCsvRow::Subscribe(SubscribeCsvRow::from_subscribe_event(
+            parse(payload, "subscribe")?,
+        )),
+        EVENT_TYPE_STICKER => EventCsvRow::Sticker(StickerCsvRow::from_sticker_event(parse(
+            payload, "sticker",
+        )?)),
+        EVENT_TYPE_GIFT => EventCsvRow::Gift(GiftCsvRow::from_gift_event(parse(payload, "gift")?)),
+        EVENT_TYPE_MISSION_DONATION => EventCsvRow::MissionDonation(
+            MissionDonationCsvRow::from_mission_event(parse(payload, "mission donation")?),
+        ),
+        EVENT_TYPE_MISSION_TOTAL => EventCsvRow::MissionTotal(
+            MissionTotalCsvRow::from_mission_total_event(parse(payload, "mission total")?),
+        ),
+        EVENT_TYPE_CHALLENGE_MISSION_RESULT => EventCsvRow::ChallengeMissionResult(
+            ChallengeMissionResultCsvRow::from_challenge_mission_result_event(parse(
+                payload,
+                "challenge mission result",
+            )?),
+        ),
+        EVENT_TYPE_BATTLE_MISSION_RESULT => EventCsvRow::BattleMissionResult(
+            BattleMissionResultCsvRow::from_battle_mission_result_event(parse(
+                payload,
+                "battle mission result",
+            )?),
+        ),
+        EVENT_TYPE_MUTE => {
+            let event: MuteEvent = parse(payload, "mute")?;
+            EventCsvRow::Mute(MuteCsvRow::from_mute_event(
+                event.timestamp,
+                event.channel_id,
+                event.user,
+                event.seconds,
+                event.message,
+                event.by,
+                event.counts,
+                event.superuser_type,
+            ))
+        }
+        EVENT_TYPE_KICK => {
+            let event: UserEvent = parse(payload, "kick")?;
+            EventCsvRow::Kick(KickCsvRow::from_kick_event(
+                event.timestamp,
+                event.channel_id,
+                event.user,
+            ))
+        }
+        EVENT_TYPE_KICK_CANCEL => EventCsvRow::SimplifiedUser(
+            SimplifiedUserCsvRow::from_simplified_user_event(parse(payload, "kick cancel")?),
+        ),
+        EVENT_TYPE_BLACK => EventCsvRow::SimplifiedUser(
+            SimplifiedUserCsvRow::from_simplified_user_event(parse(payload, "black")?),
+        ),
+        EVENT_TYPE_FREEZE => {
+            EventCsvRow::Freeze(FreezeCsvRow::from_freeze_event(parse(payload, "freeze")?))
+        }
+        EVENT_TYPE_SLOW => EventCsvRow::Slow(SlowCsvRow::from_slow_event(parse(payload, "slow")?)),
+        EVENT_TYPE_NOTIFICATION => EventCsvRow::Notification(
+            NotificationCsvRow::from_notification_event(parse(payload, "notification")?),
+        ),
+        EVENT_TYPE_METADATA_UPDATE => {
+            let event: MetadataEvent = parse(payload, "metadata")?;
+            EventCsvRow::MetadataUpdate(MetadataUpdateCsvRow::from_metadata_event(
+                event.timestamp,
+                event.channel_id,
+                event.title,
+                event.started_at,
+                event.viewer_count,
+            ))
+        }
+        _ => return Err(format!("Unsupported event type: {}", event_type)),
+    };
+
+    Ok(row)
+}
+
+fn validate_options(options: &CsvExportOptions) -> Result<(), String> {
+    if options.broadcast_id.is_none() && options.channel_id.is_none() {
+        let error = "Either broadcast_id or channel_id must be provided".to_string();
+        println!("CSVExporter: Validation failed: {}", error);
+        return Err(error);
+    }
+
+    Ok(())
+}
+
+fn validate_event_type(options: &CsvExportOptions) -> Result<(), String> {
+    if options.event_type != EXPORT_EVENT_TYPE_ALL
+        && !EXPORTABLE_EVENT_TYPES.contains(&options.event_type.as_str())
+    {
+        return Err(format!("Unsupported event type: {}", options.event_type));
+    }
+
+    Ok(())
+}
+
+fn chat_search_filters(options: &CsvExportOptions) -> ChatSearchFilters {
+    ChatSearchFilters {
+        channel_id: options.channel_id.clone(),
+        user_id: None,
+        username: None,
+        message_contains: None,
+        message_type: None,
+        start_date: options.start_date,
+        end_date: options.end_date,
+        broadcast_id: options.broadcast_id,
+    }
+}
+
+fn event_search_filters(options: &CsvExportOptions) -> EventSearchFilters {
+    EventSearchFilters {
+        channel_id: options.channel_id.clone(),
+        user_id: None,
+        username: None,
+        // 모든 이벤트를 내보낼 때는 타입으로 거르지 않습니다.
+        event_type: (options.event_type != EXPORT_EVENT_TYPE_ALL)
+            .then(|| options.event_type.clone()),
+        exclude_event_types: Vec::new(),
+        start_date: options.start_date,
+        end_date: options.end_date,
+        broadcast_id: options.broadcast_id,
+    }
 }
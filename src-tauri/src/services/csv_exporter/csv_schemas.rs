@@ -1,7 +1,58 @@
 use chrono::{DateTime, Utc};
 use serde::Serialize;
+use serde_json::Value;
 use soup_sdk::chat::types::{ChatType, DonationType, User};
 
+use crate::models::events::{
+    BattleMissionResultEvent, ChallengeMissionResultEvent, FreezeEvent, GiftEvent, MissionEvent,
+    MissionTotalEvent, NotificationEvent, SimplifiedUserEvent, SlowEvent, StickerEvent,
+    SubscribeEvent,
+};
+
+/**
+ * 모든 이벤트를 한 파일로 내보낼 때 쓰는 열 목록입니다.
+ * 이벤트별 CSV 행의 열을 모두 합친 것이며, 해당 이벤트에 없는 열은 비워 둡니다.
+ */
+pub const ALL_EVENTS_CSV_HEADERS: &[&str] = &[
+    "timestamp",
+    "event_type",
+    "channel_id",
+    "user_id",
+    "username",
+    "comment",
+    "chat_type",
+    "is_admin",
+    "amount",
+    "donation_type",
+    "message",
+    "become_top_fan",
+    "fan_club_ordinal",
+    "seconds",
+    "moderator",
+    "counts",
+    "superuser_type",
+    "title",
+    "started_at",
+    "viewer_count",
+    "tier",
+    "renew",
+    "supporter_ordinal",
+    "gift_type",
+    "receiver_id",
+    "receiver_username",
+    "gift_code",
+    "mission_type",
+    "is_success",
+    "is_draw",
+    "winner",
+    "freezed",
+    "limit_subscription_month",
+    "limit_balloons",
+    "targets",
+    "duration",
+    "show",
+];
+
 #[derive(Debug, Clone, Serialize)]
 pub struct ChatCsvRow {
     pub timestamp: String,
@@ -56,6 +107,106 @@ pub struct MetadataUpdateCsvRow {
     pub viewer_count: u64,
 }
 
+#[derive(Debug, Clone, Serialize)]
+pub struct SubscribeCsvRow {
+    pub timestamp: String,
+    pub channel_id: String,
+    pub user_id: String,
+    pub username: String,
+    pub tier: u32,
+    pub renew: u32,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct StickerCsvRow {
+    pub timestamp: String,
+    pub channel_id: String,
+    pub user_id: String,
+    pub username: String,
+    pub amount: u32,
+    pub supporter_ordinal: u32,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct GiftCsvRow {
+    pub timestamp: String,
+    pub channel_id: String,
+    pub gift_type: String,
+    pub user_id: String,
+    pub username: String,
+    pub receiver_id: String,
+    pub receiver_username: String,
+    pub gift_code: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct MissionDonationCsvRow {
+    pub timestamp: String,
+    pub channel_id: String,
+    pub user_id: String,
+    pub username: String,
+    pub amount: u32,
+    pub mission_type: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct MissionTotalCsvRow {
+    pub timestamp: String,
+    pub channel_id: String,
+    pub mission_type: String,
+    pub amount: u32,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ChallengeMissionResultCsvRow {
+    pub timestamp: String,
+    pub channel_id: String,
+    pub title: String,
+    pub is_success: bool,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct BattleMissionResultCsvRow {
+    pub timestamp: String,
+    pub channel_id: String,
+    pub title: String,
+    pub is_draw: bool,
+    pub winner: String,
+}
+
+/// 사용자 ID만 남는 이벤트(KickCancel, Black)의 행
+#[derive(Debug, Clone, Serialize)]
+pub struct SimplifiedUserCsvRow {
+    pub timestamp: String,
+    pub channel_id: String,
+    pub user_id: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct FreezeCsvRow {
+    pub timestamp: String,
+    pub channel_id: String,
+    pub freezed: bool,
+    pub limit_subscription_month: u32,
+    pub limit_balloons: u32,
+    pub targets: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct SlowCsvRow {
+    pub timestamp: String,
+    pub channel_id: String,
+    pub duration: u32,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct NotificationCsvRow {
+    pub timestamp: String,
+    pub channel_id: String,
+    pub message: String,
+    pub show: bool,
+}
+
 impl ChatCsvRow {
     pub fn from_chat_event(
         timestamp: DateTime<Utc>,
@@ -156,3 +307,163 @@ impl MetadataUpdateCsvRow {
         }
     }
 }
+
+impl SubscribeCsvRow {
+    pub fn from_subscribe_event(event: SubscribeEvent) -> Self {
+        Self {
+            timestamp: event.timestamp.to_rfc3339(),
+            channel_id: event.channel_id,
+            user_id: event.user_id,
+            username: event.label,
+            tier: event.tier,
+            renew: event.renew,
+        }
+    }
+}
+
+impl StickerCsvRow {
+    pub fn from_sticker_event(event: StickerEvent) -> Self {
+        Self {
+            timestamp: event.timestamp.to_rfc3339(),
+            channel_id: event.channel_id,
+            user_id: event.from,
+            username: event.from_label,
+            amount: event.amount,
+            supporter_ordinal: event.supporter_ordinal,
+        }
+    }
+}
+
+impl GiftCsvRow {
+    pub fn from_gift_event(event: GiftEvent) -> Self {
+        Self {
+            timestamp: event.timestamp.to_rfc3339(),
+            channel_id: event.channel_id,
+            gift_type: format!("{:?}", event.gift_type),
+            user_id: event.sender_id,
+            username: event.sender_label,
+            receiver_id: event.receiver_id,
+            receiver_username: event.receiver_label,
+            gift_code: event.gift_code,
+        }
+    }
+}
+
+impl MissionDonationCsvRow {
+    pub fn from_mission_event(event: MissionEvent) -> Self {
+        Self {
+            timestamp: event.timestamp.to_rfc3339(),
+            channel_id: event.channel_id,
+            user_id: event.from,
+            username: event.from_label,
+            amount: event.amount,
+            mission_type: format!("{:?}", event.mission_type),
+        }
+    }
+}
+
+impl MissionTotalCsvRow {
+    pub fn from_mission_total_event(event: MissionTotalEvent) -> Self {
+        Self {
+            timestamp: event.timestamp.to_rfc3339(),
+            channel_id: event.channel_id,
+            mission_type: format!("{:?}", event.mission_type),
+            amount: event.amount,
+        }
+    }
+}
+
+impl ChallengeMissionResultCsvRow {
+    pub fn from_challenge_mission_result_event(event: ChallengeMissionResultEvent) -> Self {
+        Self {
+            timestamp: event.timestamp.to_rfc3339(),
+            channel_id: event.channel_id,
+            title: event.title,
+            is_success: event.is_success,
+        }
+    }
+}
+
+impl BattleMissionResultCsvRow {
+    pub fn from_battle_mission_result_event(event: BattleMissionResultEvent) -> Self {
+        Self {
+            timestamp: event.timestamp.to_rfc3339(),
+            channel_id: event.channel_id,
+            title: event.title,
+            is_draw: event.is_draw,
+            winner: event.winner,
+        }
+    }
+}
+
+impl SimplifiedUserCsvRow {
+    pub fn from_simplified_user_event(event: SimplifiedUserEvent) -> Self {
+        Self {
+            timestamp: event.timestamp.to_rfc3339(),
+            channel_id: event.channel_id,
+            user_id: event.user_id,
+        }
+    }
+}
+
+impl FreezeCsvRow {
+    pub fn from_freeze_event(event: FreezeEvent) -> Self {
+        Self {
+            timestamp: event.timestamp.to_rfc3339(),
+            channel_id: event.channel_id,
+            freezed: event.freezed,
+            limit_subscription_month: event.limit_subscription_month,
+            limit_balloons: event.limit_balloons,
+            targets: event.targets.join(", "),
+        }
+    }
+}
+
+impl SlowCsvRow {
+    pub fn from_slow_event(event: SlowEvent) -> Self {
+        Self {
+            timestamp: event.timestamp.to_rfc3339(),
+            channel_id: event.channel_id,
+            duration: event.duration,
+        }
+    }
+}
+
+impl NotificationCsvRow {
+    pub fn from_notification_event(event: NotificationEvent) -> Self {
+        Self {
+            timestamp: event.timestamp.to_rfc3339(),
+            channel_id: event.channel_id,
+            message: event.message,
+            show: event.show,
+        }
+    }
+}
+
+/**
+ * 이벤트별 CSV 행을 ALL_EVENTS_CSV_HEADERS 순서의 값 목록으로 바꿉니다.
+ * 행에 없는 열은 빈 값으로 채웁니다.
+ */
+pub fn to_all_events_record<T: Serialize>(
+    event_type: &str,
+    row: &T,
+) -> Result<Vec<String>, String> {
+    let Value::Object(mut fields) =
+        serde_json::to_value(row).map_err(|e| format!("Failed to convert CSV row: {}", e))?
+    else {
+        return Err(format!("Unexpected CSV row for event type: {}", event_type));
+    };
+    fields.insert(
+        "event_type".to_string(),
+        Value::String(event_type.to_string()),
+    );
+
+    Ok(ALL_EVENTS_CSV_HEADERS
+        .iter()
+        .map(|header| match fields.remove(*header) {
+            None | Some(Value::Null) => String::new(),
+            Some(Value::String(value)) => value,
+            Some(value) => value.to_string(),
+        })
+        .collect())
+}
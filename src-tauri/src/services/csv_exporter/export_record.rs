@@ -0,0 +1,67 @@
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use serde_json::{json, Value};
+use soup_sdk::chat::types::User;
+
+use crate::models::events::EVENT_TYPE_CHAT;
+use crate::services::db::commands::{ChatLogResult, EventLogResult};
+
+/**
+ * JSON Lines, Parquet 내보내기에서 채팅과 이벤트를 같은 형태로 다루기 위한 행입니다.
+ * CSV와 달리 이벤트 원본 데이터(payload)를 그대로 담습니다.
+ */
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExportRecord {
+    pub id: String,
+    pub broadcast_id: i64,
+    pub event_type: String,
+    pub timestamp: DateTime<Utc>,
+    pub channel_id: String,
+    pub channel_name: String,
+    pub broadcast_title: String,
+    pub user: Option<User>,
+    /**
+     * 이벤트 원본 데이터입니다. 채팅은 메시지 타입, 본문, 메타데이터를 담습니다.
+     */
+    pub payload: Value,
+}
+
+impl From<ChatLogResult> for ExportRecord {
+    fn from(chat: ChatLogResult) -> Self {
+        Self {
+            id: chat.id,
+            broadcast_id: chat.broadcast_id,
+            event_type: EVENT_TYPE_CHAT.to_string(),
+            timestamp: chat.timestamp,
+            channel_id: chat.channel_id,
+            channel_name: chat.channel_name,
+            broadcast_title: chat.broadcast_title,
+            user: Some(chat.user),
+            payload: json!({
+                "messageType": chat.message_type,
+                "message": chat.message,
+                "metadata": chat.metadata,
+            }),
+        }
+    }
+}
+
+impl From<EventLogResult> for ExportRecord {
+    fn from(event: EventLogResult) -> Self {
+        // 저장된 payload가 JSON이 아니면 문자열 그대로 내보냅니다.
+        let payload = serde_json::from_str(&event.payload).unwrap_or(Value::String(event.payload));
+
+        Self {
+            id: event.id,
+            broadcast_id: event.broadcast_id,
+            event_type: event.event_type,
+            timestamp: event.timestamp,
+            channel_id: event.channel_id,
+            channel_name: event.channel_name,
+            broadcast_title: event.broadcast_title,
+            user: event.user,
+            payload,
+        }
+    }
+}
@@ -0,0 +1,33 @@
+use std::fs::File;
+use std::io::{BufWriter, Write};
+
+use crate::services::csv_exporter::export_record::ExportRecord;
+
+/// 한 줄에 하나의 JSON 객체를 쓰는 JSON Lines 파일 작성기입니다.
+pub struct JsonlWriter {
+    writer: BufWriter<File>,
+}
+
+impl JsonlWriter {
+    pub fn create(path: &str) -> Result<Self, String> {
+        let file = File::create(path).map_err(|e| format!("Failed to create JSONL file: {}", e))?;
+
+        Ok(Self {
+            writer: BufWriter::new(file),
+        })
+    }
+
+    pub fn write(&mut self, record: &ExportRecord) -> Result<(), String> {
+        serde_json::to_writer(&mut self.writer, record)
+            .map_err(|e| format!("Failed to write JSONL row: {}", e))?;
+        self.writer
+            .write_all(b"\n")
+            .map_err(|e| format!("Failed to write JSONL row: {}", e))
+    }
+
+    pub fn finish(mut self) -> Result<(), String> {
+        self.writer
+            .flush()
+            .map_err(|e| format!("Failed to flush JSONL writer: {}", e))
+    }
+}
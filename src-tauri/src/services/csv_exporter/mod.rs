@@ -1,2 +1,5 @@
 pub mod csv_exporter;
 pub mod csv_schemas;
+pub mod export_record;
+pub mod jsonl_writer;
+pub mod parquet_writer;
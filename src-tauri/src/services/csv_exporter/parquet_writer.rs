@@ -0,0 +1,98 @@
+use arrow_array::{ArrayRef, Int64Array, RecordBatch, StringArray, TimestampMillisecondArray};
+use arrow_schema::{DataType, Field, Schema, SchemaRef, TimeUnit};
+use parquet::arrow::ArrowWriter;
+use parquet::basic::Compression;
+use parquet::file::properties::WriterProperties;
+use std::fs::File;
+use std::sync::Arc;
+
+use crate::services::csv_exporter::export_record::ExportRecord;
+
+/**
+ * 채팅과 이벤트를 하나의 평평한 스키마로 쓰는 Parquet 파일 작성기입니다.
+ * 이벤트별 데이터는 payload 열에 JSON 문자열로 저장합니다.
+ */
+pub struct ParquetRecordWriter {
+    writer: ArrowWriter<File>,
+    schema: SchemaRef,
+}
+
+impl ParquetRecordWriter {
+    pub fn create(path: &str) -> Result<Self, String> {
+        let schema: SchemaRef = Arc::new(Schema::new(vec![
+            Field::new("id", DataType::Utf8, false),
+            Field::new("broadcast_id", DataType::Int64, false),
+            Field::new("event_type", DataType::Utf8, false),
+            Field::new(
+                "timestamp",
+                DataType::Timestamp(TimeUnit::Millisecond, Some("UTC".into())),
+                false,
+            ),
+            Field::new("channel_id", DataType::Utf8, false),
+            Field::new("channel_name", DataType::Utf8, false),
+            Field::new("broadcast_title", DataType::Utf8, false),
+            Field::new("user_id", DataType::Utf8, true),
+            Field::new("username", DataType::Utf8, true),
+            Field::new("payload", DataType::Utf8, false),
+        ]));
+
+        let file =
+            File::create(path).map_err(|e| format!("Failed to create Parquet file: {}", e))?;
+        let properties = WriterProperties::builder()
+            .set_compression(Compression::SNAPPY)
+            .build();
+        let writer = ArrowWriter::try_new(file, schema.clone(), Some(properties))
+            .map_err(|e| format!("Failed to create Parquet writer: {}", e))?;
+
+        Ok(Self { writer, schema })
+    }
+
+    /// 페이지 하나를 RecordBatch로 변환해 씁니다.
+    pub fn write(&mut self, records: &[ExportRecord]) -> Result<(), String> {
+        let strings = |get: fn(&ExportRecord) -> &str| -> ArrayRef {
+            Arc::new(StringArray::from_iter_values(records.iter().map(get)))
+        };
+        let user_fields = |get: fn(&soup_sdk::chat::types::User) -> &str| -> ArrayRef {
+            Arc::new(StringArray::from_iter(
+                records.iter().map(|record| record.user.as_ref().map(get)),
+            ))
+        };
+
+        let columns: Vec<ArrayRef> = vec![
+            strings(|record| &record.id),
+            Arc::new(Int64Array::from_iter_values(
+                records.iter().map(|record| record.broadcast_id),
+            )),
+            strings(|record| &record.event_type),
+            Arc::new(
+                TimestampMillisecondArray::from_iter_values(
+                    records
+                        .iter()
+                        .map(|record| record.timestamp.timestamp_millis()),
+                )
+                .with_timezone("UTC"),
+            ),
+            strings(|record| &record.channel_id),
+            strings(|record| &record.channel_name),
+            strings(|record| &record.broadcast_title),
+            user_fields(|user| &user.id),
+            user_fields(|user| &user.label),
+            Arc::new(StringArray::from_iter_values(
+                records.iter().map(|record| record.payload.to_string()),
+            )),
+        ];
+
+        let batch = RecordBatch::try_new(self.schema.clone(), columns)
+            .map_err(|e| format!("Failed to build Parquet batch: {}", e))?;
+        self.writer
+            .write(&batch)
+            .map_err(|e| format!("Failed to write Parquet batch: {}", e))
+    }
+
+    pub fn finish(self) -> Result<(), String> {
+        self.writer
+            .close()
+            .map(|_| ())
+            .map_err(|e| format!("Failed to close Parquet writer: {}", e))
+    }
+}
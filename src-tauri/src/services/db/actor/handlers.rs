@@ -2,15 +2,21 @@ use chrono::{DateTime, Utc};
 use rusqlite::Connection;
 use tokio::sync::oneshot;
 
+use crate::models::events::{EVENT_TYPE_DONATION, EVENT_TYPE_MISSION_DONATION};
 use crate::services::addons::db_logger::user_flag::parse_user_from_flag;
+use crate::services::ai::sentiment_analyzer::{AnalysisResult, Sentiment, SentimentMethod};
 use crate::services::db::commands::{
     BroadcastSessionResult, BroadcastSessionSearchFilters, BroadcastSessionSearchResult,
-    ChannelData, ChatLogData, ChatLogResult, ChatSearchFilters, ChatSearchResult, EventLogData,
-    EventLogResult, EventSearchFilters, EventSearchResult, PaginationParams, ReportInfo,
-    ReportStatusInfo, TargetUser, UserLogEntry, UserSearchFilters, UserSearchResult,
+    ChannelData, ChatLogCursor, ChatLogData, ChatLogResult, ChatSearchFilters, ChatSearchResult,
+    ChatSentimentData, ChatSentimentResult, ChatToxicityData, ChatToxicityResult,
+    DatabaseSubsetFilters, EventLogData, EventLogResult, EventSearchFilters, EventSearchResult,
+    PaginationParams, ReportInfo, ReportStatusInfo, SentimentBackfillJobInfo, StatsSnapshotData,
+    StatsSnapshotResult, TargetUser, UserLogEntry, UserSearchFilters, UserSearchResult,
 };
 use crate::util::hangul::decompose_hangul_to_string;
 
+use super::initialization::DBInitializer;
+
 pub struct CommandHandlers<'a> {
     conn: &'a Connection,
 }
@@ -1068,6 +1074,17 @@ impl<'a> CommandHandlers<'a> {
             return Err("Foreign key violations detected".to_string());
         }
 
+        // 외래 키가 없는 채팅별 분석 결과는 CASCADE로 지워지지 않으므로 먼저 삭제
+        self.conn.execute(
+            "DELETE FROM chat_toxicity WHERE chat_log_id IN (SELECT id FROM chat_logs WHERE broadcast_id = ?1)",
+            [broadcast_id]
+        ).map_err(|e| format!("Chat toxicity deletion failed: {}", e))?;
+
+        self.conn.execute(
+            "DELETE FROM chat_sentiment WHERE chat_log_id IN (SELECT id FROM chat_logs WHERE broadcast_id = ?1)",
+            [broadcast_id]
+        ).map_err(|e| format!("Chat sentiment deletion failed: {}", e))?;
+
         // 정상적인 CASCADE DELETE 시도
         let rows_affected = self
             .conn
@@ -1140,6 +1157,16 @@ impl<'a> CommandHandlers<'a> {
             [broadcast_id]
         ).map_err(|e| format!("FTS deletion failed: {}", e))?;
 
+        self.conn.execute(
+            "DELETE FROM chat_toxicity WHERE chat_log_id IN (SELECT id FROM chat_logs WHERE broadcast_id = ?1)",
+            [broadcast_id]
+        ).map_err(|e| format!("Chat toxicity deletion failed: {}", e))?;
+
+        self.conn.execute(
+            "DELETE FROM chat_sentiment WHERE chat_log_id IN (SELECT id FROM chat_logs WHERE broadcast_id = ?1)",
+            [broadcast_id]
+        ).map_err(|e| format!("Chat sentiment deletion failed: {}", e))?;
+
         // 3. 관련 테이블 순차 삭제
         self.conn
             .execute(
@@ -1162,6 +1189,13 @@ impl<'a> CommandHandlers<'a> {
             )
             .map_err(|e| format!("Reports deletion failed: {}", e))?;
 
+        self.conn
+            .execute(
+                "DELETE FROM stats_snapshots WHERE broadcast_id = ?1",
+                [broadcast_id],
+            )
+            .map_err(|e| format!("Stats snapshots deletion failed: {}", e))?;
+
         // 4. 방송 세션 삭제
         let rows_affected = self
             .conn
@@ -1530,6 +1564,15 @@ impl<'a> CommandHandlers<'a> {
         let _ = reply_to.send(result);
     }
 
+    pub fn handle_get_previous_donor_ids(
+        &mut self,
+        broadcast_id: i64,
+        reply_to: oneshot::Sender<Result<Vec<String>, String>>,
+    ) {
+        let result = self.get_previous_donor_ids(broadcast_id);
+        let _ = reply_to.send(result);
+    }
+
     // 실제 DB 작업 메서드들
     fn create_report(&mut self, broadcast_id: i64) -> Result<(), String> {
         let query = r#"
@@ -1762,6 +1805,449 @@ impl<'a> CommandHandlers<'a> {
             .map_err(|e| e.to_string())
     }
 
+    fn get_previous_donor_ids(&mut self, broadcast_id: i64) -> Result<Vec<String>, String> {
+        let query = r#"
+            SELECT DISTINCT el.user_id
+            FROM event_logs el
+            JOIN broadcast_sessions bs ON el.broadcast_id = bs.id
+            JOIN broadcast_sessions cur ON cur.id = ?1
+            WHERE bs.channel_id = cur.channel_id
+              AND bs.started_at < cur.started_at
+              AND el.event_type IN (?2, ?3)
+              AND el.user_id IS NOT NULL
+        "#;
+
+        let mut stmt = self.conn.prepare_cached(query).map_err(|e| e.to_string())?;
+
+        let rows = stmt
+            .query_map(
+                (
+                    broadcast_id,
+                    EVENT_TYPE_DONATION,
+                    EVENT_TYPE_MISSION_DONATION,
+                ),
+                |row| row.get::<_, String>(0),
+            )
+            .map_err(|e| e.to_string())?;
+
+        rows.collect::<Result<Vec<_>, _>>()
+            .map_err(|e| e.to_string())
+    }
+
+    pub fn handle_insert_stats_snapshots(
+        &self,
+        snapshots: Vec<StatsSnapshotData>,
+        reply_to: oneshot::Sender<Result<(), String>>,
+    ) {
+        let result = self.insert_stats_snapshots(snapshots);
+        let _ = reply_to.send(result);
+    }
+
+    fn insert_stats_snapshots(&self, snapshots: Vec<StatsSnapshotData>) -> Result<(), String> {
+        self.conn
+            .execute("BEGIN TRANSACTION", [])
+            .and_then(|_| {
+                let mut stmt = self.conn.prepare_cached(
+                    "INSERT INTO stats_snapshots (broadcast_id, stats_type, payload, timestamp) VALUES (?1, ?2, ?3, ?4)",
+                )?;
+
+                for snapshot in snapshots {
+                    stmt.execute((
+                        snapshot.broadcast_id,
+                        snapshot.stats_type,
+                        snapshot.payload,
+                        snapshot.timestamp.to_rfc3339(),
+                    ))?;
+                }
+
+                self.conn.execute("COMMIT", [])?;
+                Ok(())
+            })
+            .map_err(|e| {
+                let _ = self.conn.execute("ROLLBACK", []);
+                e.to_string()
+            })
+    }
+
+    pub fn handle_get_stats_snapshots(
+        &self,
+        broadcast_id: i64,
+        stats_type: Option<String>,
+        reply_to: oneshot::Sender<Result<Vec<StatsSnapshotResult>, String>>,
+    ) {
+        let result = self.get_stats_snapshots(broadcast_id, stats_type);
+        let _ = reply_to.send(result);
+    }
+
+    fn get_stats_snapshots(
+        &self,
+        broadcast_id: i64,
+        stats_type: Option<String>,
+    ) -> Result<Vec<StatsSnapshotResult>, String> {
+        let query = r#"
+            SELECT stats_type, payload, timestamp
+            FROM stats_snapshots
+            WHERE broadcast_id = ?1 AND (?2 IS NULL OR stats_type = ?2)
+            ORDER BY timestamp ASC
+        "#;
+
+        let mut stmt = self.conn.prepare_cached(query).map_err(|e| e.to_string())?;
+
+        let rows = stmt
+            .query_map((broadcast_id, stats_type), |row| {
+                let payload: String = row.get(1)?;
+                Ok(StatsSnapshotResult {
+                    stats_type: row.get(0)?,
+                    payload: serde_json::from_str(&payload).unwrap_or(serde_json::Value::Null),
+                    timestamp: DateTime::parse_from_rfc3339(&row.get::<_, String>(2)?)
+                        .unwrap()
+                        .with_timezone(&Utc),
+                })
+            })
+            .map_err(|e| e.to_string())?;
+
+        rows.collect::<Result<Vec<_>, _>>()
+            .map_err(|e| e.to_string())
+    }
+
+    pub fn handle_insert_chat_toxicity(
+        &self,
+        items: Vec<ChatToxicityData>,
+        reply_to: oneshot::Sender<Result<(), String>>,
+    ) {
+        let result = self.insert_chat_toxicity(items);
+        let _ = reply_to.send(result);
+    }
+
+    fn insert_chat_toxicity(&self, items: Vec<ChatToxicityData>) -> Result<(), String> {
+        self.conn
+            .execute("BEGIN TRANSACTION", [])
+            .and_then(|_| {
+                let mut stmt = self.conn.prepare_cached(
+                    "INSERT OR REPLACE INTO chat_toxicity (chat_log_id, scores, max_label, max_score) VALUES (?1, ?2, ?3, ?4)",
+                )?;
+
+                for item in items {
+                    let (max_label, max_score) = item
+                        .result
+                        .max()
+                        .map(|max| (max.label.clone(), max.score))
+                        .unwrap_or_default();
+                    let scores = serde_json::to_string(&item.result.scores)
+                        .unwrap_or_else(|_| "[]".to_string());
+                    stmt.execute((item.chat_log_id, scores, max_label, max_score))?;
+                }
+
+                self.conn.execute("COMMIT", [])?;
+                Ok(())
+            })
+            .map_err(|e| {
+                let _ = self.conn.execute("ROLLBACK", []);
+                e.to_string()
+            })
+    }
+
+    pub fn handle_get_chat_toxicity(
+        &self,
+        broadcast_id: i64,
+        reply_to: oneshot::Sender<Result<Vec<ChatToxicityResult>, String>>,
+    ) {
+        let result = self.get_chat_toxicity(broadcast_id);
+        let _ = reply_to.send(result);
+    }
+
+    fn get_chat_toxicity(&self, broadcast_id: i64) -> Result<Vec<ChatToxicityResult>, String> {
+        let query = r#"
+            SELECT ct.chat_log_id, ct.scores, ct.max_label, ct.max_score
+            FROM chat_toxicity ct
+            JOIN chat_logs cl ON ct.chat_log_id = cl.id
+            WHERE cl.broadcast_id = ?1
+            ORDER BY cl.timestamp ASC
+        "#;
+
+        let mut stmt = self.conn.prepare_cached(query).map_err(|e| e.to_string())?;
+
+        let rows = stmt
+            .query_map([broadcast_id], |row| {
+                let scores: String = row.get(1)?;
+                Ok(ChatToxicityResult {
+                    chat_log_id: row.get(0)?,
+                    scores: serde_json::from_str(&scores).unwrap_or_default(),
+                    max_label: row.get(2)?,
+                    max_score: row.get(3)?,
+                })
+            })
+            .map_err(|e| e.to_string())?;
+
+        rows.collect::<Result<Vec<_>, _>>()
+            .map_err(|e| e.to_string())
+    }
+
+    pub fn handle_insert_chat_sentiment(
+        &self,
+        items: Vec<ChatSentimentData>,
+        reply_to: oneshot::Sender<Result<(), String>>,
+    ) {
+        let result = self.insert_chat_sentiment(items);
+        let _ = reply_to.send(result);
+    }
+
+    fn insert_chat_sentiment(&self, items: Vec<ChatSentimentData>) -> Result<(), String> {
+        self.conn
+            .execute("BEGIN TRANSACTION", [])
+            .and_then(|_| {
+                let mut stmt = self.conn.prepare_cached(
+                    "INSERT OR REPLACE INTO chat_sentiment (chat_log_id, sentiment, score, method, model_version) VALUES (?1, ?2, ?3, ?4, ?5)",
+                )?;
+
+                for item in items {
+                    stmt.execute((
+                        item.chat_log_id,
+                        format!("{:?}", item.result.sentiment),
+                        item.result.score,
+                        format!("{:?}", item.result.method),
+                        item.model_version,
+                    ))?;
+                }
+
+                self.conn.execute("COMMIT", [])?;
+                Ok(())
+            })
+            .map_err(|e| {
+                let _ = self.conn.execute("ROLLBACK", []);
+                e.to_string()
+            })
+    }
+
+    pub fn handle_get_chat_sentiment(
+        &self,
+        broadcast_id: i64,
+        reply_to: oneshot::Sender<Result<Vec<ChatSentimentResult>, String>>,
+    ) {
+        let result = self.get_chat_sentiment(broadcast_id);
+        let _ = reply_to.send(result);
+    }
+
+    fn get_chat_sentiment(&self, broadcast_id: i64) -> Result<Vec<ChatSentimentResult>, String> {
+        let query = r#"
+            SELECT cs.chat_log_id, cs.sentiment, cs.score, cs.method, cs.model_version
+            FROM chat_sentiment cs
+            JOIN chat_logs cl ON cs.chat_log_id = cl.id
+            WHERE cl.broadcast_id = ?1
+            ORDER BY cl.timestamp ASC
+        "#;
+
+        let mut stmt = self.conn.prepare_cached(query).map_err(|e| e.to_string())?;
+
+        let rows = stmt
+            .query_map([broadcast_id], |row| {
+                let sentiment: String = row.get(1)?;
+                let method: String = row.get(3)?;
+                Ok(ChatSentimentResult {
+                    chat_log_id: row.get(0)?,
+                    result: AnalysisResult {
+                        sentiment: Sentiment::from(sentiment.as_str()),
+                        score: row.get(2)?,
+                        method: SentimentMethod::from(method.as_str()),
+                    },
+                    model_version: row.get(4)?,
+                })
+            })
+            .map_err(|e| e.to_string())?;
+
+        rows.collect::<Result<Vec<_>, _>>()
+            .map_err(|e| e.to_string())
+    }
+
+    pub fn handle_get_chat_logs_without_sentiment(
+        &self,
+        broadcast_id: i64,
+        cursor: Option<ChatLogCursor>,
+        limit: i64,
+        model_version: Option<String>,
+        reply_to: oneshot::Sender<Result<Vec<ChatLogResult>, String>>,
+    ) {
+        let result =
+            self.get_chat_logs_without_sentiment(broadcast_id, cursor, limit, model_version);
+        let _ = reply_to.send(result);
+    }
+
+    fn get_chat_logs_without_sentiment(
+        &self,
+        broadcast_id: i64,
+        cursor: Option<ChatLogCursor>,
+        limit: i64,
+        model_version: Option<String>,
+    ) -> Result<Vec<ChatLogResult>, String> {
+        // 모델이 있으면 사전 기반(model_version 없음)이나 다른 모델로 분석된 채팅도 다시 분석합니다.
+        let query = r#"
+            SELECT cl.id, cl.broadcast_id, cl.user_id, cl.username, cl.user_flag, cl.message_type,
+                    cl.message, cl.metadata, cl.timestamp, c.channel_id, c.channel_name, bs.title
+            FROM chat_logs cl
+            JOIN broadcast_sessions bs ON cl.broadcast_id = bs.id
+            JOIN channels c ON bs.channel_id = c.channel_id
+            LEFT JOIN chat_sentiment cs ON cs.chat_log_id = cl.id
+            WHERE cl.broadcast_id = ?1
+                AND (cs.chat_log_id IS NULL OR (?5 IS NOT NULL AND cs.model_version IS NOT ?5))
+                AND (cl.timestamp > ?2 OR (cl.timestamp = ?2 AND cl.id > ?3))
+            ORDER BY cl.timestamp ASC, cl.id ASC
+            LIMIT ?4
+        "#;
+
+        // 첫 페이지는 모든 채팅보다 앞선 위치에서 시작합니다.
+        let (cursor_timestamp, cursor_id) = cursor
+            .map(|cursor| (cursor.timestamp.to_rfc3339(), cursor.id))
+            .unwrap_or_default();
+
+        let params = (
+            broadcast_id,
+            cursor_timestamp,
+            cursor_id,
+            limit,
+            model_version,
+        );
+
+        let mut stmt = self.conn.prepare_cached(query).map_err(|e| e.to_string())?;
+
+        let rows = stmt
+            .query_map(params, |row| {
+                let user_id: String = row.get(2)?;
+                let username: String = row.get(3)?;
+                let user_flag: u32 = row.get(4)?;
+
+                Ok(ChatLogResult {
+                    id: row.get(0)?,
+                    broadcast_id: row.get(1)?,
+                    user: parse_user_from_flag(user_flag, user_id, username),
+                    message_type: row.get(5)?,
+                    message: row.get(6)?,
+                    metadata: {
+                        let metadata_str: String = row.get(7)?;
+                        if metadata_str.is_empty() {
+                            None
+                        } else {
+                            serde_json::from_str(&metadata_str).ok()
+                        }
+                    },
+                    timestamp: DateTime::parse_from_rfc3339(&row.get::<_, String>(8)?)
+                        .unwrap()
+                        .with_timezone(&Utc),
+                    channel_id: row.get(9)?,
+                    channel_name: row.get(10)?,
+                    broadcast_title: row.get(11)?,
+                })
+            })
+            .map_err(|e| e.to_string())?;
+
+        rows.collect::<Result<Vec<_>, _>>()
+            .map_err(|e| e.to_string())
+    }
+
+    pub fn handle_upsert_sentiment_backfill_job(
+        &self,
+        broadcast_id: i64,
+        status: String,
+        progress_percentage: Option<f64>,
+        error_message: Option<String>,
+        reply_to: oneshot::Sender<Result<(), String>>,
+    ) {
+        let result = self.upsert_sentiment_backfill_job(
+            broadcast_id,
+            status,
+            progress_percentage,
+            error_message,
+        );
+        let _ = reply_to.send(result);
+    }
+
+    fn upsert_sentiment_backfill_job(
+        &self,
+        broadcast_id: i64,
+        status: String,
+        progress_percentage: Option<f64>,
+        error_message: Option<String>,
+    ) -> Result<(), String> {
+        let query = r#"
+            INSERT INTO sentiment_backfill_jobs (broadcast_id, status, progress_percentage, error_message, updated_at)
+            VALUES (?1, ?2, ?3, ?4, CURRENT_TIMESTAMP)
+            ON CONFLICT(broadcast_id) DO UPDATE SET
+                status = excluded.status,
+                progress_percentage = excluded.progress_percentage,
+                error_message = excluded.error_message,
+                updated_at = excluded.updated_at
+        "#;
+
+        self.conn
+            .execute(
+                query,
+                (broadcast_id, status, progress_percentage, error_message),
+            )
+            .map_err(|e| e.to_string())?;
+
+        Ok(())
+    }
+
+    pub fn handle_get_sentiment_backfill_job(
+        &self,
+        broadcast_id: i64,
+        model_version: Option<String>,
+        reply_to: oneshot::Sender<Result<Option<SentimentBackfillJobInfo>, String>>,
+    ) {
+        let result = self.get_sentiment_backfill_job(broadcast_id, model_version);
+        let _ = reply_to.send(result);
+    }
+
+    fn get_sentiment_backfill_job(
+        &self,
+        broadcast_id: i64,
+        model_version: Option<String>,
+    ) -> Result<Option<SentimentBackfillJobInfo>, String> {
+        let query = r#"
+            SELECT j.broadcast_id, j.status, j.progress_percentage, j.error_message,
+                (SELECT COUNT(*) FROM chat_logs cl WHERE cl.broadcast_id = j.broadcast_id),
+                (SELECT COUNT(*) FROM chat_sentiment cs
+                    JOIN chat_logs cl ON cs.chat_log_id = cl.id
+                    WHERE cl.broadcast_id = j.broadcast_id
+                        AND (?2 IS NULL OR cs.model_version = ?2))
+            FROM sentiment_backfill_jobs j
+            WHERE j.broadcast_id = ?1
+        "#;
+
+        let mut stmt = self.conn.prepare_cached(query).map_err(|e| e.to_string())?;
+
+        let result = stmt.query_row((broadcast_id, model_version), |row| {
+            Ok(SentimentBackfillJobInfo {
+                broadcast_id: row.get(0)?,
+                status: row.get(1)?,
+                progress_percentage: row.get(2)?,
+                error_message: row.get(3)?,
+                total_count: row.get(4)?,
+                analyzed_count: row.get(5)?,
+            })
+        });
+
+        match result {
+            Ok(job) => Ok(Some(job)),
+            Err(rusqlite::Error::QueryReturnedNoRows) => Ok(None),
+            Err(e) => Err(e.to_string()),
+        }
+    }
+
+    pub fn handle_cancel_interrupted_sentiment_backfill_jobs(
+        &self,
+        reply_to: oneshot::Sender<Result<(), String>>,
+    ) {
+        let result = self
+            .conn
+            .execute(
+                "UPDATE sentiment_backfill_jobs SET status = 'CANCELLED', updated_at = CURRENT_TIMESTAMP WHERE status IN ('PENDING', 'RUNNING')",
+                [],
+            )
+            .map(|_| ())
+            .map_err(|e| e.to_string());
+        let _ = reply_to.send(result);
+    }
+
     pub fn handle_reset_all_data(&self, reply_to: oneshot::Sender<Result<(), String>>) {
         let result = self.reset_all_tables();
         let _ = reply_to.send(result);
@@ -1779,6 +2265,10 @@ impl<'a> CommandHandlers<'a> {
             "event_logs",
             "target_users",
             "reports",
+            "stats_snapshots",
+            "chat_toxicity",
+            "chat_sentiment",
+            "sentiment_backfill_jobs",
             "broadcast_sessions",
             "channels",
         ];
@@ -1797,6 +2287,217 @@ impl<'a> CommandHandlers<'a> {
         Ok(())
     }
 
+    pub fn handle_create_database_subset(
+        &self,
+        filters: DatabaseSubsetFilters,
+        output_path: String,
+        reply_to: oneshot::Sender<Result<Vec<i64>, String>>,
+    ) {
+        let result = self.create_database_subset(filters, &output_path);
+        let _ = reply_to.send(result);
+    }
+
+    /**
+     * 앱과 같은 스키마의 새 DB 파일을 만들고 대상 방송의 채널, 세션 정보를 복사합니다.
+     * 로그는 양이 많아 다른 명령이 밀리지 않도록 방송 단위로 나눠 복사합니다. (copy_database_subset_broadcast)
+     */
+    fn create_database_subset(
+        &self,
+        filters: DatabaseSubsetFilters,
+        output_path: &str,
+    ) -> Result<Vec<i64>, String> {
+        // 1. 앱과 같은 스키마(FTS 트리거 포함)로 빈 DB 파일 생성
+        if std::path::Path::new(output_path).exists() {
+            std::fs::remove_file(output_path)
+                .map_err(|e| format!("Failed to remove existing file: {}", e))?;
+        }
+        {
+            let subset = Connection::open(output_path)
+                .map_err(|e| format!("Failed to create database file: {}", e))?;
+            DBInitializer::new(&subset)
+                .initialize()
+                .map_err(|e| format!("Failed to initialize database file: {}", e))?;
+            // 파일 하나로 옮길 수 있도록 WAL 모드를 해제합니다.
+            subset
+                .execute_batch("PRAGMA journal_mode = DELETE;")
+                .map_err(|e| format!("Failed to set journal mode: {}", e))?;
+        }
+
+        // 2. 대상 방송 조건
+        let mut where_conditions = Vec::new();
+        let mut param_values = Vec::new();
+
+        if let Some(broadcast_id) = filters.broadcast_id {
+            where_conditions.push("id = ?");
+            param_values.push(broadcast_id.to_string());
+        }
+
+        if let Some(channel_id) = &filters.channel_id {
+            where_conditions.push("channel_id = ?");
+            param_values.push(channel_id.clone());
+        }
+
+        if let Some(start_date) = &filters.start_date {
+            where_conditions.push("started_at >= ?");
+            param_values.push(start_date.to_rfc3339());
+        }
+
+        if let Some(end_date) = &filters.end_date {
+            where_conditions.push("started_at <= ?");
+            param_values.push(end_date.to_rfc3339());
+        }
+
+        let where_clause = if where_conditions.is_empty() {
+            String::new()
+        } else {
+            format!("WHERE {}", where_conditions.join(" AND "))
+        };
+
+        let broadcast_ids = {
+            let mut stmt = self
+                .conn
+                .prepare(&format!(
+                    "SELECT id FROM broadcast_sessions {} ORDER BY id",
+                    where_clause
+                ))
+                .map_err(|e| e.to_string())?;
+            let rows = stmt
+                .query_map(rusqlite::params_from_iter(param_values.iter()), |row| {
+                    row.get::<_, i64>(0)
+                })
+                .map_err(|e| e.to_string())?;
+            rows.collect::<Result<Vec<_>, _>>()
+                .map_err(|e| e.to_string())?
+        };
+        if broadcast_ids.is_empty() {
+            let _ = std::fs::remove_file(output_path);
+            return Err("No broadcast sessions match the export filters".to_string());
+        }
+
+        // 3. 새 파일을 연결해 채널, 세션 정보 복사
+        let ids = broadcast_ids
+            .iter()
+            .map(i64::to_string)
+            .collect::<Vec<_>>()
+            .join(", ");
+        let in_sessions = format!("id IN ({})", ids);
+        let in_channels = format!(
+            "channel_id IN (SELECT channel_id FROM main.broadcast_sessions WHERE id IN ({}))",
+            ids
+        );
+        // 외래 키 순서대로 복사합니다.
+        let tables = [
+            ("channels", in_channels.as_str()),
+            ("broadcast_sessions", in_sessions.as_str()),
+        ];
+
+        let result = self.copy_subset_tables(output_path, &tables, &[]);
+        if result.is_err() {
+            let _ = std::fs::remove_file(output_path);
+        }
+        result.map(|()| broadcast_ids)
+    }
+
+    pub fn handle_copy_database_subset_broadcast(
+        &self,
+        output_path: String,
+        broadcast_id: i64,
+        reply_to: oneshot::Sender<Result<(), String>>,
+    ) {
+        let result = self.copy_database_subset_broadcast(&output_path, broadcast_id);
+        let _ = reply_to.send(result);
+    }
+
+    // create_database_subset으로 만든 파일에 방송 하나의 로그와 분석 결과를 복사
+    fn copy_database_subset_broadcast(
+        &self,
+        output_path: &str,
+        broadcast_id: i64,
+    ) -> Result<(), String> {
+        let in_broadcast = "broadcast_id = ?1";
+        let in_chat_logs = "chat_log_id IN (SELECT id FROM main.chat_logs WHERE broadcast_id = ?1)";
+        // 외래 키 순서대로 복사합니다.
+        let tables = [
+            ("chat_logs", in_broadcast),
+            ("event_logs", in_broadcast),
+            ("reports", in_broadcast),
+            ("stats_snapshots", in_broadcast),
+            ("sentiment_backfill_jobs", in_broadcast),
+            ("chat_toxicity", in_chat_logs),
+            ("chat_sentiment", in_chat_logs),
+        ];
+
+        self.copy_subset_tables(output_path, &tables, &[&broadcast_id])
+    }
+
+    // 새 파일을 연결해 조건에 맞는 행을 한 트랜잭션으로 복사하고 연결을 해제합니다.
+    fn copy_subset_tables(
+        &self,
+        output_path: &str,
+        tables: &[(&str, &str)],
+        params: &[&dyn rusqlite::ToSql],
+    ) -> Result<(), String> {
+        self.conn
+            .execute("ATTACH DATABASE ?1 AS subset", [output_path])
+            .map_err(|e| format!("Failed to attach database file: {}", e))?;
+
+        let result = self.copy_attached_subset_tables(tables, params);
+
+        let _ = self.conn.execute("DETACH DATABASE subset", []);
+        result
+    }
+
+    fn copy_attached_subset_tables(
+        &self,
+        tables: &[(&str, &str)],
+        params: &[&dyn rusqlite::ToSql],
+    ) -> Result<(), String> {
+        self.conn
+            .execute("BEGIN TRANSACTION", [])
+            .map_err(|e| e.to_string())?;
+
+        for (table, condition) in tables {
+            // 원본 DB에 없는 컬럼은 새 파일의 기본값을 사용합니다.
+            let main_columns = self.table_columns("main", table)?;
+            let columns: Vec<String> = self
+                .table_columns("subset", table)?
+                .into_iter()
+                .filter(|column| main_columns.contains(column))
+                .collect();
+            let columns = columns.join(", ");
+
+            let query = format!(
+                "INSERT INTO subset.{table} ({columns}) SELECT {columns} FROM main.{table} WHERE {condition}"
+            );
+            if let Err(e) = self.conn.execute(&query, params) {
+                let _ = self.conn.execute("ROLLBACK", []);
+                return Err(format!("Failed to copy table {}: {}", table, e));
+            }
+        }
+
+        self.conn.execute("COMMIT", []).map_err(|e| {
+            let _ = self.conn.execute("ROLLBACK", []);
+            e.to_string()
+        })?;
+
+        Ok(())
+    }
+
+    fn table_columns(&self, schema: &str, table: &str) -> Result<Vec<String>, String> {
+        let mut stmt = self
+            .conn
+            .prepare(&format!("PRAGMA {}.table_info({})", schema, table))
+            .map_err(|e| e.to_string())?;
+
+        let columns = stmt
+            .query_map([], |row| row.get::<_, String>(1))
+            .map_err(|e| e.to_string())?
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| e.to_string())?;
+
+        Ok(columns)
+    }
+
     // 채널 삭제 구현
     fn delete_channel_impl(&self, channel_id: String) -> Result<(), String> {
         // 1. 채널과 연관된 모든 broadcast_session을 조회
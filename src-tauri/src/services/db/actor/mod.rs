@@ -180,7 +180,82 @@ impl DBActor {
                 end_time,
                 reply_to,
             ),
+            DBCommand::GetPreviousDonorIds {
+                broadcast_id,
+                reply_to,
+            } => handlers.handle_get_previous_donor_ids(broadcast_id, reply_to),
+            DBCommand::InsertStatsSnapshots {
+                snapshots,
+                reply_to,
+            } => handlers.handle_insert_stats_snapshots(snapshots, reply_to),
+            DBCommand::GetStatsSnapshots {
+                broadcast_id,
+                stats_type,
+                reply_to,
+            } => handlers.handle_get_stats_snapshots(broadcast_id, stats_type, reply_to),
+            DBCommand::InsertChatToxicity { items, reply_to } => {
+                handlers.handle_insert_chat_toxicity(items, reply_to)
+            }
+            DBCommand::GetChatToxicity {
+                broadcast_id,
+                reply_to,
+            } => handlers.handle_get_chat_toxicity(broadcast_id, reply_to),
+            DBCommand::InsertChatSentiment { items, reply_to } => {
+                handlers.handle_insert_chat_sentiment(items, reply_to)
+            }
+            DBCommand::GetChatSentiment {
+                broadcast_id,
+                reply_to,
+            } => handlers.handle_get_chat_sentiment(broadcast_id, reply_to),
+            DBCommand::GetChatLogsWithoutSentiment {
+                broadcast_id,
+                cursor,
+                limit,
+                model_version,
+                reply_to,
+            } => handlers.handle_get_chat_logs_without_sentiment(
+                broadcast_id,
+                cursor,
+                limit,
+                model_version,
+                reply_to,
+            ),
+            DBCommand::UpsertSentimentBackfillJob {
+                broadcast_id,
+                status,
+                progress_percentage,
+                error_message,
+                reply_to,
+            } => handlers.handle_upsert_sentiment_backfill_job(
+                broadcast_id,
+                status,
+                progress_percentage,
+                error_message,
+                reply_to,
+            ),
+            DBCommand::GetSentimentBackfillJob {
+                broadcast_id,
+                model_version,
+                reply_to,
+            } => {
+                handlers.handle_get_sentiment_backfill_job(broadcast_id, model_version, reply_to)
+            }
+            DBCommand::CancelInterruptedSentimentBackfillJobs { reply_to } => {
+                handlers.handle_cancel_interrupted_sentiment_backfill_jobs(reply_to)
+            }
             DBCommand::ResetAllData { reply_to } => handlers.handle_reset_all_data(reply_to),
+            DBCommand::CreateDatabaseSubset {
+                filters,
+                output_path,
+                reply_to,
+            } => handlers.handle_create_database_subset(filters, output_path, reply_to),
+            DBCommand::CopyDatabaseSubsetBroadcast {
+                output_path,
+                broadcast_id,
+                reply_to,
+            } => {
+                handlers.handle_copy_database_subset_broadcast(output_path, broadcast_id, reply_to)
+            }
         }
     }
 }
@@ -4,6 +4,8 @@ use soup_sdk::chat::types::User;
 use tokio::sync::oneshot;
 
 use crate::models::reports::ReportData;
+use crate::services::ai::sentiment_analyzer::AnalysisResult;
+use crate::services::ai::toxicity_classifier::{ToxicityResult, ToxicityScore};
 
 #[derive(Debug)]
 pub enum DBCommand {
@@ -151,6 +153,70 @@ pub enum DBCommand {
         end_time: DateTime<Utc>,
         reply_to: oneshot::Sender<Result<Vec<EventLogResult>, String>>,
     },
+    // 같은 채널의 이전 방송에서 후원한 사용자 ID 조회
+    GetPreviousDonorIds {
+        broadcast_id: i64,
+        reply_to: oneshot::Sender<Result<Vec<String>, String>>,
+    },
+
+    // 실시간 통계 스냅샷
+    InsertStatsSnapshots {
+        snapshots: Vec<StatsSnapshotData>,
+        reply_to: oneshot::Sender<Result<(), String>>,
+    },
+    GetStatsSnapshots {
+        broadcast_id: i64,
+        stats_type: Option<String>,
+        reply_to: oneshot::Sender<Result<Vec<StatsSnapshotResult>, String>>,
+    },
+
+    // 채팅 유해 표현 분류 점수
+    InsertChatToxicity {
+        items: Vec<ChatToxicityData>,
+        reply_to: oneshot::Sender<Result<(), String>>,
+    },
+    GetChatToxicity {
+        broadcast_id: i64,
+        reply_to: oneshot::Sender<Result<Vec<ChatToxicityResult>, String>>,
+    },
+
+    // 채팅별 감정 분석 결과
+    InsertChatSentiment {
+        items: Vec<ChatSentimentData>,
+        reply_to: oneshot::Sender<Result<(), String>>,
+    },
+    GetChatSentiment {
+        broadcast_id: i64,
+        reply_to: oneshot::Sender<Result<Vec<ChatSentimentResult>, String>>,
+    },
+    // 감정 분석 결과가 없는 채팅을 (timestamp, id) 순서로 cursor 이후부터 조회
+    // model_version이 있으면 다른 모델이나 사전 기반으로 분석된 채팅도 포함합니다.
+    GetChatLogsWithoutSentiment {
+        broadcast_id: i64,
+        cursor: Option<ChatLogCursor>,
+        limit: i64,
+        model_version: Option<String>,
+        reply_to: oneshot::Sender<Result<Vec<ChatLogResult>, String>>,
+    },
+
+    // 감정 소급 분석 작업
+    UpsertSentimentBackfillJob {
+        broadcast_id: i64,
+        status: String,
+        progress_percentage: Option<f64>,
+        error_message: Option<String>,
+        reply_to: oneshot::Sender<Result<(), String>>,
+    },
+    // model_version이 있으면 해당 모델로 분석된 채팅만 분석된 것으로 셉니다.
+    GetSentimentBackfillJob {
+        broadcast_id: i64,
+        model_version: Option<String>,
+        reply_to: oneshot::Sender<Result<Option<SentimentBackfillJobInfo>, String>>,
+    },
+    // 앱 종료로 중단된 작업(PENDING, RUNNING)을 CANCELLED로 변경
+    CancelInterruptedSentimentBackfillJobs {
+        reply_to: oneshot::Sender<Result<(), String>>,
+    },
 
     // 사용자 기록 검색 (채팅 로그와 이벤트 로그 통합)
     SearchUserLogs {
@@ -170,6 +236,19 @@ pub enum DBCommand {
     ResetAllData {
         reply_to: oneshot::Sender<Result<(), String>>,
     },
+
+    // 같은 스키마의 새 SQLite 파일을 만들고 대상 방송의 채널/세션 정보를 복사 (대상 방송 ID 반환)
+    CreateDatabaseSubset {
+        filters: DatabaseSubsetFilters,
+        output_path: String,
+        reply_to: oneshot::Sender<Result<Vec<i64>, String>>,
+    },
+    // CreateDatabaseSubset으로 만든 파일에 방송 하나의 로그와 분석 결과를 복사
+    CopyDatabaseSubsetBroadcast {
+        output_path: String,
+        broadcast_id: i64,
+        reply_to: oneshot::Sender<Result<(), String>>,
+    },
 }
 
 #[derive(Debug, Clone, Serialize)]
@@ -246,6 +325,15 @@ pub struct EventSearchFilters {
     pub broadcast_id: Option<i64>,
 }
 
+// SQLite 부분 내보내기 대상 방송 필터 (기간은 방송 시작 시각 기준)
+#[derive(Debug, Clone)]
+pub struct DatabaseSubsetFilters {
+    pub broadcast_id: Option<i64>,
+    pub channel_id: Option<String>,
+    pub start_date: Option<DateTime<Utc>>,
+    pub end_date: Option<DateTime<Utc>>,
+}
+
 // 방송 세션 검색 필터
 #[derive(Debug, Clone, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -331,6 +419,74 @@ pub struct BroadcastSessionResult {
     pub ended_at: Option<DateTime<Utc>>,
 }
 
+#[derive(Debug, Clone)]
+pub struct StatsSnapshotData {
+    pub broadcast_id: i64,
+    pub stats_type: String,
+    pub payload: String,
+    pub timestamp: DateTime<Utc>,
+}
+
+// 실시간 통계 스냅샷 조회 결과
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StatsSnapshotResult {
+    pub stats_type: String,
+    pub payload: serde_json::Value,
+    pub timestamp: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone)]
+pub struct ChatToxicityData {
+    pub chat_log_id: String,
+    pub result: ToxicityResult,
+}
+
+// 채팅 유해 표현 분류 점수 조회 결과
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChatToxicityResult {
+    pub chat_log_id: String,
+    pub scores: Vec<ToxicityScore>,
+    pub max_label: String,
+    pub max_score: f32,
+}
+
+#[derive(Debug, Clone)]
+pub struct ChatSentimentData {
+    pub chat_log_id: String,
+    pub result: AnalysisResult,
+    pub model_version: Option<String>,
+}
+
+// 채팅 감정 분석 결과 조회 결과
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChatSentimentResult {
+    pub chat_log_id: String,
+    pub result: AnalysisResult,
+    pub model_version: Option<String>,
+}
+
+// 채팅 로그 페이지 조회 위치 (마지막으로 읽은 채팅)
+#[derive(Debug, Clone)]
+pub struct ChatLogCursor {
+    pub timestamp: DateTime<Utc>,
+    pub id: String,
+}
+
+// 감정 소급 분석 작업 상태
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SentimentBackfillJobInfo {
+    pub broadcast_id: i64,
+    pub status: String,
+    pub progress_percentage: Option<f64>,
+    pub error_message: Option<String>,
+    pub total_count: i64,
+    pub analyzed_count: i64,
+}
+
 // 채팅 로그 결과
 #[derive(Debug, Clone, Serialize)]
 #[serde(rename_all = "camelCase")]
@@ -6,9 +6,12 @@ use crate::services::db::{
     actor::DBActor,
     commands::{
         BroadcastSessionResult, BroadcastSessionSearchFilters, BroadcastSessionSearchResult,
-        ChannelData, ChatLogData, ChatLogResult, ChatSearchFilters, ChatSearchResult, DBCommand,
-        EventLogData, EventLogResult, EventSearchFilters, EventSearchResult, PaginationParams,
-        ReportInfo, ReportStatusInfo, TargetUser, UserSearchFilters, UserSearchResult,
+        ChannelData, ChatLogCursor, ChatLogData, ChatLogResult, ChatSearchFilters,
+        ChatSearchResult, ChatSentimentData, ChatSentimentResult, ChatToxicityData,
+        ChatToxicityResult, DBCommand, DatabaseSubsetFilters, EventLogData, EventLogResult,
+        EventSearchFilters, EventSearchResult, PaginationParams, ReportInfo, ReportStatusInfo,
+        SentimentBackfillJobInfo, StatsSnapshotData, StatsSnapshotResult, TargetUser,
+        UserSearchFilters, UserSearchResult,
     },
 };
 
@@ -490,6 +493,232 @@ impl DBService {
             .map_err(|_| "Failed to receive response".to_string())?
     }
 
+    pub async fn get_previous_donor_ids(&self, broadcast_id: i64) -> Result<Vec<String>, String> {
+        let (tx, rx) = oneshot::channel();
+        self.sender
+            .send(DBCommand::GetPreviousDonorIds {
+                broadcast_id,
+                reply_to: tx,
+            })
+            .await
+            .map_err(|_| "Failed to send command".to_string())?;
+
+        rx.await
+            .map_err(|_| "Failed to receive response".to_string())?
+    }
+
+    pub async fn insert_stats_snapshots(
+        &self,
+        snapshots: Vec<StatsSnapshotData>,
+    ) -> Result<(), String> {
+        let (tx, rx) = oneshot::channel();
+        self.sender
+            .send(DBCommand::InsertStatsSnapshots {
+                snapshots,
+                reply_to: tx,
+            })
+            .await
+            .map_err(|_| "Failed to send command".to_string())?;
+
+        rx.await
+            .map_err(|_| "Failed to receive response".to_string())?
+    }
+
+    pub async fn get_stats_snapshots(
+        &self,
+        broadcast_id: i64,
+        stats_type: Option<String>,
+    ) -> Result<Vec<StatsSnapshotResult>, String> {
+        let (tx, rx) = oneshot::channel();
+        self.sender
+            .send(DBCommand::GetStatsSnapshots {
+                broadcast_id,
+                stats_type,
+                reply_to: tx,
+            })
+            .await
+            .map_err(|_| "Failed to send command".to_string())?;
+
+        rx.await
+            .map_err(|_| "Failed to receive response".to_string())?
+    }
+
+    pub async fn insert_chat_toxicity(&self, items: Vec<ChatToxicityData>) -> Result<(), String> {
+        let (tx, rx) = oneshot::channel();
+        self.sender
+            .send(DBCommand::InsertChatToxicity {
+                items,
+                reply_to: tx,
+            })
+            .await
+            .map_err(|_| "Failed to send command".to_string())?;
+
+        rx.await
+            .map_err(|_| "Failed to receive response".to_string())?
+    }
+
+    pub async fn get_chat_toxicity(
+        &self,
+        broadcast_id: i64,
+    ) -> Result<Vec<ChatToxicityResult>, String> {
+        let (tx, rx) = oneshot::channel();
+        self.sender
+            .send(DBCommand::GetChatToxicity {
+                broadcast_id,
+                reply_to: tx,
+            })
+            .await
+            .map_err(|_| "Failed to send command".to_string())?;
+
+        rx.await
+            .map_err(|_| "Failed to receive response".to_string())?
+    }
+
+    pub async fn insert_chat_sentiment(&self, items: Vec<ChatSentimentData>) -> Result<(), String> {
+        let (tx, rx) = oneshot::channel();
+        self.sender
+            .send(DBCommand::InsertChatSentiment {
+                items,
+                reply_to: tx,
+            })
+            .await
+            .map_err(|_| "Failed to send command".to_string())?;
+
+        rx.await
+            .map_err(|_| "Failed to receive response".to_string())?
+    }
+
+    pub async fn get_chat_sentiment(
+        &self,
+        broadcast_id: i64,
+    ) -> Result<Vec<ChatSentimentResult>, String> {
+        let (tx, rx) = oneshot::channel();
+        self.sender
+            .send(DBCommand::GetChatSentiment {
+                broadcast_id,
+                reply_to: tx,
+            })
+            .await
+            .map_err(|_| "Failed to send command".to_string())?;
+
+        rx.await
+            .map_err(|_| "Failed to receive response".to_string())?
+    }
+
+    pub async fn get_chat_logs_without_sentiment(
+        &self,
+        broadcast_id: i64,
+        cursor: Option<ChatLogCursor>,
+        limit: i64,
+        model_version: Option<String>,
+    ) -> Result<Vec<ChatLogResult>, String> {
+        let (tx, rx) = oneshot::channel();
+        self.sender
+            .send(DBCommand::GetChatLogsWithoutSentiment {
+                broadcast_id,
+                cursor,
+                limit,
+                model_version,
+                reply_to: tx,
+            })
+            .await
+            .map_err(|_| "Failed to send command".to_string())?;
+
+        rx.await
+            .map_err(|_| "Failed to receive response".to_string())?
+    }
+
+    pub async fn upsert_sentiment_backfill_job(
+        &self,
+        broadcast_id: i64,
+        status: String,
+        progress_percentage: Option<f64>,
+        error_message: Option<String>,
+    ) -> Result<(), String> {
+        let (tx, rx) = oneshot::channel();
+        self.sender
+            .send(DBCommand::UpsertSentimentBackfillJob {
+                broadcast_id,
+                status,
+                progress_percentage,
+                error_message,
+                reply_to: tx,
+            })
+            .await
+            .map_err(|_| "Failed to send command".to_string())?;
+
+        rx.await
+            .map_err(|_| "Failed to receive response".to_string())?
+    }
+
+    pub async fn get_sentiment_backfill_job(
+        &self,
+        broadcast_id: i64,
+        model_version: Option<String>,
+    ) -> Result<Option<SentimentBackfillJobInfo>, String> {
+        let (tx, rx) = oneshot::channel();
+        self.sender
+            .send(DBCommand::GetSentimentBackfillJob {
+                broadcast_id,
+                model_version,
+                reply_to: tx,
+            })
+            .await
+            .map_err(|_| "Failed to send command".to_string())?;
+
+        rx.await
+            .map_err(|_| "Failed to receive response".to_string())?
+    }
+
+    pub async fn cancel_interrupted_sentiment_backfill_jobs(&self) -> Result<(), String> {
+        let (tx, rx) = oneshot::channel();
+        self.sender
+            .send(DBCommand::CancelInterruptedSentimentBackfillJobs { reply_to: tx })
+            .await
+            .map_err(|_| "Failed to send command".to_string())?;
+
+        rx.await
+            .map_err(|_| "Failed to receive response".to_string())?
+    }
+
+    pub async fn create_database_subset(
+        &self,
+        filters: DatabaseSubsetFilters,
+        output_path: String,
+    ) -> Result<Vec<i64>, String> {
+        let (tx, rx) = oneshot::channel();
+        self.sender
+            .send(DBCommand::CreateDatabaseSubset {
+                filters,
+                output_path,
+                reply_to: tx,
+            })
+            .await
+            .map_err(|_| "Failed to send command".to_string())?;
+
+        rx.await
+            .map_err(|_| "Failed to receive response".to_string())?
+    }
+
+    pub async fn copy_database_subset_broadcast(
+        &self,
+        output_path: String,
+        broadcast_id: i64,
+    ) -> Result<(), String> {
+        let (tx, rx) = oneshot::channel();
+        self.sender
+            .send(DBCommand::CopyDatabaseSubsetBroadcast {
+                output_path,
+                broadcast_id,
+                reply_to: tx,
+            })
+            .await
+            .map_err(|_| "Failed to send command".to_string())?;
+
+        rx.await
+            .map_err(|_| "Failed to receive response".to_string())?
+    }
+
     pub async fn reset_all_data(&self) -> Result<(), String> {
         let (tx, rx) = oneshot::channel();
         self.sender
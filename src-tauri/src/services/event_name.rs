@@ -4,3 +4,8 @@ pub const LOG_CHAT: EventName = "log:chat";
 pub const LOG_EVENT: EventName = "log:event";
 pub const LOG_STATS: EventName = "log:stats";
 pub const DISCONNECT_EVENT: EventName = "disconnect";
+pub const REPORT_COMPLETED: EventName = "report:completed";
+pub const REPORT_FAILED: EventName = "report:failed";
+pub const SENTIMENT_BACKFILL_PROGRESS: EventName = "sentiment_backfill:progress";
+pub const SENTIMENT_BACKFILL_COMPLETED: EventName = "sentiment_backfill:completed";
+pub const SENTIMENT_BACKFILL_FAILED: EventName = "sentiment_backfill:failed";
@@ -4,4 +4,8 @@ pub mod csv_exporter;
 pub mod db;
 pub mod event_mapper;
 pub mod event_name;
+pub mod report_exporter;
+pub mod report_queue;
+pub mod sentiment_backfill;
 pub mod stats;
+pub mod subtitle_exporter;
@@ -0,0 +1,131 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::models::reports::{FreezeInterval, ReportStatus, TopicTerm};
+use crate::services::db::service::DBService;
+use crate::services::report_exporter::{
+    html_renderer::render_html, markdown_renderer::render_markdown,
+};
+
+pub const REPORT_EXPORT_FORMAT_HTML: &str = "HTML";
+pub const REPORT_EXPORT_FORMAT_MARKDOWN: &str = "MARKDOWN";
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReportExportOptions {
+    pub broadcast_id: i64,
+    /**
+     * HTML, MARKDOWN 중 하나입니다.
+     */
+    pub format: String,
+    pub output_path: String,
+}
+
+#[derive(Debug)]
+pub struct ReportExporter {
+    db_service: DBService,
+}
+
+impl ReportExporter {
+    pub fn new(db_service: DBService) -> Self {
+        Self { db_service }
+    }
+
+    pub async fn export_report(&self, options: ReportExportOptions) -> Result<String, String> {
+        let report = self
+            .db_service
+            .get_report(options.broadcast_id)
+            .await?
+            .ok_or_else(|| format!("Report not found: {}", options.broadcast_id))?;
+
+        if ReportStatus::from(report.status.clone()) != ReportStatus::Completed {
+            return Err(format!("Report is not completed: {}", report.status));
+        }
+
+        let report_data = report
+            .report_data
+            .ok_or_else(|| "Report data is missing or invalid".to_string())?;
+
+        let session = self
+            .db_service
+            .get_broadcast_session(options.broadcast_id)
+            .await?
+            .ok_or_else(|| format!("Broadcast session not found: {}", options.broadcast_id))?;
+
+        let content = match options.format.as_str() {
+            REPORT_EXPORT_FORMAT_HTML => render_html(&session, &report_data),
+            REPORT_EXPORT_FORMAT_MARKDOWN => render_markdown(&session, &report_data),
+            _ => return Err(format!("Unsupported report format: {}", options.format)),
+        };
+
+        std::fs::write(&options.output_path, content).map_err(|e| {
+            let error = format!("Failed to write report file: {}", e);
+            println!("ReportExporter: {}", error);
+            error
+        })?;
+
+        Ok(options.output_path)
+    }
+}
+
+/// 주제 구간의 핵심 단어를 쉼표로 이어 붙입니다.
+pub fn join_key_terms(terms: &[TopicTerm]) -> String {
+    terms
+        .iter()
+        .map(|term| term.word.as_str())
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// 방송 시작 시각부터 지난 시간을 00:00:00 형식으로 표시합니다.
+pub fn format_offset(start_time: DateTime<Utc>, timestamp: DateTime<Utc>) -> String {
+    format_duration(
+        timestamp
+            .signed_duration_since(start_time)
+            .num_seconds()
+            .max(0) as u64,
+    )
+}
+
+/// 관리 조치 종류(ModerationAction::action_type)의 표시 이름
+pub fn moderation_action_label(action_type: &str) -> &str {
+    match action_type {
+        "Mute" => "채팅 금지",
+        "Kick" => "강제 퇴장",
+        "KickCancel" => "강제 퇴장 취소",
+        "Black" => "블랙",
+        _ => action_type,
+    }
+}
+
+/// 얼리기 조건 (구독 개월 수, 별풍선 개수)을 이어 붙입니다. 조건이 없으면 "-"입니다.
+pub fn freeze_condition(interval: &FreezeInterval) -> String {
+    let mut conditions = Vec::new();
+    if interval.limit_subscription_month > 0 {
+        conditions.push(format!(
+            "구독 {}개월 이상",
+            interval.limit_subscription_month
+        ));
+    }
+    if interval.limit_balloons > 0 {
+        conditions.push(format!("별풍선 {}개 이상", interval.limit_balloons));
+    }
+    if !interval.targets.is_empty() {
+        conditions.push(interval.targets.join(", "));
+    }
+
+    if conditions.is_empty() {
+        "-".to_string()
+    } else {
+        conditions.join(" · ")
+    }
+}
+
+pub fn format_duration(total_seconds: u64) -> String {
+    format!(
+        "{:02}:{:02}:{:02}",
+        total_seconds / 3600,
+        (total_seconds % 3600) / 60,
+        total_seconds % 60
+    )
+}
@@ -0,0 +1,604 @@
+use std::fmt::Write;
+
+use crate::models::reports::{ReportChunk, ReportData, SentimentMoment};
+use crate::services::ai::sentiment_analyzer::SentimentMethod;
+use crate::services::db::commands::BroadcastSessionResult;
+use crate::services::report_exporter::{
+    exporter::{
+        format_duration, format_offset, freeze_condition, join_key_terms, moderation_action_label,
+    },
+    svg_chart::{escape_xml, render_chart, ChartKind},
+};
+
+/// 순위 표에 보여줄 최대 항목 수
+const RANK_LIMIT: usize = 10;
+
+const STYLE: &str = r#"
+body { font-family: -apple-system, 'Apple SD Gothic Neo', 'Malgun Gothic', sans-serif; max-width: 960px; margin: 0 auto; padding: 24px; color: #222; }
+h1 { margin-bottom: 4px; }
+.meta { color: #666; margin-bottom: 24px; }
+.cards { display: grid; grid-template-columns: repeat(4, 1fr); gap: 12px; }
+.card { border: 1px solid #e5e5e5; border-radius: 8px; padding: 12px; }
+.card .label { color: #666; font-size: 13px; }
+.card .value { font-size: 22px; font-weight: bold; }
+section { margin-top: 32px; }
+table { width: 100%; border-collapse: collapse; }
+th, td { text-align: left; padding: 6px 8px; border-bottom: 1px solid #eee; }
+td.num { text-align: right; }
+"#;
+
+/**
+ * 외부 리소스 없이 단독으로 열 수 있는 HTML 리포트를 생성합니다.
+ */
+pub fn render_html(session: &BroadcastSessionResult, report: &ReportData) -> String {
+    let mut html = String::new();
+    let title = escape_xml(&session.title);
+
+    let _ = write!(
+        html,
+        r#"<!DOCTYPE html><html lang="ko"><head><meta charset="utf-8"><title>{title}</title><style>{STYLE}</style></head><body>"#
+    );
+    let _ = write!(
+        html,
+        r#"<h1>{title}</h1><div class="meta">{} · {} · {}</div>"#,
+        escape_xml(&session.channel_name),
+        report.metadata.start_time.format("%Y-%m-%d %H:%M"),
+        format_duration(report.metadata.duration_seconds)
+    );
+
+    render_summary(&mut html, report);
+    render_charts(&mut html, &report.chunks);
+    render_topics(&mut html, report);
+    render_chat_summary(&mut html, report);
+    render_sentiment(&mut html, report);
+    render_rankings(&mut html, report);
+    render_donation(&mut html, report);
+    render_emoticons(&mut html, report);
+    render_toxicity(&mut html, report);
+    render_moderation(&mut html, report);
+
+    html.push_str("</body></html>");
+    html
+}
+
+fn render_summary(html: &mut String, report: &ReportData) {
+    let cards = [
+        ("총 채팅", report.chat_analysis.total_count.to_string()),
+        ("참여 시청자", report.user_analysis.unique.total.to_string()),
+        (
+            "별풍선",
+            report.event_analysis.total_donation_amount.to_string(),
+        ),
+        (
+            "구독",
+            report.event_analysis.total_subscribe_count.to_string(),
+        ),
+    ];
+
+    html.push_str(r#"<div class="cards">"#);
+    for (label, value) in cards {
+        let _ = write!(
+            html,
+            r#"<div class="card"><div class="label">{label}</div><div class="value">{value}</div></div>"#
+        );
+    }
+    html.push_str("</div>");
+}
+
+fn render_charts(html: &mut String, chunks: &[ReportChunk]) {
+    let labels: Vec<String> = chunks
+        .iter()
+        .map(|c| c.relative_timestamp.clone())
+        .collect();
+
+    let mut charts = vec![
+        (
+            "채팅 수",
+            ChartKind::Line,
+            "#3b82f6",
+            chunks
+                .iter()
+                .map(|c| c.chat.total_count as f64)
+                .collect::<Vec<_>>(),
+        ),
+        (
+            "채팅 참여자 수",
+            ChartKind::Line,
+            "#10b981",
+            chunks.iter().map(|c| c.user.unique_count as f64).collect(),
+        ),
+        (
+            "후원 금액",
+            ChartKind::Bar,
+            "#f59e0b",
+            chunks
+                .iter()
+                .map(|c| (c.event.donation_amount + c.event.mission_donation_amount) as f64)
+                .collect(),
+        ),
+    ];
+
+    if chunks.iter().any(|c| c.viewer_count.is_some()) {
+        charts.push((
+            "시청자 수",
+            ChartKind::Line,
+            "#8b5cf6",
+            chunks
+                .iter()
+                .map(|c| c.viewer_count.unwrap_or(0) as f64)
+                .collect(),
+        ));
+    }
+
+    if chunks.iter().any(|c| c.active_viewer_count.is_some()) {
+        charts.push((
+            "활성 시청자 수",
+            ChartKind::Line,
+            "#06b6d4",
+            chunks
+                .iter()
+                .map(|c| c.active_viewer_count.unwrap_or(0) as f64)
+                .collect(),
+        ));
+    }
+
+    if chunks.iter().any(|c| c.sentiment.is_some()) {
+        let is_lexicon = chunks.iter().any(|c| {
+            c.sentiment
+                .as_ref()
+                .is_some_and(|s| s.method == SentimentMethod::Lexicon)
+        });
+        charts.push((
+            if is_lexicon {
+                "평균 감정 점수 (사전 기반)"
+            } else {
+                "평균 감정 점수"
+            },
+            ChartKind::Bar,
+            "#ef4444",
+            chunks
+                .iter()
+                .map(|c| c.sentiment.as_ref().map_or(0.0, |s| s.average_score))
+                .collect(),
+        ));
+    }
+
+    for (title, kind, color, values) in charts {
+        let _ = write!(
+            html,
+            "<section><h2>{}</h2>{}</section>",
+            title,
+            render_chart(kind, &labels, &values, color)
+        );
+    }
+}
+
+fn render_table(html: &mut String, title: &str, headers: &[&str], rows: Vec<Vec<String>>) {
+    if rows.is_empty() {
+        return;
+    }
+
+    let _ = write!(html, "<section><h2>{}</h2><table><tr>", title);
+    for header in headers {
+        let _ = write!(html, "<th>{}</th>", header);
+    }
+    html.push_str("</tr>");
+    for row in rows {
+        html.push_str("<tr>");
+        for (index, cell) in row.iter().enumerate() {
+            // 첫 번째 열을 제외한 나머지는 수치 열입니다.
+            let class = if index == 0 { "" } else { r#" class="num""# };
+            let _ = write!(html, "<td{}>{}</td>", class, escape_xml(cell));
+        }
+        html.push_str("</tr>");
+    }
+    html.push_str("</table></section>");
+}
+
+fn render_rankings(html: &mut String, report: &ReportData) {
+    render_table(
+        html,
+        "채팅 순위",
+        &["닉네임", "채팅 수"],
+        report
+            .chat_analysis
+            .top_chatters
+            .iter()
+            .take(RANK_LIMIT)
+            .map(|c| vec![c.user.label.clone(), c.message_count.to_string()])
+            .collect(),
+    );
+    render_table(
+        html,
+        "후원 순위",
+        &["닉네임", "후원 횟수", "금액", "미션 금액"],
+        report
+            .event_analysis
+            .top_donators
+            .iter()
+            .take(RANK_LIMIT)
+            .map(|d| {
+                vec![
+                    d.user_label.clone(),
+                    d.donation_count.to_string(),
+                    d.total_amount.to_string(),
+                    d.mission_amount.to_string(),
+                ]
+            })
+            .collect(),
+    );
+    render_table(
+        html,
+        "반응",
+        &["분류", "횟수", "최고 구간"],
+        report
+            .chat_analysis
+            .reactions
+            .iter()
+            .map(|r| {
+                vec![
+                    r.label.clone(),
+                    r.total_count.to_string(),
+                    r.peak_timestamp.map_or("-".to_string(), |t| {
+                        let offset = t.signed_duration_since(report.metadata.start_time);
+                        format!(
+                            "{} ({}회)",
+                            format_duration(offset.num_seconds().max(0) as u64),
+                            r.peak_count
+                        )
+                    }),
+                ]
+            })
+            .collect(),
+    );
+    render_table(
+        html,
+        "많이 나온 단어",
+        &["단어", "횟수"],
+        report
+            .chat_analysis
+            .popular_words
+            .iter()
+            .take(RANK_LIMIT)
+            .map(|w| vec![w.word.clone(), w.count.to_string()])
+            .collect(),
+    );
+}
+
+fn render_donation(html: &mut String, report: &ReportData) {
+    let donation = &report.donation_analysis;
+
+    render_table(
+        html,
+        "후원 종류",
+        &["종류", "횟수", "금액"],
+        donation
+            .by_type
+            .iter()
+            .map(|b| {
+                vec![
+                    b.donation_type.clone(),
+                    b.count.to_string(),
+                    b.amount.to_string(),
+                ]
+            })
+            .collect(),
+    );
+    render_table(
+        html,
+        "후원 금액 분포",
+        &["구간", "횟수", "금액"],
+        donation
+            .amount_distribution
+            .iter()
+            .filter(|b| b.count > 0)
+            .map(|b| {
+                let range = match b.max {
+                    Some(max) => format!("{} ~ {}", b.min, max),
+                    None => format!("{} ~", b.min),
+                };
+                vec![range, b.count.to_string(), b.amount.to_string()]
+            })
+            .collect(),
+    );
+}
+
+fn render_emoticons(html: &mut String, report: &ReportData) {
+    let emoticon = &report.emoticon_analysis;
+
+    render_table(
+        html,
+        "시그니처 이모티콘",
+        &["이모티콘", "티어", "사용 횟수", "사용자 수"],
+        emoticon
+            .signature_emoticons
+            .iter()
+            .take(RANK_LIMIT)
+            .map(|e| {
+                vec![
+                    e.title.clone(),
+                    e.tier.to_string(),
+                    e.count.to_string(),
+                    e.user_count.to_string(),
+                ]
+            })
+            .collect(),
+    );
+    render_table(
+        html,
+        "OGQ 스티커",
+        &["스티커", "사용 횟수", "사용자 수"],
+        emoticon
+            .ogq_stickers
+            .iter()
+            .take(RANK_LIMIT)
+            .map(|s| {
+                vec![
+                    format!("{} #{}", s.id, s.number),
+                    s.count.to_string(),
+                    s.user_count.to_string(),
+                ]
+            })
+            .collect(),
+    );
+}
+
+fn render_topics(html: &mut String, report: &ReportData) {
+    render_table(
+        html,
+        "대화 주제",
+        &["구간", "주제", "채팅 수", "핵심 단어"],
+        report
+            .topic_analysis
+            .segments
+            .iter()
+            .filter(|segment| !segment.key_terms.is_empty())
+            .map(|segment| {
+                vec![
+                    format!(
+                        "{} ~ {}",
+                        format_duration(segment.start_offset_seconds),
+                        format_duration(segment.end_offset_seconds)
+                    ),
+                    format!("#{}", segment.topic_id + 1),
+                    segment.chat_count.to_string(),
+                    join_key_terms(&segment.key_terms),
+                ]
+            })
+            .collect(),
+    );
+}
+
+fn render_chat_summary(html: &mut String, report: &ReportData) {
+    render_table(
+        html,
+        "채팅 요약",
+        &["구간", "닉네임", "채팅", "반응"],
+        report
+            .chat_summary
+            .segments
+            .iter()
+            .flat_map(|segment| {
+                let range = format!(
+                    "{} ~ {}",
+                    format_duration(segment.start_offset_seconds),
+                    format_duration(segment.end_offset_seconds)
+                );
+                segment.messages.iter().map(move |message| {
+                    vec![
+                        range.clone(),
+                        message.user.label.clone(),
+                        message.message.clone(),
+                        message.reaction_count.to_string(),
+                    ]
+                })
+            })
+            .collect(),
+    );
+}
+
+fn render_sentiment(html: &mut String, report: &ReportData) {
+    let Some(sentiment) = &report.sentiment_analysis else {
+        return;
+    };
+
+    let rows = |moments: &[SentimentMoment]| -> Vec<Vec<String>> {
+        moments
+            .iter()
+            .map(|m| {
+                vec![
+                    m.relative_timestamp.clone(),
+                    format!("{:.2}", m.average_score),
+                    m.analyzed_count.to_string(),
+                    m.message.clone().unwrap_or_default(),
+                ]
+            })
+            .collect()
+    };
+
+    let headers = ["구간", "평균 점수", "분석 채팅 수", "대표 채팅"];
+    render_table(
+        html,
+        "가장 긍정적인 구간",
+        &headers,
+        rows(&sentiment.most_positive_moments),
+    );
+    render_table(
+        html,
+        "가장 부정적인 구간",
+        &headers,
+        rows(&sentiment.most_negative_moments),
+    );
+}
+
+fn render_toxicity(html: &mut String, report: &ReportData) {
+    let toxicity = &report.toxicity_analysis;
+
+    render_table(
+        html,
+        "유해 표현",
+        &["분류", "경고 수", "최고 점수"],
+        toxicity
+            .labels
+            .iter()
+            .filter(|l| l.flagged_count > 0)
+            .map(|l| {
+                vec![
+                    l.label.clone(),
+                    l.flagged_count.to_string(),
+                    format!("{:.2}", l.max_score),
+                ]
+            })
+            .collect(),
+    );
+    render_table(
+        html,
+        "유해 표현 경고 사용자",
+        &["닉네임", "경고 수", "최고 점수"],
+        toxicity
+            .top_users
+            .iter()
+            .take(RANK_LIMIT)
+            .map(|u| {
+                vec![
+                    u.user.label.clone(),
+                    u.flagged_count.to_string(),
+                    format!("{:.2}", u.max_score),
+                ]
+            })
+            .collect(),
+    );
+}
+
+fn render_moderation(html: &mut String, report: &ReportData) {
+    let stats = &report.moderation_analysis.stats;
+
+    render_table(
+        html,
+        "관리 조치",
+        &["종류", "횟수"],
+        vec![
+            vec!["채팅 금지".to_string(), stats.mutes.to_string()],
+            vec!["강제 퇴장".to_string(), stats.kicks.to_string()],
+            vec!["블랙".to_string(), stats.bans.to_string()],
+            vec!["얼리기".to_string(), stats.freezes.to_string()],
+            vec!["저속 모드".to_string(), stats.slows.to_string()],
+        ],
+    );
+    render_table(
+        html,
+        "관리자별 조치",
+        &["관리자", "채팅 금지", "총 금지 시간(초)"],
+        report
+            .moderation_analysis
+            .moderator_actions
+            .iter()
+            .take(RANK_LIMIT)
+            .map(|m| {
+                vec![
+                    m.moderator.clone(),
+                    m.mute_count.to_string(),
+                    m.total_mute_seconds.to_string(),
+                ]
+            })
+            .collect(),
+    );
+
+    let moderation = &report.moderation_analysis;
+    let start_time = report.metadata.start_time;
+    let ended_at = |ended_at: Option<_>| {
+        ended_at.map_or("방송 종료까지".to_string(), |t| {
+            format_offset(start_time, t)
+        })
+    };
+
+    render_table(
+        html,
+        "블랙 목록",
+        &["닉네임", "시각"],
+        moderation
+            .black_list
+            .iter()
+            .map(|b| {
+                vec![
+                    b.user_label.clone().unwrap_or_else(|| b.user_id.clone()),
+                    format_offset(start_time, b.timestamp),
+                ]
+            })
+            .collect(),
+    );
+    render_table(
+        html,
+        "얼리기 구간",
+        &["조건", "시작", "해제", "지속 시간"],
+        moderation
+            .freeze_intervals
+            .iter()
+            .map(|f| {
+                vec![
+                    freeze_condition(f),
+                    format_offset(start_time, f.started_at),
+                    ended_at(f.ended_at),
+                    format_duration(f.duration_seconds),
+                ]
+            })
+            .collect(),
+    );
+    render_table(
+        html,
+        "저속 모드 구간",
+        &["채팅 간격(초)", "시작", "해제", "지속 시간"],
+        moderation
+            .slow_intervals
+            .iter()
+            .map(|s| {
+                vec![
+                    s.slow_seconds.to_string(),
+                    format_offset(start_time, s.started_at),
+                    ended_at(s.ended_at),
+                    format_duration(s.duration_seconds),
+                ]
+            })
+            .collect(),
+    );
+    render_table(
+        html,
+        "반복 조치 사용자",
+        &["닉네임", "채팅 금지", "강제 퇴장", "블랙", "합계"],
+        moderation
+            .repeat_offenders
+            .iter()
+            .map(|r| {
+                vec![
+                    r.user_label.clone().unwrap_or_else(|| r.user_id.clone()),
+                    r.mute_count.to_string(),
+                    r.kick_count.to_string(),
+                    r.black_count.to_string(),
+                    r.total_count.to_string(),
+                ]
+            })
+            .collect(),
+    );
+    render_table(
+        html,
+        "조치 기록",
+        &["닉네임", "시각", "조치", "관리자", "직전 채팅"],
+        moderation
+            .actions
+            .iter()
+            .map(|a| {
+                vec![
+                    a.user_label.clone().unwrap_or_else(|| a.user_id.clone()),
+                    format_offset(start_time, a.timestamp),
+                    moderation_action_label(&a.action_type).to_string(),
+                    a.by.clone().unwrap_or_else(|| "-".to_string()),
+                    a.preceding_chats
+                        .iter()
+                        .map(|c| c.message.as_str())
+                        .collect::<Vec<_>>()
+                        .join(" / "),
+                ]
+            })
+            .collect(),
+    );
+}
@@ -0,0 +1,400 @@
+use std::fmt::Write;
+
+use crate::models::reports::ReportData;
+use crate::services::ai::sentiment_analyzer::SentimentMethod;
+use crate::services::db::commands::BroadcastSessionResult;
+use crate::services::report_exporter::exporter::{
+    format_duration, format_offset, freeze_condition, join_key_terms, moderation_action_label,
+};
+
+/// 순위 목록에 보여줄 최대 항목 수
+const RANK_LIMIT: usize = 5;
+
+/**
+ * Discord, Notion 등에 붙여넣기 위한 Markdown 요약을 생성합니다.
+ * Discord는 표를 지원하지 않으므로 목록으로만 구성합니다.
+ */
+pub fn render_markdown(session: &BroadcastSessionResult, report: &ReportData) -> String {
+    let mut md = String::new();
+
+    let _ = writeln!(md, "# {}", escape_markdown(&session.title));
+    let _ = writeln!(
+        md,
+        "{} · {} · {}",
+        escape_markdown(&session.channel_name),
+        report.metadata.start_time.format("%Y-%m-%d %H:%M"),
+        format_duration(report.metadata.duration_seconds)
+    );
+
+    let _ = writeln!(md, "\n## 요약");
+    let _ = writeln!(md, "- 총 채팅: {}", report.chat_analysis.total_count);
+    let _ = writeln!(md, "- 참여 시청자: {}", report.user_analysis.unique.total);
+    let _ = writeln!(
+        md,
+        "- 후원: {}회 / {}개",
+        report.event_analysis.total_donation_count, report.event_analysis.total_donation_amount
+    );
+    let _ = writeln!(
+        md,
+        "- 미션 후원: {}회 / {}개",
+        report.event_analysis.total_mission_donation_count,
+        report.event_analysis.total_mission_donation_amount
+    );
+    let _ = writeln!(
+        md,
+        "- 구독: {} (갱신 {})",
+        report.event_analysis.total_subscribe_count,
+        report.event_analysis.total_subscribe_renew_count
+    );
+
+    if let Some(peak) = report
+        .chunks
+        .iter()
+        .max_by_key(|chunk| chunk.chat.total_count)
+        .filter(|chunk| chunk.chat.total_count > 0)
+    {
+        let _ = writeln!(
+            md,
+            "- 채팅 최고점: {} ({}개)",
+            peak.relative_timestamp, peak.chat.total_count
+        );
+    }
+
+    if let Some(sentiment) = &report.sentiment_analysis {
+        let _ = writeln!(
+            md,
+            "- 분위기: {} (긍정 {:.0}% / 부정 {:.0}%){}",
+            sentiment.overall_sentiment,
+            sentiment.positive_ratio * 100.0,
+            sentiment.negative_ratio * 100.0,
+            match sentiment.method {
+                SentimentMethod::Model => "",
+                SentimentMethod::Lexicon => " · 사전 기반",
+            }
+        );
+    }
+
+    if !report.chat_analysis.top_chatters.is_empty() {
+        let _ = writeln!(md, "\n## 채팅 순위");
+        for (rank, chatter) in report
+            .chat_analysis
+            .top_chatters
+            .iter()
+            .take(RANK_LIMIT)
+            .enumerate()
+        {
+            let _ = writeln!(
+                md,
+                "{}. {} - {}개",
+                rank + 1,
+                escape_markdown(&chatter.user.label),
+                chatter.message_count
+            );
+        }
+    }
+
+    if !report.event_analysis.top_donators.is_empty() {
+        let _ = writeln!(md, "\n## 후원 순위");
+        for (rank, donator) in report
+            .event_analysis
+            .top_donators
+            .iter()
+            .take(RANK_LIMIT)
+            .enumerate()
+        {
+            let _ = writeln!(
+                md,
+                "{}. {} - {}개 ({}회)",
+                rank + 1,
+                escape_markdown(&donator.user_label),
+                donator.total_amount,
+                donator.donation_count
+            );
+        }
+    }
+
+    if report
+        .chat_analysis
+        .reactions
+        .iter()
+        .any(|r| r.total_count > 0)
+    {
+        let _ = writeln!(md, "\n## 반응");
+        for reaction in &report.chat_analysis.reactions {
+            let _ = writeln!(
+                md,
+                "- {} - {}회",
+                escape_markdown(&reaction.label),
+                reaction.total_count
+            );
+        }
+    }
+
+    if !report.chat_analysis.popular_words.is_empty() {
+        let words = report
+            .chat_analysis
+            .popular_words
+            .iter()
+            .take(RANK_LIMIT * 2)
+            .map(|w| code_span(&w.word))
+            .collect::<Vec<_>>()
+            .join(" ");
+        let _ = writeln!(md, "\n## 많이 나온 단어\n{}", words);
+    }
+
+    let emoticon = &report.emoticon_analysis;
+    if !emoticon.signature_emoticons.is_empty() || !emoticon.ogq_stickers.is_empty() {
+        let _ = writeln!(md, "\n## 이모티콘");
+        for e in emoticon.signature_emoticons.iter().take(RANK_LIMIT) {
+            let _ = writeln!(
+                md,
+                "- {} - {}회 ({}명)",
+                code_span(&format!("/{}/", e.title)),
+                e.count,
+                e.user_count
+            );
+        }
+        for s in emoticon.ogq_stickers.iter().take(RANK_LIMIT) {
+            let _ = writeln!(
+                md,
+                "- OGQ {} #{} - {}회 ({}명)",
+                s.id, s.number, s.count, s.user_count
+            );
+        }
+        if !emoticon.unused_signature_emoticons.is_empty() {
+            let _ = writeln!(
+                md,
+                "- 사용되지 않은 시그니처 이모티콘: {}개",
+                emoticon.unused_signature_emoticons.len()
+            );
+        }
+    }
+
+    let topics: Vec<_> = report
+        .topic_analysis
+        .segments
+        .iter()
+        .filter(|segment| !segment.key_terms.is_empty())
+        .collect();
+    if !topics.is_empty() {
+        let _ = writeln!(md, "\n## 대화 주제");
+        for segment in topics {
+            let _ = writeln!(
+                md,
+                "- {} ~ {} · {}",
+                format_duration(segment.start_offset_seconds),
+                format_duration(segment.end_offset_seconds),
+                escape_markdown(&join_key_terms(&segment.key_terms))
+            );
+        }
+    }
+
+    if !report.chat_summary.segments.is_empty() {
+        let _ = writeln!(md, "\n## 채팅 요약");
+        for segment in &report.chat_summary.segments {
+            let _ = writeln!(
+                md,
+                "- {} ~ {}",
+                format_duration(segment.start_offset_seconds),
+                format_duration(segment.end_offset_seconds)
+            );
+            for message in &segment.messages {
+                let _ = writeln!(
+                    md,
+                    "  - {}: {}",
+                    escape_markdown(&message.user.label),
+                    escape_markdown(&message.message)
+                );
+            }
+        }
+    }
+
+    if let Some(sentiment) = &report.sentiment_analysis {
+        let moments = [
+            ("가장 긍정적인 구간", &sentiment.most_positive_moments),
+            ("가장 부정적인 구간", &sentiment.most_negative_moments),
+        ];
+        for (title, moments) in moments.into_iter().filter(|(_, m)| !m.is_empty()) {
+            let _ = writeln!(md, "\n## {}", title);
+            for moment in moments {
+                let _ = write!(
+                    md,
+                    "- {} · 평균 {:.2} ({}개)",
+                    moment.relative_timestamp, moment.average_score, moment.analyzed_count
+                );
+                match &moment.message {
+                    Some(message) => {
+                        let _ = writeln!(md, ": {}", escape_markdown(message));
+                    }
+                    None => md.push('\n'),
+                }
+            }
+        }
+    }
+
+    let toxicity = &report.toxicity_analysis;
+    if toxicity.flagged_count > 0 {
+        let _ = writeln!(md, "\n## 유해 표현");
+        let _ = writeln!(
+            md,
+            "- 분류된 채팅 {}개 중 {}개가 경고 기준({:.2}) 이상",
+            toxicity.analyzed_count, toxicity.flagged_count, toxicity.threshold
+        );
+        for label in toxicity.labels.iter().filter(|l| l.flagged_count > 0) {
+            let _ = writeln!(
+                md,
+                "- {} - {}회",
+                escape_markdown(&label.label),
+                label.flagged_count
+            );
+        }
+    }
+
+    let stats = &report.moderation_analysis.stats;
+    let _ = writeln!(md, "\n## 관리");
+    let _ = writeln!(
+        md,
+        "- 채팅 금지 {} · 강제 퇴장 {} · 블랙 {}",
+        stats.mutes, stats.kicks, stats.bans
+    );
+    if stats.freezes > 0 || stats.slows > 0 {
+        let _ = writeln!(
+            md,
+            "- 얼리기 {}회 ({}) · 저속 모드 {}회 ({})",
+            stats.freezes,
+            format_duration(stats.freeze_seconds),
+            stats.slows,
+            format_duration(stats.slow_seconds)
+        );
+    }
+
+    let moderation = &report.moderation_analysis;
+    let start_time = report.metadata.start_time;
+    let ended_at = |ended_at: Option<_>| {
+        ended_at.map_or("방송 종료까지".to_string(), |t| {
+            format_offset(start_time, t)
+        })
+    };
+
+    if !moderation.black_list.is_empty() {
+        let _ = writeln!(md, "\n### 블랙 목록");
+        for black in &moderation.black_list {
+            let _ = writeln!(
+                md,
+                "- {} · {}",
+                format_offset(start_time, black.timestamp),
+                escape_markdown(black.user_label.as_deref().unwrap_or(&black.user_id))
+            );
+        }
+    }
+
+    if !moderation.freeze_intervals.is_empty() || !moderation.slow_intervals.is_empty() {
+        let _ = writeln!(md, "\n### 얼리기 · 저속 모드 구간");
+        for freeze in &moderation.freeze_intervals {
+            let _ = writeln!(
+                md,
+                "- 얼리기 {} ~ {} ({}) · {}",
+                format_offset(start_time, freeze.started_at),
+                ended_at(freeze.ended_at),
+                format_duration(freeze.duration_seconds),
+                escape_markdown(&freeze_condition(freeze))
+            );
+        }
+        for slow in &moderation.slow_intervals {
+            let _ = writeln!(
+                md,
+                "- 저속 모드 {} ~ {} ({}) · {}초 간격",
+                format_offset(start_time, slow.started_at),
+                ended_at(slow.ended_at),
+                format_duration(slow.duration_seconds),
+                slow.slow_seconds
+            );
+        }
+    }
+
+    if !moderation.repeat_offenders.is_empty() {
+        let _ = writeln!(md, "\n### 반복 조치 사용자");
+        for offender in &moderation.repeat_offenders {
+            let _ = writeln!(
+                md,
+                "- {} - {}회 (채팅 금지 {} · 강제 퇴장 {} · 블랙 {})",
+                escape_markdown(offender.user_label.as_deref().unwrap_or(&offender.user_id)),
+                offender.total_count,
+                offender.mute_count,
+                offender.kick_count,
+                offender.black_count
+            );
+        }
+    }
+
+    if !moderation.actions.is_empty() {
+        let _ = writeln!(md, "\n### 조치 기록");
+        for action in &moderation.actions {
+            let _ = write!(
+                md,
+                "- {} · {} · {}",
+                format_offset(start_time, action.timestamp),
+                moderation_action_label(&action.action_type),
+                escape_markdown(action.user_label.as_deref().unwrap_or(&action.user_id))
+            );
+            match &action.by {
+                Some(by) => {
+                    let _ = writeln!(md, " (관리자: {})", escape_markdown(by));
+                }
+                None => md.push('\n'),
+            }
+            for chat in &action.preceding_chats {
+                let _ = writeln!(
+                    md,
+                    "  - {} {}",
+                    format_offset(start_time, chat.timestamp),
+                    escape_markdown(&chat.message)
+                );
+            }
+        }
+    }
+
+    md
+}
+
+/**
+ * 방송 제목, 닉네임, 채팅 등 사용자가 입력한 문자열이 Markdown 문법으로 해석되지 않도록 이스케이프합니다.
+ * 줄바꿈은 목록과 표를 깨뜨리므로 공백으로 바꿉니다.
+ */
+fn escape_markdown(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '\r' | '\n' => escaped.push(' '),
+            '\\' | '`' | '*' | '_' | '~' | '|' | '[' | ']' | '<' | '>' | '#' => {
+                escaped.push('\\');
+                escaped.push(c);
+            }
+            _ => escaped.push(c),
+        }
+    }
+
+    // 줄 앞의 -, +, 1. 은 목록으로 해석됩니다.
+    if escaped.starts_with(['-', '+']) {
+        escaped.insert(0, '\\');
+    } else if let Some(index) = escaped.find(|c: char| !c.is_ascii_digit()) {
+        if index > 0 && escaped[index..].starts_with('.') {
+            escaped.insert(index, '\\');
+        }
+    }
+
+    escaped
+}
+
+/// 코드 블록 안에서는 이스케이프가 동작하지 않으므로 백틱과 줄바꿈만 바꿔서 감쌉니다.
+fn code_span(text: &str) -> String {
+    let text: String = text
+        .chars()
+        .map(|c| match c {
+            '`' => '\'',
+            '\r' | '\n' => ' ',
+            _ => c,
+        })
+        .collect();
+    format!("`{}`", text)
+}
@@ -0,0 +1,4 @@
+pub mod exporter;
+pub mod html_renderer;
+pub mod markdown_renderer;
+pub mod svg_chart;
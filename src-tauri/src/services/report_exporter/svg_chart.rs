@@ -0,0 +1,145 @@
+use std::fmt::Write;
+
+const CHART_WIDTH: f64 = 800.0;
+const CHART_HEIGHT: f64 = 200.0;
+const PADDING_LEFT: f64 = 48.0;
+const PADDING_RIGHT: f64 = 16.0;
+const PADDING_TOP: f64 = 12.0;
+const PADDING_BOTTOM: f64 = 28.0;
+
+/// x축에 표시할 라벨 수
+const X_LABEL_COUNT: usize = 6;
+
+#[derive(Debug, Clone, Copy)]
+pub enum ChartKind {
+    Line,
+    Bar,
+}
+
+/**
+ * 청크 단위 값을 외부 의존성 없는 인라인 SVG 차트로 그립니다.
+ * labels와 values의 길이는 같아야 합니다.
+ */
+pub fn render_chart(kind: ChartKind, labels: &[String], values: &[f64], color: &str) -> String {
+    let mut svg = String::new();
+    let _ = write!(
+        svg,
+        r#"<svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 {w} {h}" width="100%" role="img">"#,
+        w = CHART_WIDTH,
+        h = CHART_HEIGHT
+    );
+
+    if values.is_empty() {
+        let _ = write!(
+            svg,
+            r##"<text x="{}" y="{}" text-anchor="middle" fill="#888">데이터 없음</text></svg>"##,
+            CHART_WIDTH / 2.0,
+            CHART_HEIGHT / 2.0
+        );
+        return svg;
+    }
+
+    // 음수 값(감정 점수 등)도 표현할 수 있도록 0을 항상 범위에 포함합니다.
+    let max = values.iter().cloned().fold(0.0_f64, f64::max);
+    let min = values.iter().cloned().fold(0.0_f64, f64::min);
+    let range = if max - min > f64::EPSILON {
+        max - min
+    } else {
+        1.0
+    };
+
+    let plot_width = CHART_WIDTH - PADDING_LEFT - PADDING_RIGHT;
+    let plot_height = CHART_HEIGHT - PADDING_TOP - PADDING_BOTTOM;
+    let step = plot_width / values.len() as f64;
+    let y_of = |value: f64| PADDING_TOP + (max - value) / range * plot_height;
+    let x_of = |index: usize| PADDING_LEFT + step * (index as f64 + 0.5);
+    let zero_y = y_of(0.0);
+
+    // 축과 눈금
+    let _ = write!(
+        svg,
+        r##"<line x1="{x}" y1="{top}" x2="{x}" y2="{bottom}" stroke="#ccc"/><line x1="{x}" y1="{zero}" x2="{right}" y2="{zero}" stroke="#ccc"/>"##,
+        x = PADDING_LEFT,
+        top = PADDING_TOP,
+        bottom = PADDING_TOP + plot_height,
+        zero = zero_y,
+        right = CHART_WIDTH - PADDING_RIGHT
+    );
+    for (value, y) in [(max, y_of(max)), (min, y_of(min))] {
+        let _ = write!(
+            svg,
+            r##"<text x="{}" y="{}" font-size="11" text-anchor="end" fill="#666">{}</text>"##,
+            PADDING_LEFT - 6.0,
+            y + 4.0,
+            format_axis_value(value)
+        );
+    }
+
+    let label_interval = (labels.len() / X_LABEL_COUNT).max(1);
+    for (index, label) in labels.iter().enumerate().step_by(label_interval) {
+        let _ = write!(
+            svg,
+            r##"<text x="{}" y="{}" font-size="11" text-anchor="middle" fill="#666">{}</text>"##,
+            x_of(index),
+            CHART_HEIGHT - 8.0,
+            escape_xml(label)
+        );
+    }
+
+    match kind {
+        ChartKind::Line => {
+            let points = values
+                .iter()
+                .enumerate()
+                .map(|(index, value)| format!("{:.1},{:.1}", x_of(index), y_of(*value)))
+                .collect::<Vec<_>>()
+                .join(" ");
+            let _ = write!(
+                svg,
+                r#"<polyline fill="none" stroke="{}" stroke-width="2" points="{}"/>"#,
+                color, points
+            );
+        }
+        ChartKind::Bar => {
+            let bar_width = (step * 0.8).max(1.0);
+            for (index, value) in values.iter().enumerate() {
+                let y = y_of(*value);
+                let _ = write!(
+                    svg,
+                    r#"<rect x="{:.1}" y="{:.1}" width="{:.1}" height="{:.1}" fill="{}"/>"#,
+                    x_of(index) - bar_width / 2.0,
+                    y.min(zero_y),
+                    bar_width,
+                    (zero_y - y).abs(),
+                    color
+                );
+            }
+        }
+    }
+
+    svg.push_str("</svg>");
+    svg
+}
+
+fn format_axis_value(value: f64) -> String {
+    if value.fract().abs() < f64::EPSILON {
+        format!("{}", value as i64)
+    } else {
+        format!("{:.2}", value)
+    }
+}
+
+pub fn escape_xml(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
@@ -0,0 +1,279 @@
+use chrono::{Duration, Utc};
+use soup_sdk::SoopHttpClient;
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+use tokio::task;
+
+use crate::{
+    models::reports::{ReportChunk, ReportStatus},
+    services::{
+        addons::data_enrichment::{
+            reaction_rules::ReactionRuleStore, token_analyzer::TokenAnalyzer,
+        },
+        ai::{
+            lexicon_sentiment,
+            sentiment_analyzer::{AnalysisResult, SentimentMethod},
+            SentimentAnalyzer,
+        },
+        db::{commands::ChatLogResult, service::DBService},
+        stats::{config::StatsConfigStore, registry::STATS_ACTIVE_VIEWER},
+    },
+    util::emoticon::SignatureEmoticonCatalog,
+    util::reports::{
+        apply_active_viewer_snapshots, create_report_chunk, create_report_data,
+        is_sentiment_target, ReportSource, SentimentSample, CHUNK_SIZE, SENTIMENT_BATCH_SIZE,
+    },
+};
+
+/// 방송 하나의 리포트를 생성하고 저장합니다.
+pub async fn generate_report(db: Arc<DBService>, broadcast_id: i64) -> Result<(), String> {
+    let token_analyzer = TokenAnalyzer::global();
+    let reaction_rules = ReactionRuleStore::global().get();
+    // 상태를 GENERATING으로 변경
+    db.update_report_status(
+        broadcast_id,
+        ReportStatus::Generating.into(),
+        Some(0.0),
+        None,
+    )
+    .await?;
+
+    // 방송 세션 정보 조회
+    let broadcast_session = db
+        .get_broadcast_session(broadcast_id)
+        .await?
+        .ok_or("Broadcast session not found")?;
+
+    let start_time = broadcast_session.started_at;
+    let end_time = broadcast_session.ended_at.unwrap_or_else(Utc::now);
+
+    let chunk_duration = Duration::seconds(CHUNK_SIZE as i64);
+    let total_duration = end_time.signed_duration_since(start_time);
+    let total_chunks = (total_duration.num_seconds() as f64 / CHUNK_SIZE as f64).ceil() as usize;
+
+    // 실시간 분석이나 소급 분석으로 저장된 감정 분석 결과는 다시 분석하지 않습니다.
+    // 리포트 하나는 한 가지 방식으로만 분석하므로, 모델이 있으면 현재 모델의 결과만,
+    // 없으면 사전 기반 결과만 재사용하고 나머지는 다시 분석합니다.
+    let current_model_version = SentimentAnalyzer::global().map(SentimentAnalyzer::model_version);
+    let stored_sentiment = db
+        .get_chat_sentiment(broadcast_id)
+        .await
+        .unwrap_or_else(|e| {
+            eprintln!("[ReportQueue] Failed to load chat sentiment: {}", e);
+            Vec::new()
+        });
+    let stored_sentiment: HashMap<String, AnalysisResult> = stored_sentiment
+        .into_iter()
+        .filter(|item| match &current_model_version {
+            Some(version) => item.model_version.as_ref() == Some(version),
+            None => item.result.method == SentimentMethod::Lexicon,
+        })
+        .map(|item| (item.chat_log_id, item.result))
+        .collect();
+
+    let mut current_time = start_time;
+    let mut chunk_index = 0;
+
+    let mut chunks: Vec<ReportChunk> = Vec::new();
+    let mut all_chat_logs = Vec::new();
+    let mut all_event_logs = Vec::new();
+
+    while current_time < end_time {
+        let chunk_end = std::cmp::min(current_time + chunk_duration, end_time);
+
+        // 진행률 업데이트
+        let progress = (chunk_index as f64 / total_chunks as f64) * 100.0;
+        db.update_report_status(
+            broadcast_id,
+            ReportStatus::Generating.into(),
+            Some(progress),
+            None,
+        )
+        .await?;
+
+        // 청크 단위로 로그 조회
+        let chat_logs = db
+            .get_chat_logs_for_report(broadcast_id, current_time, chunk_end)
+            .await?;
+
+        let event_logs = db
+            .get_event_logs_for_report(broadcast_id, current_time, chunk_end)
+            .await?;
+
+        let sentiment_samples =
+            analyze_chunk_sentiment(&chat_logs, &stored_sentiment, token_analyzer).await;
+
+        chunks.push(create_report_chunk(
+            chunk_index,
+            current_time,
+            &chat_logs,
+            &event_logs,
+            token_analyzer,
+            &reaction_rules,
+            sentiment_samples.as_deref(),
+        ));
+        all_chat_logs.extend(chat_logs);
+        all_event_logs.extend(event_logs);
+
+        // 다음 청크로 이동
+        current_time = chunk_end;
+        chunk_index += 1;
+
+        // 메모리 사용량 제어를 위해 중간중간 처리 가능
+        if chunk_index % 100 == 0 {
+            tokio::task::yield_now().await;
+        }
+    }
+
+    // 방송 중 저장된 실시간 통계를 재사용합니다.
+    match db
+        .get_stats_snapshots(broadcast_id, Some(STATS_ACTIVE_VIEWER.to_string()))
+        .await
+    {
+        Ok(snapshots) => apply_active_viewer_snapshots(&mut chunks, &snapshots, CHUNK_SIZE),
+        Err(e) => eprintln!("[ReportQueue] Failed to load stats snapshots: {}", e),
+    }
+
+    // 첫 후원 여부 판단을 위해 이전 방송의 후원자 조회
+    let previous_donor_ids: HashSet<String> = db
+        .get_previous_donor_ids(broadcast_id)
+        .await?
+        .into_iter()
+        .collect();
+
+    // 방송 중 분류된 유해 표현 점수 (분류 모델이 없었다면 비어 있습니다)
+    let chat_toxicity = db
+        .get_chat_toxicity(broadcast_id)
+        .await
+        .unwrap_or_else(|e| {
+            eprintln!("[ReportQueue] Failed to load chat toxicity: {}", e);
+            Vec::new()
+        });
+    let toxicity_threshold = StatsConfigStore::global().get().toxicity_alert_threshold;
+
+    // 이모티콘 사용 순위를 스트리머 이모티콘 목록과 연결하기 위해 조회
+    let emoticon_catalog = fetch_emoticon_catalog(&broadcast_session.channel_id).await;
+
+    // 리포트 데이터 생성
+    let report_data = create_report_data(
+        chunks,
+        start_time,
+        end_time,
+        CHUNK_SIZE,
+        &ReportSource {
+            chat_logs: &all_chat_logs,
+            event_logs: &all_event_logs,
+            previous_donor_ids: &previous_donor_ids,
+            emoticon_catalog: emoticon_catalog.as_ref(),
+            reaction_rules: &reaction_rules,
+            chat_toxicity: &chat_toxicity,
+            toxicity_threshold,
+            sentiment_model_version: current_model_version,
+        },
+        token_analyzer,
+    )?;
+
+    // 리포트 데이터를 JSON으로 직렬화
+    let report_json = serde_json::to_string(&report_data)
+        .map_err(|e| format!("Failed to serialize report data: {}", e))?;
+
+    // 리포트 데이터 저장
+    db.update_report_data(broadcast_id, report_json).await?;
+
+    // 완료 상태로 업데이트
+    db.update_report_status(
+        broadcast_id,
+        ReportStatus::Completed.into(),
+        Some(100.0),
+        None,
+    )
+    .await?;
+
+    Ok(())
+}
+
+/// 이모티콘 목록 조회에 실패해도 리포트 생성은 계속 진행합니다.
+async fn fetch_emoticon_catalog(channel_id: &str) -> Option<SignatureEmoticonCatalog> {
+    match SoopHttpClient::new()
+        .get_signature_emoticon(channel_id)
+        .await
+    {
+        Ok(data) => Some(SignatureEmoticonCatalog::new(channel_id, &data)),
+        Err(e) => {
+            eprintln!(
+                "[ReportQueue] Failed to fetch signature emoticons for {}: {}",
+                channel_id, e
+            );
+            None
+        }
+    }
+}
+
+/// 청크 내 채팅을 배치 단위로 감정 분석합니다.
+/// 저장된 결과가 있는 채팅은 그대로 사용하고, 감정 분석 모델이 없으면 사전 기반으로 분석합니다.
+async fn analyze_chunk_sentiment(
+    chat_logs: &[ChatLogResult],
+    stored_sentiment: &HashMap<String, AnalysisResult>,
+    token_analyzer: &'static TokenAnalyzer,
+) -> Option<Vec<SentimentSample>> {
+    let mut samples = Vec::new();
+    let mut messages = Vec::new();
+
+    for chat in chat_logs.iter().filter(|chat| is_sentiment_target(chat)) {
+        match stored_sentiment.get(&chat.id) {
+            Some(analysis) => samples.push(SentimentSample {
+                message: chat.message.clone(),
+                analysis: analysis.clone(),
+            }),
+            None => messages.push(chat.message.clone()),
+        }
+    }
+
+    let Some(analyzer) = SentimentAnalyzer::global() else {
+        let lexicon_samples = task::spawn_blocking(move || {
+            messages
+                .into_iter()
+                .map(|message| {
+                    let analysis = lexicon_sentiment::analyze(token_analyzer, &message);
+                    SentimentSample { message, analysis }
+                })
+                .collect::<Vec<_>>()
+        })
+        .await
+        .map_err(|e| eprintln!("Lexicon sentiment analysis failed: {}", e))
+        .ok()?;
+        samples.extend(lexicon_samples);
+        return Some(samples);
+    };
+
+    for batch in messages.chunks(SENTIMENT_BATCH_SIZE) {
+        let batch = batch.to_vec();
+
+        // 추론은 CPU 작업이므로 blocking 스레드에서 수행
+        let batch_samples = task::spawn_blocking(move || {
+            let texts: Vec<&str> = batch.iter().map(String::as_str).collect();
+            match analyzer.analyze_batch(&texts) {
+                Ok(results) => batch
+                    .into_iter()
+                    .zip(results)
+                    .map(|(message, analysis)| SentimentSample { message, analysis })
+                    .collect::<Vec<_>>(),
+                Err(e) => {
+                    eprintln!("Sentiment batch inference failed: {}", e);
+                    Vec::new()
+                }
+            }
+        })
+        .await;
+
+        match batch_samples {
+            Ok(batch_samples) => samples.extend(batch_samples),
+            Err(e) => {
+                eprintln!("Sentiment batch analysis failed: {}", e);
+                return None;
+            }
+        }
+    }
+
+    Some(samples)
+}
@@ -0,0 +1,223 @@
+pub mod generator;
+
+use serde::Serialize;
+use std::collections::HashSet;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tauri::{AppHandle, Emitter};
+use tokio::sync::mpsc;
+
+use crate::{
+    models::reports::ReportStatus,
+    services::{
+        db::{commands::ReportInfo, service::DBService},
+        event_name,
+    },
+};
+
+use generator::generate_report;
+
+/// 대기열에 쌓을 수 있는 최대 리포트 작업 수
+const REPORT_QUEUE_CAPACITY: usize = 16;
+
+/// 실패 시 재시도를 포함한 최대 시도 횟수
+const REPORT_MAX_ATTEMPTS: u32 = 3;
+
+/// 재시도 대기 시간 (시도 횟수에 비례해 늘어납니다)
+const REPORT_RETRY_DELAY_SECS: u64 = 10;
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReportJobResult {
+    pub broadcast_id: i64,
+    pub attempts: u32,
+    pub error_message: Option<String>,
+}
+
+/**
+ * 리포트 생성 작업을 한 번에 하나씩 처리하는 대기열입니다.
+ * 리포트 생성은 DB 조회가 많아 동시에 여러 개를 돌리면 DB Actor가 밀리므로
+ * 단일 워커가 순서대로 처리합니다.
+ */
+pub struct ReportQueue {
+    sender: mpsc::Sender<i64>,
+    db: Arc<DBService>,
+    // 대기 중이거나 생성 중인 방송 ID (중복 등록 방지)
+    pending: Arc<Mutex<HashSet<i64>>>,
+}
+
+impl ReportQueue {
+    pub fn new(app_handle: AppHandle, db: Arc<DBService>) -> Self {
+        let (sender, receiver) = mpsc::channel(REPORT_QUEUE_CAPACITY);
+        let pending = Arc::new(Mutex::new(HashSet::new()));
+
+        tauri::async_runtime::spawn(Self::run_worker(
+            app_handle,
+            db.clone(),
+            receiver,
+            pending.clone(),
+        ));
+
+        Self {
+            sender,
+            db,
+            pending,
+        }
+    }
+
+    /// PENDING 상태로 생성된 리포트를 대기열에 추가합니다.
+    pub fn enqueue(&self, broadcast_id: i64) -> Result<(), String> {
+        let mut pending = self.pending.lock().map_err(|e| e.to_string())?;
+        if pending.contains(&broadcast_id) {
+            return Err(format!("Report is already queued: {}", broadcast_id));
+        }
+
+        self.sender
+            .try_send(broadcast_id)
+            .map_err(|e| format!("Failed to queue report: {}", e))?;
+        pending.insert(broadcast_id);
+
+        Ok(())
+    }
+
+    /**
+     * 방송 종료 시 호출됩니다.
+     * 완료된 리포트가 이미 이번 종료 시각까지 다루고 있으면 그대로 두고,
+     * 그 외에는 행을 유지한 채 다시 생성합니다. (생성에 성공하면 기존 결과를 덮어씁니다)
+     * 방송 중 재접속하면 같은 세션의 종료 시각이 늘어나므로 리포트도 다시 생성됩니다.
+     */
+    pub async fn enqueue_on_session_end(&self, broadcast_id: i64) -> Result<(), String> {
+        if self.is_pending(broadcast_id) {
+            return Ok(());
+        }
+
+        match self.db.get_report(broadcast_id).await? {
+            Some(report) => {
+                if self.covers_session_end(broadcast_id, &report).await? {
+                    return Ok(());
+                }
+                self.db
+                    .update_report_status(broadcast_id, ReportStatus::Pending.into(), None, None)
+                    .await?
+            }
+            None => self.db.create_report(broadcast_id).await?,
+        }
+
+        if let Err(e) = self.enqueue(broadcast_id) {
+            let _ = self
+                .db
+                .update_report_status(
+                    broadcast_id,
+                    ReportStatus::Failed.into(),
+                    None,
+                    Some(e.clone()),
+                )
+                .await;
+            return Err(e);
+        }
+
+        Ok(())
+    }
+
+    /// 완료된 리포트가 세션의 현재 종료 시각까지 반영하고 있는지 확인합니다.
+    async fn covers_session_end(
+        &self,
+        broadcast_id: i64,
+        report: &ReportInfo,
+    ) -> Result<bool, String> {
+        if ReportStatus::from(report.status.clone()) != ReportStatus::Completed {
+            return Ok(false);
+        }
+        let Some(report_data) = &report.report_data else {
+            return Ok(false);
+        };
+
+        let session = self.db.get_broadcast_session(broadcast_id).await?;
+        let ended_at = session.and_then(|session| session.ended_at);
+        Ok(match (ended_at, report_data.metadata.end_time) {
+            (Some(ended_at), Some(report_end)) => ended_at <= report_end,
+            _ => false,
+        })
+    }
+
+    fn is_pending(&self, broadcast_id: i64) -> bool {
+        self.pending
+            .lock()
+            .map(|pending| pending.contains(&broadcast_id))
+            .unwrap_or(false)
+    }
+
+    async fn run_worker(
+        app_handle: AppHandle,
+        db: Arc<DBService>,
+        mut receiver: mpsc::Receiver<i64>,
+        pending: Arc<Mutex<HashSet<i64>>>,
+    ) {
+        while let Some(broadcast_id) = receiver.recv().await {
+            let result = Self::generate_with_retry(db.clone(), broadcast_id).await;
+
+            if let Ok(mut pending) = pending.lock() {
+                pending.remove(&broadcast_id);
+            }
+
+            match result {
+                Ok(attempts) => {
+                    println!("[ReportQueue] Report completed: {}", broadcast_id);
+                    let _ = app_handle.emit(
+                        event_name::REPORT_COMPLETED,
+                        ReportJobResult {
+                            broadcast_id,
+                            attempts,
+                            error_message: None,
+                        },
+                    );
+                }
+                Err(e) => {
+                    eprintln!(
+                        "[ReportQueue] Report generation failed for broadcast {}: {}",
+                        broadcast_id, e
+                    );
+                    let _ = db
+                        .update_report_status(
+                            broadcast_id,
+                            ReportStatus::Failed.into(),
+                            None,
+                            Some(e.clone()),
+                        )
+                        .await;
+                    let _ = app_handle.emit(
+                        event_name::REPORT_FAILED,
+                        ReportJobResult {
+                            broadcast_id,
+                            attempts: REPORT_MAX_ATTEMPTS,
+                            error_message: Some(e),
+                        },
+                    );
+                }
+            }
+        }
+    }
+
+    /// 성공 시 시도 횟수를, 모든 시도가 실패하면 마지막 에러를 반환합니다.
+    async fn generate_with_retry(db: Arc<DBService>, broadcast_id: i64) -> Result<u32, String> {
+        let mut attempt = 1;
+
+        loop {
+            match generate_report(db.clone(), broadcast_id).await {
+                Ok(()) => return Ok(attempt),
+                Err(e) if attempt < REPORT_MAX_ATTEMPTS => {
+                    eprintln!(
+                        "[ReportQueue] Attempt {} failed for broadcast {}: {}",
+                        attempt, broadcast_id, e
+                    );
+                    tokio::time::sleep(Duration::from_secs(
+                        REPORT_RETRY_DELAY_SECS * attempt as u64,
+                    ))
+                    .await;
+                    attempt += 1;
+                }
+                Err(e) => return Err(e),
+            }
+        }
+    }
+}
@@ -0,0 +1,360 @@
+use serde::Serialize;
+use std::collections::HashSet;
+use std::sync::{Arc, Mutex};
+use tauri::{AppHandle, Emitter};
+use tokio::sync::mpsc;
+use tokio::task;
+
+use crate::{
+    models::sentiment_backfill::SentimentBackfillStatus,
+    services::{
+        addons::data_enrichment::token_analyzer::TokenAnalyzer,
+        ai::{
+            lexicon_sentiment,
+            sentiment_analyzer::{AnalysisResult, SentimentMethod},
+            SentimentAnalyzer,
+        },
+        db::{
+            commands::{ChatLogCursor, ChatSentimentData},
+            service::DBService,
+        },
+        event_name,
+    },
+    util::reports::{is_sentiment_target, SENTIMENT_BATCH_SIZE},
+};
+
+/// 대기열에 쌓을 수 있는 최대 작업 수 (기간 단위 요청을 고려해 넉넉하게 잡습니다)
+const BACKFILL_QUEUE_CAPACITY: usize = 256;
+
+/// 한 번에 조회할 채팅 수
+const BACKFILL_PAGE_SIZE: i64 = 512;
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SentimentBackfillProgress {
+    pub broadcast_id: i64,
+    pub status: SentimentBackfillStatus,
+    pub progress_percentage: f64,
+    pub analyzed_count: i64,
+    pub error_message: Option<String>,
+}
+
+/**
+ * 과거 방송의 채팅을 감정 분석해 chat_sentiment에 저장하는 대기열입니다.
+ * 리포트 대기열과 같이 단일 워커가 방송 하나씩 처리합니다.
+ * 감정 분석 모델이 있으면 사전 기반이나 다른 버전의 모델로 분석된 채팅도 다시 분석합니다.
+ * 이미 분석된 채팅은 건너뛰므로 취소되거나 앱 종료로 중단된 작업은 다시 등록하면 이어서 진행됩니다.
+ */
+pub struct SentimentBackfillQueue {
+    sender: mpsc::Sender<i64>,
+    db: Arc<DBService>,
+    // 대기 중이거나 분석 중인 방송 ID (중복 등록 방지)
+    pending: Arc<Mutex<HashSet<i64>>>,
+    // 취소 요청된 방송 ID (워커가 배치 사이마다 확인합니다)
+    cancelled: Arc<Mutex<HashSet<i64>>>,
+}
+
+impl SentimentBackfillQueue {
+    pub fn new(app_handle: AppHandle, db: Arc<DBService>) -> Self {
+        let (sender, receiver) = mpsc::channel(BACKFILL_QUEUE_CAPACITY);
+        let pending = Arc::new(Mutex::new(HashSet::new()));
+        let cancelled = Arc::new(Mutex::new(HashSet::new()));
+
+        tauri::async_runtime::spawn(Self::run_worker(
+            app_handle,
+            db.clone(),
+            receiver,
+            pending.clone(),
+            cancelled.clone(),
+        ));
+
+        Self {
+            sender,
+            db,
+            pending,
+            cancelled,
+        }
+    }
+
+    /// 방송의 감정 소급 분석을 대기열에 추가합니다.
+    pub async fn enqueue(&self, broadcast_id: i64) -> Result<(), String> {
+        if !self
+            .pending
+            .lock()
+            .map_err(|e| e.to_string())?
+            .insert(broadcast_id)
+        {
+            return Err(format!(
+                "Sentiment backfill is already queued: {}",
+                broadcast_id
+            ));
+        }
+
+        // 워커가 RUNNING으로 바꾸기 전에 PENDING 상태를 먼저 기록합니다.
+        let result = match self
+            .db
+            .upsert_sentiment_backfill_job(
+                broadcast_id,
+                SentimentBackfillStatus::Pending.into(),
+                None,
+                None,
+            )
+            .await
+        {
+            Ok(()) => self
+                .sender
+                .try_send(broadcast_id)
+                .map_err(|e| format!("Failed to queue sentiment backfill: {}", e)),
+            Err(e) => Err(e),
+        };
+
+        if let Err(e) = &result {
+            if let Ok(mut pending) = self.pending.lock() {
+                pending.remove(&broadcast_id);
+            }
+            let _ = self
+                .db
+                .upsert_sentiment_backfill_job(
+                    broadcast_id,
+                    SentimentBackfillStatus::Failed.into(),
+                    None,
+                    Some(e.clone()),
+                )
+                .await;
+        }
+
+        result
+    }
+
+    /// 대기 중이거나 진행 중인 작업을 취소합니다. 분석된 결과는 유지됩니다.
+    pub fn cancel(&self, broadcast_id: i64) -> Result<(), String> {
+        if !self.is_pending(broadcast_id) {
+            return Err(format!(
+                "Sentiment backfill is not running: {}",
+                broadcast_id
+            ));
+        }
+
+        self.cancelled
+            .lock()
+            .map_err(|e| e.to_string())?
+            .insert(broadcast_id);
+
+        Ok(())
+    }
+
+    pub fn is_pending(&self, broadcast_id: i64) -> bool {
+        self.pending
+            .lock()
+            .map(|pending| pending.contains(&broadcast_id))
+            .unwrap_or(false)
+    }
+
+    async fn run_worker(
+        app_handle: AppHandle,
+        db: Arc<DBService>,
+        mut receiver: mpsc::Receiver<i64>,
+        pending: Arc<Mutex<HashSet<i64>>>,
+        cancelled: Arc<Mutex<HashSet<i64>>>,
+    ) {
+        // 이전 실행에서 중단된 작업은 취소 상태로 표시합니다.
+        if let Err(e) = db.cancel_interrupted_sentiment_backfill_jobs().await {
+            eprintln!("[SentimentBackfill] Failed to mark interrupted jobs: {}", e);
+        }
+
+        while let Some(broadcast_id) = receiver.recv().await {
+            let result = Self::backfill(&app_handle, &db, broadcast_id, &cancelled).await;
+
+            if let Ok(mut pending) = pending.lock() {
+                pending.remove(&broadcast_id);
+            }
+            if let Ok(mut cancelled) = cancelled.lock() {
+                cancelled.remove(&broadcast_id);
+            }
+
+            match result {
+                Ok(progress) => {
+                    println!(
+                        "[SentimentBackfill] Finished broadcast {}: {:?}",
+                        broadcast_id, progress.status
+                    );
+                    let _ = db
+                        .upsert_sentiment_backfill_job(
+                            broadcast_id,
+                            progress.status.into(),
+                            Some(progress.progress_percentage),
+                            None,
+                        )
+                        .await;
+                    let _ = app_handle.emit(event_name::SENTIMENT_BACKFILL_COMPLETED, progress);
+                }
+                Err(e) => {
+                    eprintln!(
+                        "[SentimentBackfill] Backfill failed for broadcast {}: {}",
+                        broadcast_id, e
+                    );
+                    let _ = db
+                        .upsert_sentiment_backfill_job(
+                            broadcast_id,
+                            SentimentBackfillStatus::Failed.into(),
+                            None,
+                            Some(e.clone()),
+                        )
+                        .await;
+                    let _ = app_handle.emit(
+                        event_name::SENTIMENT_BACKFILL_FAILED,
+                        SentimentBackfillProgress {
+                            broadcast_id,
+                            status: SentimentBackfillStatus::Failed,
+                            progress_percentage: 0.0,
+                            analyzed_count: 0,
+                            error_message: Some(e),
+                        },
+                    );
+                }
+            }
+        }
+    }
+
+    /**
+     * 분석되지 않은 채팅을 시간순으로 조회하며 배치 단위로 분석합니다.
+     * 진행률은 작업 시작 시점에 남아 있던 채팅 대비 조회한 채팅의 비율입니다.
+     */
+    async fn backfill(
+        app_handle: &AppHandle,
+        db: &Arc<DBService>,
+        broadcast_id: i64,
+        cancelled: &Arc<Mutex<HashSet<i64>>>,
+    ) -> Result<SentimentBackfillProgress, String> {
+        let mut progress = SentimentBackfillProgress {
+            broadcast_id,
+            status: SentimentBackfillStatus::Running,
+            progress_percentage: 0.0,
+            analyzed_count: 0,
+            error_message: None,
+        };
+
+        db.upsert_sentiment_backfill_job(
+            broadcast_id,
+            SentimentBackfillStatus::Running.into(),
+            Some(0.0),
+            None,
+        )
+        .await?;
+
+        let token_analyzer = TokenAnalyzer::global();
+        let model_version = SentimentAnalyzer::global().map(SentimentAnalyzer::model_version);
+
+        let remaining_count = db
+            .get_sentiment_backfill_job(broadcast_id, model_version.clone())
+            .await?
+            .map(|job| job.total_count - job.analyzed_count)
+            .unwrap_or_default()
+            .max(1);
+
+        let mut cursor: Option<ChatLogCursor> = None;
+        let mut scanned_count: i64 = 0;
+
+        loop {
+            let is_cancelled = cancelled
+                .lock()
+                .map(|cancelled| cancelled.contains(&broadcast_id))
+                .unwrap_or(false);
+            if is_cancelled {
+                progress.status = SentimentBackfillStatus::Cancelled;
+                return Ok(progress);
+            }
+
+            let chat_logs = db
+                .get_chat_logs_without_sentiment(
+                    broadcast_id,
+                    cursor.take(),
+                    BACKFILL_PAGE_SIZE,
+                    model_version.clone(),
+                )
+                .await?;
+            let Some(last) = chat_logs.last() else {
+                break;
+            };
+            cursor = Some(ChatLogCursor {
+                timestamp: last.timestamp,
+                id: last.id.clone(),
+            });
+            scanned_count += chat_logs.len() as i64;
+
+            let (ids, messages): (Vec<String>, Vec<String>) = chat_logs
+                .into_iter()
+                .filter(is_sentiment_target)
+                .map(|chat| (chat.id, chat.message))
+                .unzip();
+
+            let results = analyze_messages(messages, token_analyzer).await?;
+            let items: Vec<ChatSentimentData> = ids
+                .into_iter()
+                .zip(results)
+                .map(|(chat_log_id, result)| ChatSentimentData {
+                    chat_log_id,
+                    model_version: model_version
+                        .clone()
+                        .filter(|_| result.method == SentimentMethod::Model),
+                    result,
+                })
+                .collect();
+
+            progress.analyzed_count += items.len() as i64;
+            if !items.is_empty() {
+                db.insert_chat_sentiment(items).await?;
+            }
+
+            progress.progress_percentage =
+                (scanned_count as f64 / remaining_count as f64 * 100.0).min(100.0);
+            db.upsert_sentiment_backfill_job(
+                broadcast_id,
+                SentimentBackfillStatus::Running.into(),
+                Some(progress.progress_percentage),
+                None,
+            )
+            .await?;
+            let _ = app_handle.emit(event_name::SENTIMENT_BACKFILL_PROGRESS, progress.clone());
+        }
+
+        progress.status = SentimentBackfillStatus::Completed;
+        progress.progress_percentage = 100.0;
+        Ok(progress)
+    }
+}
+
+/// 감정 분석 모델이 있으면 배치 추론을, 없으면 사전 기반 분석을 사용합니다.
+async fn analyze_messages(
+    messages: Vec<String>,
+    token_analyzer: &'static TokenAnalyzer,
+) -> Result<Vec<AnalysisResult>, String> {
+    let Some(analyzer) = SentimentAnalyzer::global() else {
+        return task::spawn_blocking(move || {
+            messages
+                .iter()
+                .map(|message| lexicon_sentiment::analyze(token_analyzer, message))
+                .collect()
+        })
+        .await
+        .map_err(|e| format!("Lexicon sentiment analysis failed: {}", e));
+    };
+
+    let mut results = Vec::with_capacity(messages.len());
+
+    for batch in messages.chunks(SENTIMENT_BATCH_SIZE) {
+        let batch = batch.to_vec();
+
+        // 추론은 CPU 작업이므로 blocking 스레드에서 수행
+        let batch_results = task::spawn_blocking(move || {
+            let texts: Vec<&str> = batch.iter().map(String::as_str).collect();
+            analyzer.analyze_batch(&texts)
+        })
+        .await
+        .map_err(|e| format!("Sentiment batch analysis failed: {}", e))??;
+
+        results.extend(batch_results);
+    }
+
+    Ok(results)
+}
@@ -1,31 +1,40 @@
-use std::collections::VecDeque;
+use chrono::{DateTime, Duration, Utc};
+use std::sync::Arc;
 
 use crate::services::stats::{
     interface::StatsMatrix,
     matrix::active_chatter_ranking::calculate_active_chatter_ranking,
-    models::{EnrichedChatData, EnrichedDonationData},
+    models::EnrichedChatData,
     stats_trait::Stats,
+    window::{ChatterCounts, SlidingWindow},
 };
 
-pub struct ActiveChatterRankingStats;
+pub struct ActiveChatterRankingStats {
+    interval_cycles: u64,
+    chats: SlidingWindow<EnrichedChatData, ChatterCounts>,
+}
 
 impl ActiveChatterRankingStats {
-    pub fn new() -> Self {
-        Self {}
+    pub fn new(interval_cycles: u64, window: Duration) -> Self {
+        Self {
+            interval_cycles,
+            chats: SlidingWindow::new(window, ChatterCounts::default()),
+        }
     }
 }
 
 impl Stats for ActiveChatterRankingStats {
     fn interval_cycles(&self) -> u64 {
-        2
+        self.interval_cycles
+    }
+
+    fn on_chat(&mut self, chat: &Arc<EnrichedChatData>) {
+        self.chats.push(chat);
     }
 
-    fn evaluate(
-        &self,
-        chat_data: &VecDeque<EnrichedChatData>,
-        _donation_data: &VecDeque<EnrichedDonationData>,
-    ) -> StatsMatrix {
-        let result = calculate_active_chatter_ranking(chat_data);
+    fn evaluate(&mut self, now: DateTime<Utc>) -> StatsMatrix {
+        self.chats.advance(now);
+        let result = calculate_active_chatter_ranking(self.chats.aggregate(), now);
         StatsMatrix::ActiveChatterRanking(result)
     }
 }
@@ -1,30 +1,49 @@
-use std::collections::VecDeque;
+use chrono::{DateTime, Duration, Utc};
+use std::sync::Arc;
 
 use crate::services::stats::interface::StatsMatrix;
-use crate::services::stats::matrix::active_viewer::calculate_active_viewer;
+use crate::services::stats::matrix::active_viewer::{
+    calculate_active_viewer, ChatterGroups, DonorSet,
+};
+use crate::services::stats::window::SlidingWindow;
 
 use super::models::*;
 use super::stats_trait::Stats;
 
-pub struct ActiveViewerStats;
+pub struct ActiveViewerStats {
+    interval_cycles: u64,
+    chats: SlidingWindow<EnrichedChatData, ChatterGroups>,
+    donations: SlidingWindow<EnrichedDonationData, DonorSet>,
+}
 
 impl ActiveViewerStats {
-    pub fn new() -> Self {
-        Self
+    pub fn new(interval_cycles: u64, window: Duration) -> Self {
+        Self {
+            interval_cycles,
+            chats: SlidingWindow::new(window, ChatterGroups::default()),
+            donations: SlidingWindow::new(window, DonorSet::default()),
+        }
     }
 }
 
 impl Stats for ActiveViewerStats {
     fn interval_cycles(&self) -> u64 {
-        1 // 1 cycle = 2500ms
+        self.interval_cycles
+    }
+
+    fn on_chat(&mut self, chat: &Arc<EnrichedChatData>) {
+        self.chats.push(chat);
+    }
+
+    fn on_donation(&mut self, donation: &Arc<EnrichedDonationData>) {
+        self.donations.push(donation);
     }
 
-    fn evaluate(
-        &self,
-        chat_data: &VecDeque<EnrichedChatData>,
-        donation_data: &VecDeque<EnrichedDonationData>,
-    ) -> StatsMatrix {
-        let active_viewer_data = calculate_active_viewer(chat_data, donation_data);
+    fn evaluate(&mut self, now: DateTime<Utc>) -> StatsMatrix {
+        self.chats.advance(now);
+        self.donations.advance(now);
+        let active_viewer_data =
+            calculate_active_viewer(self.chats.aggregate(), self.donations.aggregate(), now);
         StatsMatrix::ActiveViewer(active_viewer_data)
     }
 }
@@ -0,0 +1,42 @@
+use chrono::{DateTime, Duration, Utc};
+use std::sync::Arc;
+
+use crate::services::stats::interface::StatsMatrix;
+use crate::services::stats::matrix::anomaly::{calculate_anomaly, AnomalyCounts, ChatterHistory};
+use crate::services::stats::window::SlidingWindow;
+
+use super::models::*;
+use super::stats_trait::Stats;
+
+pub struct AnomalyStats {
+    interval_cycles: u64,
+    chats: SlidingWindow<EnrichedChatData, AnomalyCounts>,
+    history: ChatterHistory,
+}
+
+impl AnomalyStats {
+    pub fn new(interval_cycles: u64, window: Duration) -> Self {
+        Self {
+            interval_cycles,
+            chats: SlidingWindow::new(window, AnomalyCounts::default()),
+            history: ChatterHistory::default(),
+        }
+    }
+}
+
+impl Stats for AnomalyStats {
+    fn interval_cycles(&self) -> u64 {
+        self.interval_cycles
+    }
+
+    fn on_chat(&mut self, chat: &Arc<EnrichedChatData>) {
+        self.history.record(chat);
+        self.chats.push(chat);
+    }
+
+    fn evaluate(&mut self, now: DateTime<Utc>) -> StatsMatrix {
+        self.chats.advance(now);
+        let anomaly = calculate_anomaly(&self.chats, &mut self.history, now);
+        StatsMatrix::Anomaly(anomaly)
+    }
+}
@@ -1,32 +1,40 @@
-use chrono::Utc;
-use std::collections::VecDeque;
+use chrono::{DateTime, Duration, Utc};
+use std::sync::Arc;
 
 use crate::services::stats::interface::StatsMatrix;
 use crate::services::stats::matrix::chat_per_minute::calculate_chat_per_minute;
+use crate::services::stats::window::SlidingWindow;
 
 use super::models::*;
 use super::stats_trait::Stats;
 
-pub struct ChatPerMinuteStats;
+pub struct ChatPerMinuteStats {
+    interval_cycles: u64,
+    chats: SlidingWindow<EnrichedChatData, ()>,
+}
 
 impl ChatPerMinuteStats {
-    pub fn new() -> Self {
-        Self
+    pub fn new(interval_cycles: u64, window: Duration) -> Self {
+        Self {
+            interval_cycles,
+            chats: SlidingWindow::new(window, ()),
+        }
     }
 }
 
 impl Stats for ChatPerMinuteStats {
     fn interval_cycles(&self) -> u64 {
-        1 // 1 cycle = 2500ms
+        self.interval_cycles
+    }
+
+    fn on_chat(&mut self, chat: &Arc<EnrichedChatData>) {
+        self.chats.push(chat);
     }
 
-    fn evaluate(
-        &self,
-        chat_data: &VecDeque<EnrichedChatData>,
-        _donation_data: &VecDeque<EnrichedDonationData>,
-    ) -> StatsMatrix {
-        // 최근 1분간의 채팅 수 계산
-        let cpm = calculate_chat_per_minute(chat_data, Utc::now());
+    fn evaluate(&mut self, now: DateTime<Utc>) -> StatsMatrix {
+        // 최근 window 동안의 분당 채팅 수 계산
+        self.chats.advance(now);
+        let cpm = calculate_chat_per_minute(&self.chats, now);
         StatsMatrix::ChatPerMinute(cpm)
     }
 }
@@ -0,0 +1,194 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{OnceLock, RwLock};
+
+use crate::services::stats::registry::{default_processor_configs, is_known_processor};
+
+const STATS_CONFIG_FILE_NAME: &str = "stats_config.json";
+
+/// 통계 계산 1 사이클의 기본 길이
+const DEFAULT_CYCLE_DURATION_MS: u64 = 2500;
+
+/// 너무 짧은 주기로 UI 이벤트가 폭주하지 않도록 제한합니다.
+const MIN_CYCLE_DURATION_MS: u64 = 500;
+
+/// 유해 표현 경고 기준의 기본값
+const DEFAULT_TOXICITY_ALERT_THRESHOLD: f32 = 0.8;
+
+static GLOBAL_STATS_CONFIG: OnceLock<StatsConfigStore> = OnceLock::new();
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StatsConfig {
+    pub cycle_duration_ms: u64,
+    pub processors: Vec<StatsProcessorConfig>,
+    /**
+     * 유해 표현 라벨 점수가 이 값 이상이면 실시간 경고로 표시합니다. (0 ~ 1)
+     */
+    #[serde(default = "default_toxicity_alert_threshold")]
+    pub toxicity_alert_threshold: f32,
+}
+
+fn default_toxicity_alert_threshold() -> f32 {
+    DEFAULT_TOXICITY_ALERT_THRESHOLD
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StatsProcessorConfig {
+    /**
+     * StatsMatrix의 variant 이름과 같습니다. (ChatPerMinute, LOL 등)
+     */
+    pub id: String,
+    pub enabled: bool,
+    /**
+     * 계산 주기 (사이클 단위)
+     */
+    pub interval_cycles: u64,
+    /**
+     * 최근 몇 초간의 데이터를 대상으로 계산할지를 의미합니다.
+     */
+    pub window_seconds: i64,
+}
+
+impl Default for StatsConfig {
+    fn default() -> Self {
+        Self {
+            cycle_duration_ms: DEFAULT_CYCLE_DURATION_MS,
+            processors: default_processor_configs(),
+            toxicity_alert_threshold: DEFAULT_TOXICITY_ALERT_THRESHOLD,
+        }
+    }
+}
+
+impl StatsConfig {
+    pub fn validate(&self) -> Result<(), String> {
+        if self.cycle_duration_ms < MIN_CYCLE_DURATION_MS {
+            return Err(format!(
+                "cycle_duration_ms must be at least {}",
+                MIN_CYCLE_DURATION_MS
+            ));
+        }
+
+        if !(0.0..=1.0).contains(&self.toxicity_alert_threshold) {
+            return Err("toxicity_alert_threshold must be between 0 and 1".to_string());
+        }
+
+        let mut processor_ids = HashSet::new();
+        for processor in &self.processors {
+            if !is_known_processor(&processor.id) {
+                return Err(format!("Unknown stats processor: {}", processor.id));
+            }
+            // 같은 ID가 두 번 있으면 같은 통계를 내보내는 프로세서가 두 개 생성됩니다.
+            if !processor_ids.insert(processor.id.as_str()) {
+                return Err(format!("Duplicate stats processor: {}", processor.id));
+            }
+            if processor.interval_cycles == 0 {
+                return Err(format!(
+                    "interval_cycles must be positive: {}",
+                    processor.id
+                ));
+            }
+            if processor.window_seconds <= 0 {
+                return Err(format!("window_seconds must be positive: {}", processor.id));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// 저장된 설정에 없는 프로세서(새로 추가된 프로세서 등)는 기본값으로 채웁니다.
+    fn fill_missing_processors(&mut self) {
+        for default in default_processor_configs() {
+            if !self.processors.iter().any(|p| p.id == default.id) {
+                self.processors.push(default);
+            }
+        }
+    }
+
+    /// 활성화된 프로세서 중 가장 긴 윈도우 (버퍼 보관 기간)
+    pub fn max_window_seconds(&self) -> i64 {
+        self.processors
+            .iter()
+            .filter(|p| p.enabled)
+            .map(|p| p.window_seconds)
+            .max()
+            .unwrap_or(0)
+    }
+}
+
+/**
+ * 통계 설정을 보관하고 파일로 저장합니다.
+ * 설정이 바뀔 때마다 version이 증가하며, 실행 중인 CoreStatsService가 이를 감지해 반영합니다.
+ */
+pub struct StatsConfigStore {
+    path: RwLock<Option<PathBuf>>,
+    config: RwLock<StatsConfig>,
+    version: AtomicU64,
+}
+
+impl StatsConfigStore {
+    pub fn global() -> &'static StatsConfigStore {
+        GLOBAL_STATS_CONFIG.get_or_init(|| StatsConfigStore {
+            path: RwLock::new(None),
+            config: RwLock::new(StatsConfig::default()),
+            version: AtomicU64::new(0),
+        })
+    }
+
+    /// 앱 데이터 폴더에서 저장된 설정을 불러옵니다.
+    pub fn load(app_dir: PathBuf) -> Result<(), String> {
+        let store = Self::global();
+        let path = app_dir.join(STATS_CONFIG_FILE_NAME);
+
+        if path.exists() {
+            let content = std::fs::read_to_string(&path)
+                .map_err(|e| format!("Failed to read stats config: {}", e))?;
+            match serde_json::from_str::<StatsConfig>(&content) {
+                Ok(mut config) if config.validate().is_ok() => {
+                    config.fill_missing_processors();
+                    store.replace(config);
+                }
+                _ => eprintln!("[StatsConfigStore] Invalid stats config, using defaults"),
+            }
+        }
+
+        *store.path.write().map_err(|e| e.to_string())? = Some(path);
+        Ok(())
+    }
+
+    pub fn get(&self) -> StatsConfig {
+        self.config
+            .read()
+            .map(|config| config.clone())
+            .unwrap_or_default()
+    }
+
+    pub fn version(&self) -> u64 {
+        self.version.load(Ordering::Acquire)
+    }
+
+    pub fn update(&self, mut config: StatsConfig) -> Result<(), String> {
+        config.validate()?;
+        config.fill_missing_processors();
+
+        if let Some(path) = self.path.read().map_err(|e| e.to_string())?.as_ref() {
+            let content = serde_json::to_string_pretty(&config)
+                .map_err(|e| format!("Failed to serialize stats config: {}", e))?;
+            std::fs::write(path, content)
+                .map_err(|e| format!("Failed to write stats config: {}", e))?;
+        }
+
+        self.replace(config);
+        Ok(())
+    }
+
+    fn replace(&self, config: StatsConfig) {
+        if let Ok(mut guard) = self.config.write() {
+            *guard = config;
+            self.version.fetch_add(1, Ordering::AcqRel);
+        }
+    }
+}
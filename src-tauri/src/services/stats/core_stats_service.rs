@@ -1,34 +1,34 @@
 use chrono::{Duration, Utc};
 use std::collections::VecDeque;
+use std::sync::atomic::{AtomicI64, AtomicU64, Ordering};
 use std::sync::Arc;
 use tokio::sync::RwLock;
 use tokio::task::JoinHandle;
 use tokio::time::{interval, Duration as TokioDuration};
 
+use crate::services::addons::interface::BroadcastMetadata;
+use crate::services::db::service::DBService;
 use crate::services::event_name;
-use crate::services::stats::active_chatter_ranking_stats::ActiveChatterRankingStats;
-use crate::services::stats::active_viewer_stats::ActiveViewerStats;
+use crate::services::stats::config::{StatsConfig, StatsConfigStore};
 use crate::services::stats::interface::StatsMatrix;
-use crate::services::stats::lol_stats::LOLStats;
-use crate::services::stats::sentiment_stats::SentimentStats;
-use crate::services::stats::word_count_stats::WordCountStats;
+use crate::services::stats::registry::create_stats_processor;
+use crate::services::stats::snapshot_recorder::StatsSnapshotRecorder;
 
-use super::chat_per_minute_stats::ChatPerMinuteStats;
 use super::models::*;
 use super::stats_trait::Stats;
 use tauri::{AppHandle, Emitter};
 
-const CYCLE_DURATION_MS: u64 = 2500;
-
-fn cycles_to_duration(cycles: u64) -> TokioDuration {
-    TokioDuration::from_millis(cycles * CYCLE_DURATION_MS)
-}
-
 pub struct CoreStatsService {
-    chat_data: Arc<RwLock<VecDeque<EnrichedChatData>>>,
-    donation_data: Arc<RwLock<VecDeque<EnrichedDonationData>>>,
-    time_window_minutes: u32,
-    stats_processors: Vec<Box<dyn Stats>>,
+    // 각 프로세서는 자신의 윈도우를 직접 유지합니다.
+    // 이 버퍼는 설정 변경으로 새로 만든 프로세서를 채우는 용도로만 사용합니다.
+    chat_data: Arc<RwLock<VecDeque<Arc<EnrichedChatData>>>>,
+    donation_data: Arc<RwLock<VecDeque<Arc<EnrichedDonationData>>>>,
+    // 활성화된 프로세서 중 가장 긴 윈도우만큼 데이터를 보관합니다.
+    time_window_seconds: AtomicI64,
+    stats_processors: RwLock<Vec<Box<dyn Stats>>>,
+    // 마지막으로 반영한 StatsConfigStore의 버전
+    config_version: AtomicU64,
+    snapshot_recorder: StatsSnapshotRecorder,
     app_handle: AppHandle,
     max_buffer_size: usize,
     cleanup_task: Arc<RwLock<Option<JoinHandle<()>>>>,
@@ -38,49 +38,104 @@ pub struct CoreStatsService {
 impl CoreStatsService {
     const DEFAULT_MAX_BUFFER_SIZE: usize = 50000; // 기본 최대 버퍼 크기
 
-    pub fn new(time_window_minutes: u32, app_handle: AppHandle) -> Self {
-        let mut service = Self {
+    pub fn new(app_handle: AppHandle) -> Self {
+        let store = StatsConfigStore::global();
+        let config_version = store.version();
+        let config = store.get();
+
+        Self {
             chat_data: Arc::new(RwLock::new(VecDeque::with_capacity(1000))),
             donation_data: Arc::new(RwLock::new(VecDeque::with_capacity(100))),
-            time_window_minutes,
-            stats_processors: Vec::new(),
+            time_window_seconds: AtomicI64::new(config.max_window_seconds()),
+            stats_processors: RwLock::new(Self::create_processors(&config)),
+            config_version: AtomicU64::new(config_version),
+            snapshot_recorder: StatsSnapshotRecorder::default(),
             app_handle,
             max_buffer_size: Self::DEFAULT_MAX_BUFFER_SIZE,
             cleanup_task: Arc::new(RwLock::new(None)),
             batch_task: Arc::new(RwLock::new(None)),
-        };
-
-        service.add_stats_processor(Box::new(ChatPerMinuteStats::new()));
-        service.add_stats_processor(Box::new(LOLStats::new()));
-        service.add_stats_processor(Box::new(ActiveViewerStats::new()));
-        service.add_stats_processor(Box::new(ActiveChatterRankingStats::new()));
-        service.add_stats_processor(Box::new(WordCountStats::new()));
-        service.add_stats_processor(Box::new(SentimentStats::new()));
-        service
+        }
+    }
+
+    fn create_processors(config: &StatsConfig) -> Vec<Box<dyn Stats>> {
+        config
+            .processors
+            .iter()
+            .filter_map(create_stats_processor)
+            .collect()
+    }
+
+    /// 설정이 변경되었다면 프로세서를 다시 구성합니다.
+    async fn reload_config_if_changed(&self) {
+        let store = StatsConfigStore::global();
+        let version = store.version();
+        if self.config_version.swap(version, Ordering::AcqRel) == version {
+            return;
+        }
+
+        let config = store.get();
+        let mut processors = Self::create_processors(&config);
+
+        // 새 프로세서는 빈 윈도우로 시작하므로 보관 중인 데이터로 채웁니다.
+        let mut stats_processors = self.stats_processors.write().await;
+        {
+            let (chat_data, donation_data) =
+                tokio::join!(self.chat_data.read(), self.donation_data.read());
+            for processor in processors.iter_mut() {
+                for chat in chat_data.iter() {
+                    processor.on_chat(chat);
+                }
+                for donation in donation_data.iter() {
+                    processor.on_donation(donation);
+                }
+            }
+        }
+        *stats_processors = processors;
+        drop(stats_processors);
+
+        self.time_window_seconds
+            .store(config.max_window_seconds(), Ordering::Release);
     }
 
-    pub fn add_stats_processor(&mut self, processor: Box<dyn Stats>) {
-        self.stats_processors.push(processor);
+    /// 계산된 통계를 저장할 방송을 지정합니다.
+    pub async fn bind_broadcast(&self, db: Arc<DBService>, metadata: &BroadcastMetadata) {
+        self.snapshot_recorder.bind(db, metadata).await;
     }
 
     pub async fn record_chat_data(&self, data: EnrichedChatData) {
-        let mut chat_data = self.chat_data.write().await;
-        chat_data.push_back(data);
+        let data = Arc::new(data);
+        {
+            let mut chat_data = self.chat_data.write().await;
+            chat_data.push_back(Arc::clone(&data));
+
+            // 메모리 제한 - 최대 크기 초과 시 오래된 데이터 제거
+            if chat_data.len() > self.max_buffer_size {
+                chat_data.pop_front();
+            }
+        }
 
-        // 메모리 제한 - 최대 크기 초과 시 오래된 데이터 제거
-        if chat_data.len() > self.max_buffer_size {
-            chat_data.pop_front();
+        let mut stats_processors = self.stats_processors.write().await;
+        for processor in stats_processors.iter_mut() {
+            processor.on_chat(&data);
         }
     }
 
     pub async fn record_donation_data(&self, data: EnrichedDonationData) {
-        let mut donation_data = self.donation_data.write().await;
-        donation_data.push_back(data);
+        let data = Arc::new(data);
+        {
+            let mut donation_data = self.donation_data.write().await;
+            donation_data.push_back(Arc::clone(&data));
 
-        // 도네이션은 일반적으로 적으므로 채팅의 1/10 크기로 제한
-        let max_donation_size = self.max_buffer_size / 10;
-        if donation_data.len() > max_donation_size {
-            donation_data.pop_front();
+            // 도네이션은 일반적으로 적으므로 채팅의 1/10 크기로 제한
+            let max_donation_size = self.max_buffer_size / 10;
+            if donation_data.len() > max_donation_size {
+                donation_data.pop_front();
+            }
+        }
+
+        let mut stats_processors = self.stats_processors.write().await;
+        for processor in stats_processors.iter_mut() {
+            processor.on_donation(&data);
         }
     }
 
@@ -99,14 +154,24 @@ impl CoreStatsService {
         // 배치 처리 스케줄러 - 각 통계의 interval_cycles에 따라 계산
         let batch_service = Arc::clone(&self);
         let batch_task = tokio::spawn(async move {
-            let mut batch_timer = interval(cycles_to_duration(1));
+            let mut cycle_duration_ms = StatsConfigStore::global().get().cycle_duration_ms;
+            let mut batch_timer = interval(TokioDuration::from_millis(cycle_duration_ms));
             let mut cycle_count = 0u64;
 
             loop {
                 batch_timer.tick().await;
                 cycle_count += 1;
 
+                batch_service.reload_config_if_changed().await;
                 batch_service.process_all_stats_batch(cycle_count).await;
+
+                // 사이클 길이가 바뀌면 타이머를 다시 생성합니다.
+                let configured_ms = StatsConfigStore::global().get().cycle_duration_ms;
+                if configured_ms != cycle_duration_ms {
+                    cycle_duration_ms = configured_ms;
+                    batch_timer = interval(TokioDuration::from_millis(cycle_duration_ms));
+                    batch_timer.tick().await;
+                }
             }
         });
 
@@ -120,28 +185,29 @@ impl CoreStatsService {
     }
 
     async fn process_all_stats_batch(&self, cycle_count: u64) {
-        // 한 번만 락 획득하여 데이터 복사
-        let (chat_data_clone, donation_data_clone) = {
-            let (chat_guard, donation_guard) =
-                tokio::join!(self.chat_data.read(), self.donation_data.read());
-            (chat_guard.clone(), donation_guard.clone())
-        };
+        let now = Utc::now();
 
-        let results: Vec<StatsMatrix> = self
-            .stats_processors
-            .iter()
+        // 각 프로세서가 변경분만 반영해 두었으므로 데이터를 복사하지 않고 바로 계산합니다.
+        let mut stats_processors = self.stats_processors.write().await;
+        let results: Vec<StatsMatrix> = stats_processors
+            .iter_mut()
             .filter(|processor| cycle_count % processor.interval_cycles() == 0)
-            .map(|processor| processor.evaluate(&chat_data_clone, &donation_data_clone))
+            .map(|processor| processor.evaluate(now))
             .collect();
 
-        for stat in results {
-            let _ = self.app_handle.emit(event_name::LOG_STATS, &stat);
+        drop(stats_processors);
+
+        for stat in &results {
+            let _ = self.app_handle.emit(event_name::LOG_STATS, stat);
         }
+
+        self.snapshot_recorder.record(&results, now).await;
     }
 
     async fn cleanup_old_data_periodic(&self) {
         let current_time = Utc::now();
-        let cutoff_time = current_time - Duration::minutes(self.time_window_minutes as i64);
+        let cutoff_time =
+            current_time - Duration::seconds(self.time_window_seconds.load(Ordering::Acquire));
 
         // 채팅 데이터 정리 - VecDeque에서 앞쪽부터 제거
         {
@@ -178,5 +244,8 @@ impl CoreStatsService {
         if let Some(task) = batch_guard.take() {
             task.abort();
         }
+
+        // 남은 통계 스냅샷 저장
+        self.snapshot_recorder.finish().await;
     }
 }
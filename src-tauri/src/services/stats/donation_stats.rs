@@ -0,0 +1,39 @@
+use chrono::{DateTime, Duration, Utc};
+use std::sync::Arc;
+
+use crate::services::stats::interface::StatsMatrix;
+use crate::services::stats::matrix::donation::{calculate_donation_stats, DonationTotals};
+use crate::services::stats::window::SlidingWindow;
+
+use super::models::*;
+use super::stats_trait::Stats;
+
+pub struct DonationStats {
+    interval_cycles: u64,
+    donations: SlidingWindow<EnrichedDonationData, DonationTotals>,
+}
+
+impl DonationStats {
+    pub fn new(interval_cycles: u64, window: Duration) -> Self {
+        Self {
+            interval_cycles,
+            donations: SlidingWindow::new(window, DonationTotals::default()),
+        }
+    }
+}
+
+impl Stats for DonationStats {
+    fn interval_cycles(&self) -> u64 {
+        self.interval_cycles
+    }
+
+    fn on_donation(&mut self, donation: &Arc<EnrichedDonationData>) {
+        self.donations.push(donation);
+    }
+
+    fn evaluate(&mut self, now: DateTime<Utc>) -> StatsMatrix {
+        self.donations.advance(now);
+        let donation_stats = calculate_donation_stats(&self.donations, now);
+        StatsMatrix::Donation(donation_stats)
+    }
+}
@@ -0,0 +1,39 @@
+use chrono::{DateTime, Duration, Utc};
+use std::sync::Arc;
+
+use crate::services::stats::interface::StatsMatrix;
+use crate::services::stats::matrix::emoticon::{calculate_emoticon, EmoticonCounts};
+use crate::services::stats::window::SlidingWindow;
+
+use super::models::*;
+use super::stats_trait::Stats;
+
+pub struct EmoticonStats {
+    interval_cycles: u64,
+    chats: SlidingWindow<EnrichedChatData, EmoticonCounts>,
+}
+
+impl EmoticonStats {
+    pub fn new(interval_cycles: u64, window: Duration) -> Self {
+        Self {
+            interval_cycles,
+            chats: SlidingWindow::new(window, EmoticonCounts::default()),
+        }
+    }
+}
+
+impl Stats for EmoticonStats {
+    fn interval_cycles(&self) -> u64 {
+        self.interval_cycles
+    }
+
+    fn on_chat(&mut self, chat: &Arc<EnrichedChatData>) {
+        self.chats.push(chat);
+    }
+
+    fn evaluate(&mut self, now: DateTime<Utc>) -> StatsMatrix {
+        self.chats.advance(now);
+        let emoticon = calculate_emoticon(self.chats.aggregate(), now);
+        StatsMatrix::Emoticon(emoticon)
+    }
+}
@@ -46,6 +46,226 @@ pub struct WordCountItem {
     pub count: u32,
 }
 
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DonationStatsData {
+    pub timestamp: DateTime<Utc>,
+    /**
+     * 윈도우 내 후원 합계입니다.
+     */
+    pub total_amount: u64,
+    pub donation_count: u32,
+    pub donations_per_minute: f32,
+    pub amount_per_minute: f32,
+    pub top_donors: Vec<DonorRankingItem>,
+    /**
+     * 윈도우 내 단일 최고 후원입니다.
+     */
+    pub biggest_donation: Option<BiggestDonationItem>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DonorRankingItem {
+    pub user_id: String,
+    pub user_name: String,
+    pub amount: u64,
+    pub count: u32,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BiggestDonationItem {
+    pub user_id: String,
+    pub user_name: String,
+    pub amount: u32,
+    pub message: Option<String>,
+    pub timestamp: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AnomalyData {
+    pub timestamp: DateTime<Utc>,
+    /**
+     * 여러 사용자가 같은(거의 같은) 메시지를 보내는 도배입니다.
+     */
+    pub copy_paste_floods: Vec<CopyPasteFlood>,
+    /**
+     * 한 사용자가 짧은 시간에 많은 채팅을 보내는 경우입니다.
+     */
+    pub bursts: Vec<ChatBurst>,
+    /**
+     * 처음 보는 채팅 참여자가 평소보다 급격히 늘어난 경우입니다. (습격 등)
+     */
+    pub new_chatter_surge: Option<NewChatterSurge>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CopyPasteFlood {
+    pub fingerprint: String,
+    pub sample_message: String,
+    pub user_count: u32,
+    pub message_count: u32,
+    pub users: Vec<User>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChatBurst {
+    pub user: User,
+    pub message_count: u32,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NewChatterSurge {
+    pub new_chatter_count: u32,
+    /**
+     * 윈도우당 평균 신규 참여자 수입니다.
+     */
+    pub baseline: f32,
+    pub users: Vec<User>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EmoticonData {
+    pub timestamp: DateTime<Utc>,
+    pub signature_emoticons: Vec<SignatureEmoticonRankingItem>,
+    pub ogq_stickers: Vec<OgqStickerRankingItem>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SignatureEmoticonRankingItem {
+    pub title: String,
+    pub tier: u8,
+    pub image_url: String,
+    pub count: u32,
+    pub user_count: u32,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OgqStickerRankingItem {
+    pub id: String,
+    pub number: String,
+    pub image_url: String,
+    pub count: u32,
+    pub user_count: u32,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TrendingData {
+    pub timestamp: DateTime<Utc>,
+    pub terms: Vec<TrendingTerm>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TrendingTerm {
+    pub word: String,
+    /**
+     * 최근 구간에서 언급된 횟수입니다.
+     */
+    pub recent_count: u32,
+    pub user_count: u32,
+    /**
+     * 비교 기준 구간(윈도우의 나머지)에서 언급된 횟수입니다.
+     */
+    pub baseline_count: u32,
+    /**
+     * 최근 구간 언급 빈도가 기준 구간의 몇 배인지를 의미합니다.
+     */
+    pub score: f32,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReactionData {
+    pub timestamp: DateTime<Utc>,
+    pub reactions: Vec<ReactionCountItem>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReactionCountItem {
+    /**
+     * 반응 분류 ID입니다. (ReactionCategory.id)
+     */
+    pub id: String,
+    pub label: String,
+    pub count: u32,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ToxicityData {
+    pub timestamp: DateTime<Utc>,
+    /**
+     * 윈도우 내 유해 표현 분류를 거친 채팅 수입니다. (부하가 높으면 표본만 분류됩니다)
+     */
+    pub analyzed_count: u32,
+    /**
+     * 가장 높은 라벨 점수가 경고 기준 이상인 채팅 수입니다.
+     */
+    pub flagged_count: u32,
+    /**
+     * 경고 기준을 넘은 채팅입니다. 최신순으로 정렬됩니다.
+     */
+    pub alerts: Vec<ToxicityAlert>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ToxicityAlert {
+    pub event_id: String,
+    pub user: User,
+    pub message: String,
+    pub label: String,
+    pub score: f32,
+    pub timestamp: DateTime<Utc>,
+}
+
+/**
+ * 활성 유저 그룹을 구독 티어까지 나눈 구분입니다.
+ * 구독과 팬이 경합하는 경우 구독으로 포함됩니다. (ActiveViewerData와 같은 기준)
+ */
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum UserSegment {
+    SubscriberTier1,
+    SubscriberTier2,
+    Fan,
+    Normal,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SegmentStatsData {
+    pub timestamp: DateTime<Utc>,
+    pub segments: Vec<SegmentStatsItem>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SegmentStatsItem {
+    pub segment: UserSegment,
+    /**
+     * 윈도우 내 채팅한 사용자 수입니다.
+     */
+    pub active_users: u32,
+    pub chat_count: u32,
+    /**
+     * 분당 채팅 수로 환산한 값입니다.
+     */
+    pub chats_per_minute: u32,
+    pub sentiment: SentimentSummary,
+    pub top_words: Vec<WordCountItem>,
+}
+
 // 전처리 과정을 거친 이벤트입니다.
 #[derive(Debug, Clone, Serialize)]
 #[serde(tag = "type", content = "payload")]
@@ -56,6 +276,13 @@ pub enum StatsMatrix {
     ActiveChatterRanking(ActiveChatterRankingData),
     WordCount(WordCountData),
     Sentiment(SentimentSummary),
+    Donation(DonationStatsData),
+    Anomaly(AnomalyData),
+    Emoticon(EmoticonData),
+    Trending(TrendingData),
+    Segment(SegmentStatsData),
+    Reaction(ReactionData),
+    Toxicity(ToxicityData),
 }
 
 // 전처리 과정을 거친 이벤트입니다.
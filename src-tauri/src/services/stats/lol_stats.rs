@@ -1,32 +1,39 @@
-use chrono::Utc;
-use std::collections::VecDeque;
+use chrono::{DateTime, Duration, Utc};
+use std::sync::Arc;
 
 use crate::services::stats::interface::StatsMatrix;
-use crate::services::stats::matrix::lol::calculate_lol;
+use crate::services::stats::matrix::lol::{calculate_lol, LOLCounter};
+use crate::services::stats::window::SlidingWindow;
 
 use super::models::*;
 use super::stats_trait::Stats;
 
-pub struct LOLStats;
+pub struct LOLStats {
+    interval_cycles: u64,
+    chats: SlidingWindow<EnrichedChatData, LOLCounter>,
+}
 
 impl LOLStats {
-    pub fn new() -> Self {
-        Self
+    pub fn new(interval_cycles: u64, window: Duration) -> Self {
+        Self {
+            interval_cycles,
+            chats: SlidingWindow::new(window, LOLCounter::default()),
+        }
     }
 }
 
 impl Stats for LOLStats {
     fn interval_cycles(&self) -> u64 {
-        1 // 1 cycle = 2500ms
+        self.interval_cycles
+    }
+
+    fn on_chat(&mut self, chat: &Arc<EnrichedChatData>) {
+        self.chats.push(chat);
     }
 
-    fn evaluate(
-        &self,
-        chat_data: &VecDeque<EnrichedChatData>,
-        _donation_data: &VecDeque<EnrichedDonationData>,
-    ) -> StatsMatrix {
-        // 최근 30초간
-        let lol = calculate_lol(chat_data, Utc::now());
+    fn evaluate(&mut self, now: DateTime<Utc>) -> StatsMatrix {
+        self.chats.advance(now);
+        let lol = calculate_lol(self.chats.aggregate(), now);
         StatsMatrix::LOL(lol)
     }
 }
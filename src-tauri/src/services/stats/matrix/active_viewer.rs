@@ -1,43 +1,78 @@
-use std::collections::{HashSet, VecDeque};
+use std::collections::HashMap;
 
-use chrono::Utc;
+use chrono::{DateTime, Utc};
 use soup_sdk::chat::types::UserStatus;
 
 use crate::services::stats::{
     interface::ActiveViewerData,
     models::{EnrichedChatData, EnrichedDonationData},
+    window::{decrement, Aggregate},
 };
 
-pub fn calculate_active_viewer(
-    chats: &VecDeque<EnrichedChatData>,
-    donations: &VecDeque<EnrichedDonationData>,
-) -> ActiveViewerData {
-    let standard = Utc::now();
+/**
+ * 윈도우 내 채팅 사용자와 그룹별 인원입니다.
+ * 사용자의 그룹은 윈도우에 처음 들어올 때의 상태로 정합니다.
+ */
+#[derive(Default)]
+pub struct ChatterGroups {
+    // user_id -> (그룹, 채팅 수)
+    users: HashMap<String, (u8, u32)>,
+    group_counts: [u32; 3], // [subscriber, fan, normal]
+}
 
-    let mut user_set = HashSet::with_capacity(chats.len() + donations.len());
-    let mut counts = [0u32; 3]; // [subscriber, fan, normal]
+impl Aggregate<EnrichedChatData> for ChatterGroups {
+    fn add(&mut self, chat: &EnrichedChatData) {
+        let (group, count) = self
+            .users
+            .entry(chat.user.id.clone())
+            .or_insert_with(|| (classify_user_group(&chat.user.status), 0));
+        if *count == 0 {
+            self.group_counts[*group as usize] += 1;
+        }
+        *count += 1;
+    }
 
-    // 채팅 사용자 처리
-    for chat in chats.iter() {
-        let user_id = &chat.user.id;
-        if user_set.insert(user_id) {
-            // 중복 체크와 삽입을 동시에
-            let group = classify_user_group(&chat.user.status);
-            if group < 3 {
-                counts[group as usize] += 1;
+    fn remove(&mut self, chat: &EnrichedChatData) {
+        if let Some((group, count)) = self.users.get_mut(&chat.user.id) {
+            *count -= 1;
+            if *count == 0 {
+                self.group_counts[*group as usize] -= 1;
+                self.users.remove(&chat.user.id);
             }
         }
     }
+}
 
-    // 도네이션 사용자 처리 (새로운 사용자만 일반사용자로 분류)
-    for donation in donations.iter() {
-        let user_id = &donation.user_id;
-        if user_set.insert(user_id) {
-            // 새로운 사용자만 카운트
-            counts[2] += 1; // 일반 사용자로 분류
-        }
+/// 윈도우 내 후원 사용자
+#[derive(Default)]
+pub struct DonorSet {
+    users: HashMap<String, u32>,
+}
+
+impl Aggregate<EnrichedDonationData> for DonorSet {
+    fn add(&mut self, donation: &EnrichedDonationData) {
+        *self.users.entry(donation.user_id.clone()).or_insert(0) += 1;
     }
 
+    fn remove(&mut self, donation: &EnrichedDonationData) {
+        decrement(&mut self.users, &donation.user_id);
+    }
+}
+
+pub fn calculate_active_viewer(
+    chatters: &ChatterGroups,
+    donors: &DonorSet,
+    standard: DateTime<Utc>,
+) -> ActiveViewerData {
+    let mut counts = chatters.group_counts;
+
+    // 도네이션 사용자 처리 (채팅하지 않은 사용자만 일반사용자로 분류)
+    counts[2] += donors
+        .users
+        .keys()
+        .filter(|user_id| !chatters.users.contains_key(*user_id))
+        .count() as u32;
+
     ActiveViewerData {
         total: counts.iter().sum(),
         subscriber: counts[0],
@@ -53,7 +88,7 @@ pub fn calculate_active_viewer(
  * 1: 팬(+서포터)
  * 2: 일반 사용자
  */
-fn classify_user_group(user_stats: &UserStatus) -> u8 {
+pub fn classify_user_group(user_stats: &UserStatus) -> u8 {
     if user_stats.follow != 0 {
         return 0;
     } else if user_stats.is_fan || user_stats.is_top_fan || user_stats.is_supporter {
@@ -0,0 +1,216 @@
+use chrono::{DateTime, Duration, Utc};
+use soup_sdk::chat::types::User;
+use std::cmp::Reverse;
+use std::collections::HashMap;
+
+use crate::services::stats::{
+    interface::{AnomalyData, ChatBurst, CopyPasteFlood, NewChatterSurge},
+    models::EnrichedChatData,
+    window::{Aggregate, ChatterCounts, SlidingWindow},
+};
+
+/// 같은 지문의 메시지를 보낸 사용자가 이 수 이상이면 도배로 판단합니다.
+const COPY_PASTE_MIN_USERS: usize = 5;
+
+/// 정규화 후 이보다 짧은 메시지는 도배 검사에서 제외합니다. (ㅋㅋ, ㄷㄷ 등)
+const MIN_FINGERPRINT_LENGTH: usize = 4;
+
+/// 한 사용자가 윈도우 내에 이 수 이상 채팅하면 연속 채팅으로 판단합니다.
+const BURST_MIN_MESSAGES: usize = 6;
+
+/// 신규 채팅 참여자가 이 수 이상이면서 평소의 SURGE_MULTIPLIER배를 넘으면 급증으로 판단합니다.
+const NEW_CHATTER_SURGE_MIN: usize = 10;
+const NEW_CHATTER_SURGE_MULTIPLIER: f32 = 3.0;
+
+/// 방송 초반에는 모두가 신규 참여자이므로 급증 검사를 하지 않습니다.
+const NEW_CHATTER_WARMUP_MINUTES: i64 = 5;
+
+/// 결과에 포함할 최대 사용자 수
+const OFFENDER_LIMIT: usize = 20;
+
+/**
+ * 세션 동안 처음 채팅한 시각을 기억합니다.
+ * 윈도우에는 최근 데이터만 남으므로 신규 참여자 판단을 위해 별도로 보관합니다.
+ */
+#[derive(Default)]
+pub struct ChatterHistory {
+    first_seen: HashMap<String, DateTime<Utc>>,
+    started_at: Option<DateTime<Utc>>,
+}
+
+impl ChatterHistory {
+    pub fn record(&mut self, chat: &EnrichedChatData) {
+        self.first_seen
+            .entry(chat.user.id.clone())
+            .or_insert(chat.timestamp);
+    }
+}
+
+/// 같은 지문을 가진 메시지 묶음
+struct FingerprintGroup {
+    sample_message: String,
+    chatters: ChatterCounts,
+    message_count: u32,
+}
+
+/// 윈도우 내 지문별 메시지와 사용자별 채팅 수
+#[derive(Default)]
+pub struct AnomalyCounts {
+    groups: HashMap<String, FingerprintGroup>,
+    chatters: ChatterCounts,
+}
+
+impl Aggregate<EnrichedChatData> for AnomalyCounts {
+    fn add(&mut self, chat: &EnrichedChatData) {
+        self.chatters.add(&chat.user);
+
+        let key = fingerprint(&chat.message);
+        if key.chars().count() < MIN_FINGERPRINT_LENGTH {
+            return;
+        }
+        let group = self.groups.entry(key).or_insert_with(|| FingerprintGroup {
+            sample_message: chat.message.clone(),
+            chatters: ChatterCounts::default(),
+            message_count: 0,
+        });
+        group.chatters.add(&chat.user);
+        group.message_count += 1;
+    }
+
+    fn remove(&mut self, chat: &EnrichedChatData) {
+        self.chatters.remove(&chat.user.id);
+
+        let key = fingerprint(&chat.message);
+        if let Some(group) = self.groups.get_mut(&key) {
+            group.chatters.remove(&chat.user.id);
+            group.message_count = group.message_count.saturating_sub(1);
+            if group.message_count == 0 {
+                self.groups.remove(&key);
+            }
+        }
+    }
+}
+
+pub fn calculate_anomaly(
+    chats: &SlidingWindow<EnrichedChatData, AnomalyCounts>,
+    history: &mut ChatterHistory,
+    standard: DateTime<Utc>,
+) -> AnomalyData {
+    let counts = chats.aggregate();
+    let started_at = *history.started_at.get_or_insert(standard);
+
+    AnomalyData {
+        timestamp: standard,
+        copy_paste_floods: find_copy_paste_floods(counts),
+        bursts: find_bursts(counts),
+        new_chatter_surge: find_new_chatter_surge(
+            counts,
+            history,
+            started_at,
+            standard,
+            chats.window(),
+        ),
+    }
+}
+
+/**
+ * 도배 판단을 위한 메시지 지문을 생성합니다.
+ * 공백/특수문자를 제거하고, 연속된 같은 글자는 하나로 합쳐 약간씩 다른 메시지도 같은 지문이 되도록 합니다.
+ */
+pub fn fingerprint(message: &str) -> String {
+    let mut result = String::with_capacity(message.len());
+    let mut last: Option<char> = None;
+
+    for c in message.chars().flat_map(char::to_lowercase) {
+        if !c.is_alphanumeric() {
+            continue;
+        }
+        if last != Some(c) {
+            result.push(c);
+            last = Some(c);
+        }
+    }
+
+    result
+}
+
+fn find_copy_paste_floods(counts: &AnomalyCounts) -> Vec<CopyPasteFlood> {
+    let mut floods: Vec<CopyPasteFlood> = counts
+        .groups
+        .iter()
+        .filter(|(_, group)| group.chatters.len() >= COPY_PASTE_MIN_USERS)
+        .map(|(fingerprint, group)| CopyPasteFlood {
+            fingerprint: fingerprint.clone(),
+            sample_message: group.sample_message.clone(),
+            user_count: group.chatters.len() as u32,
+            message_count: group.message_count,
+            users: group
+                .chatters
+                .iter()
+                .take(OFFENDER_LIMIT)
+                .map(|(user, _)| user.clone())
+                .collect(),
+        })
+        .collect();
+
+    floods.sort_by_key(|flood| Reverse(flood.user_count));
+    floods
+}
+
+fn find_bursts(counts: &AnomalyCounts) -> Vec<ChatBurst> {
+    let mut bursts: Vec<ChatBurst> = counts
+        .chatters
+        .iter()
+        .filter(|(_, count)| *count as usize >= BURST_MIN_MESSAGES)
+        .map(|(user, message_count)| ChatBurst {
+            user: user.clone(),
+            message_count,
+        })
+        .collect();
+
+    bursts.sort_by_key(|burst| Reverse(burst.message_count));
+    bursts.truncate(OFFENDER_LIMIT);
+    bursts
+}
+
+fn find_new_chatter_surge(
+    counts: &AnomalyCounts,
+    history: &ChatterHistory,
+    started_at: DateTime<Utc>,
+    standard: DateTime<Utc>,
+    window: Duration,
+) -> Option<NewChatterSurge> {
+    let elapsed = standard - started_at;
+    if elapsed < Duration::minutes(NEW_CHATTER_WARMUP_MINUTES) {
+        return None;
+    }
+
+    let window_start = standard - window;
+    let new_chatters: Vec<User> = counts
+        .chatters
+        .iter()
+        .filter(|(user, _)| {
+            history
+                .first_seen
+                .get(&user.id)
+                .is_some_and(|first_seen| *first_seen >= window_start)
+        })
+        .map(|(user, _)| user.clone())
+        .collect();
+
+    // 지금까지 윈도우당 평균 신규 참여자 수
+    let window_count = (elapsed.num_seconds() as f32 / window.num_seconds().max(1) as f32).max(1.0);
+    let baseline = history.first_seen.len() as f32 / window_count;
+
+    if new_chatters.len() < NEW_CHATTER_SURGE_MIN
+        || (new_chatters.len() as f32) < baseline * NEW_CHATTER_SURGE_MULTIPLIER
+    {
+        return None;
+    }
+
+    Some(NewChatterSurge {
+        new_chatter_count: new_chatters.len() as u32,
+        baseline,
+        users: new_chatters.into_iter().take(OFFENDER_LIMIT).collect(),
+    })
+}
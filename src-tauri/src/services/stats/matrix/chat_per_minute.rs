@@ -1,21 +1,15 @@
-use chrono::{DateTime, Duration, Utc};
-use std::collections::VecDeque;
+use chrono::{DateTime, Utc};
 
-use crate::services::stats::{interface::ChatPerMinuteData, models::EnrichedChatData};
+use crate::services::stats::{
+    interface::ChatPerMinuteData, models::EnrichedChatData, window::SlidingWindow,
+};
 
 pub fn calculate_chat_per_minute(
-    chats: &VecDeque<EnrichedChatData>,
+    chats: &SlidingWindow<EnrichedChatData, ()>,
     standard: DateTime<Utc>,
 ) -> ChatPerMinuteData {
-    let one_minute_ago = standard - Duration::minutes(1);
-
-    // 이진 탐색으로 시작점 찾기 (VecDeque는 시간순으로 정렬되어 있음)
-    let start_index = chats
-        .iter()
-        .position(|chat| chat.timestamp >= one_minute_ago)
-        .unwrap_or(chats.len());
-
-    let count = chats.len() - start_index;
+    // 윈도우가 1분이 아닌 경우 분당 개수로 환산합니다.
+    let count = chats.len() as i64 * 60 / chats.window().num_seconds().max(1);
 
     ChatPerMinuteData {
         timestamp: standard,
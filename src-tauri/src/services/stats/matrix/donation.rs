@@ -0,0 +1,91 @@
+use chrono::{DateTime, Utc};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use crate::services::stats::{
+    interface::{BiggestDonationItem, DonationStatsData, DonorRankingItem},
+    models::EnrichedDonationData,
+    window::{Aggregate, SlidingWindow},
+};
+
+/// 실시간 후원 순위에 보여줄 최대 인원
+const TOP_DONOR_LIMIT: usize = 10;
+
+/// 윈도우 내 후원 합계와 후원자별 합계
+#[derive(Default)]
+pub struct DonationTotals {
+    total_amount: u64,
+    donation_count: u32,
+    donors: HashMap<String, DonorRankingItem>,
+}
+
+impl Aggregate<EnrichedDonationData> for DonationTotals {
+    fn add(&mut self, donation: &EnrichedDonationData) {
+        self.total_amount += donation.amount as u64;
+        self.donation_count += 1;
+
+        let donor = self
+            .donors
+            .entry(donation.user_id.clone())
+            .or_insert_with(|| DonorRankingItem {
+                user_id: donation.user_id.clone(),
+                user_name: donation.user_name.clone(),
+                amount: 0,
+                count: 0,
+            });
+        donor.amount += donation.amount as u64;
+        donor.count += 1;
+    }
+
+    fn remove(&mut self, donation: &EnrichedDonationData) {
+        self.total_amount = self.total_amount.saturating_sub(donation.amount as u64);
+        self.donation_count = self.donation_count.saturating_sub(1);
+
+        if let Some(donor) = self.donors.get_mut(&donation.user_id) {
+            donor.amount = donor.amount.saturating_sub(donation.amount as u64);
+            donor.count -= 1;
+            if donor.count == 0 {
+                self.donors.remove(&donation.user_id);
+            }
+        }
+    }
+}
+
+pub fn calculate_donation_stats(
+    donations: &SlidingWindow<EnrichedDonationData, DonationTotals>,
+    standard: DateTime<Utc>,
+) -> DonationStatsData {
+    let totals = donations.aggregate();
+
+    // 후원은 채팅보다 훨씬 적으므로 최고 후원은 윈도우를 직접 훑어 찾습니다.
+    // 같은 금액이면 최근 후원을 우선합니다.
+    let biggest_donation = donations.iter().fold(
+        None::<&Arc<EnrichedDonationData>>,
+        |biggest, donation| match biggest {
+            Some(biggest) if donation.amount < biggest.amount => Some(biggest),
+            _ => Some(donation),
+        },
+    );
+
+    let mut top_donors: Vec<DonorRankingItem> = totals.donors.values().cloned().collect();
+    top_donors.sort_by(|a, b| b.amount.cmp(&a.amount).then(b.count.cmp(&a.count)));
+    top_donors.truncate(TOP_DONOR_LIMIT);
+
+    let window_minutes = donations.window().num_seconds().max(1) as f32 / 60.0;
+
+    DonationStatsData {
+        timestamp: standard,
+        total_amount: totals.total_amount,
+        donation_count: totals.donation_count,
+        donations_per_minute: totals.donation_count as f32 / window_minutes,
+        amount_per_minute: totals.total_amount as f32 / window_minutes,
+        top_donors,
+        biggest_donation: biggest_donation.map(|donation| BiggestDonationItem {
+            user_id: donation.user_id.clone(),
+            user_name: donation.user_name.clone(),
+            amount: donation.amount,
+            message: donation.message.clone(),
+            timestamp: donation.timestamp,
+        }),
+    }
+}
@@ -0,0 +1,108 @@
+use chrono::{DateTime, Utc};
+use std::cmp::Reverse;
+use std::collections::HashMap;
+
+use crate::services::stats::{
+    interface::{EmoticonData, OgqStickerRankingItem, SignatureEmoticonRankingItem},
+    models::EnrichedChatData,
+    window::{Aggregate, UserCounter},
+};
+use crate::util::emoticon::ogq_image_url;
+
+/// 이모티콘/스티커 순위를 각각 몇 개까지 보여줄지를 의미합니다.
+const EMOTICON_RANKING_LIMIT: usize = 10;
+
+struct UsageCounter<T> {
+    item: T,
+    usage: UserCounter,
+}
+
+/// 윈도우 내 시그니처 이모티콘/OGQ 스티커 사용 횟수
+#[derive(Default)]
+pub struct EmoticonCounts {
+    // 제목 -> (티어, 이미지 주소)
+    signatures: HashMap<String, UsageCounter<(u8, String)>>,
+    // OGQ 스티커는 (패키지 id, 번호)로 구분합니다.
+    stickers: HashMap<(String, String), UsageCounter<String>>,
+}
+
+impl Aggregate<EnrichedChatData> for EmoticonCounts {
+    fn add(&mut self, chat: &EnrichedChatData) {
+        for emoticon in &chat.signature_emoticons {
+            self.signatures
+                .entry(emoticon.title.clone())
+                .or_insert_with(|| UsageCounter {
+                    item: (emoticon.tier, emoticon.image_url.clone()),
+                    usage: UserCounter::default(),
+                })
+                .usage
+                .add(&chat.user.id);
+        }
+
+        if let Some(ogq) = &chat.ogq {
+            self.stickers
+                .entry((ogq.id.clone(), ogq.number.clone()))
+                .or_insert_with(|| UsageCounter {
+                    item: ogq_image_url(&ogq.id, &ogq.number, &ogq.ext, &ogq.version),
+                    usage: UserCounter::default(),
+                })
+                .usage
+                .add(&chat.user.id);
+        }
+    }
+
+    fn remove(&mut self, chat: &EnrichedChatData) {
+        for emoticon in &chat.signature_emoticons {
+            if let Some(counter) = self.signatures.get_mut(&emoticon.title) {
+                if counter.usage.remove(&chat.user.id) {
+                    self.signatures.remove(&emoticon.title);
+                }
+            }
+        }
+
+        if let Some(ogq) = &chat.ogq {
+            let key = (ogq.id.clone(), ogq.number.clone());
+            if let Some(counter) = self.stickers.get_mut(&key) {
+                if counter.usage.remove(&chat.user.id) {
+                    self.stickers.remove(&key);
+                }
+            }
+        }
+    }
+}
+
+pub fn calculate_emoticon(counts: &EmoticonCounts, standard: DateTime<Utc>) -> EmoticonData {
+    let mut signature_emoticons: Vec<SignatureEmoticonRankingItem> = counts
+        .signatures
+        .iter()
+        .map(|(title, counter)| SignatureEmoticonRankingItem {
+            title: title.clone(),
+            tier: counter.item.0,
+            image_url: counter.item.1.clone(),
+            count: counter.usage.count,
+            user_count: counter.usage.user_count() as u32,
+        })
+        .collect();
+    signature_emoticons.sort_by_key(|item| Reverse(item.count));
+    signature_emoticons.truncate(EMOTICON_RANKING_LIMIT);
+
+    let mut ogq_stickers: Vec<OgqStickerRankingItem> = counts
+        .stickers
+        .iter()
+        .map(|((id, number), counter)| OgqStickerRankingItem {
+            id: id.clone(),
+            number: number.clone(),
+            image_url: counter.item.clone(),
+            count: counter.usage.count,
+            user_count: counter.usage.user_count() as u32,
+        })
+        .collect();
+    ogq_stickers.sort_by_key(|item| Reverse(item.count));
+    ogq_stickers.truncate(EMOTICON_RANKING_LIMIT);
+
+    EmoticonData {
+        timestamp: standard,
+        signature_emoticons,
+        ogq_stickers,
+    }
+}
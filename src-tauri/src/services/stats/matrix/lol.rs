@@ -1,22 +1,30 @@
-use chrono::{DateTime, Duration, Utc};
-use std::collections::VecDeque;
+use chrono::{DateTime, Utc};
 
-use crate::services::stats::{interface::LOLData, models::EnrichedChatData};
+use crate::services::stats::{interface::LOLData, models::EnrichedChatData, window::Aggregate};
 
-pub fn calculate_lol(chats: &VecDeque<EnrichedChatData>, standard: DateTime<Utc>) -> LOLData {
-    let thirty_seconds_ago = standard - Duration::seconds(10);
+/// 윈도우 내 LOL 채팅 수
+#[derive(Default)]
+pub struct LOLCounter {
+    count: u32,
+}
 
-    // 이진 탐색으로 시작점 찾기
-    let start_index = chats
-        .iter()
-        .position(|chat| chat.timestamp >= thirty_seconds_ago)
-        .unwrap_or(chats.len());
+impl Aggregate<EnrichedChatData> for LOLCounter {
+    fn add(&mut self, chat: &EnrichedChatData) {
+        if chat.is_lol {
+            self.count += 1;
+        }
+    }
 
-    // 시작점부터 끝까지만 검사하여 LOL 카운트
-    let lol_count = chats.iter().skip(start_index).filter(|c| c.is_lol).count();
+    fn remove(&mut self, chat: &EnrichedChatData) {
+        if chat.is_lol {
+            self.count = self.count.saturating_sub(1);
+        }
+    }
+}
 
+pub fn calculate_lol(counter: &LOLCounter, standard: DateTime<Utc>) -> LOLData {
     LOLData {
         timestamp: standard,
-        count: lol_count as u32,
+        count: counter.count,
     }
 }
@@ -1,6 +1,13 @@
 pub mod active_chatter_ranking;
 pub mod active_viewer;
+pub mod anomaly;
 pub mod chat_per_minute;
+pub mod donation;
+pub mod emoticon;
 pub mod lol;
+pub mod reaction;
+pub mod segment;
 pub mod sentiment;
+pub mod toxicity;
+pub mod trending;
 pub mod word_count;
@@ -0,0 +1,50 @@
+use chrono::{DateTime, Utc};
+use std::collections::HashMap;
+
+use crate::services::addons::data_enrichment::reaction_rules::ReactionRules;
+use crate::services::stats::{
+    interface::{ReactionCountItem, ReactionData},
+    models::EnrichedChatData,
+    window::{decrement, Aggregate},
+};
+
+/// 윈도우 내 반응 분류별 채팅 수
+#[derive(Default)]
+pub struct ReactionCounts {
+    counts: HashMap<String, u32>,
+}
+
+impl Aggregate<EnrichedChatData> for ReactionCounts {
+    fn add(&mut self, chat: &EnrichedChatData) {
+        for reaction in &chat.reactions {
+            *self.counts.entry(reaction.clone()).or_insert(0) += 1;
+        }
+    }
+
+    fn remove(&mut self, chat: &EnrichedChatData) {
+        for reaction in &chat.reactions {
+            decrement(&mut self.counts, reaction);
+        }
+    }
+}
+
+/// 활성화된 반응 분류마다 윈도우 내 채팅 수를 규칙 순서대로 반환합니다.
+pub fn calculate_reaction(
+    counts: &ReactionCounts,
+    rules: &ReactionRules,
+    standard: DateTime<Utc>,
+) -> ReactionData {
+    let reactions = rules
+        .enabled_categories()
+        .map(|category| ReactionCountItem {
+            id: category.id.clone(),
+            label: category.label.clone(),
+            count: counts.counts.get(&category.id).copied().unwrap_or(0),
+        })
+        .collect();
+
+    ReactionData {
+        timestamp: standard,
+        reactions,
+    }
+}
@@ -0,0 +1,103 @@
+use chrono::{DateTime, Utc};
+use soup_sdk::chat::types::UserStatus;
+
+use crate::services::stats::{
+    interface::{SegmentStatsData, SegmentStatsItem, UserSegment},
+    matrix::{
+        active_viewer::classify_user_group,
+        sentiment::{calculate_sentiment_summary, SentimentCounter},
+        word_count::TokenCounts,
+    },
+    models::EnrichedChatData,
+    window::{Aggregate, SlidingWindow, UserCounter},
+};
+
+/// 그룹별로 보여줄 상위 단어 수
+const SEGMENT_TOP_WORD_LIMIT: usize = 10;
+
+const SEGMENTS: [UserSegment; 4] = [
+    UserSegment::SubscriberTier1,
+    UserSegment::SubscriberTier2,
+    UserSegment::Fan,
+    UserSegment::Normal,
+];
+
+/**
+ * 사용자를 활성 유저 그룹으로 분류하고, 구독자는 티어(UserStatus.follow)까지 나눕니다.
+ */
+pub fn classify_user_segment(user_stats: &UserStatus) -> UserSegment {
+    match classify_user_group(user_stats) {
+        0 if user_stats.follow >= 2 => UserSegment::SubscriberTier2,
+        0 => UserSegment::SubscriberTier1,
+        1 => UserSegment::Fan,
+        _ => UserSegment::Normal,
+    }
+}
+
+/// 그룹 하나의 채팅 수, 감정, 단어 빈도
+#[derive(Default)]
+struct SegmentCounter {
+    chats: UserCounter,
+    sentiment: SentimentCounter,
+    tokens: TokenCounts,
+}
+
+/// 윈도우 내 그룹별 집계 (SEGMENTS 순서)
+#[derive(Default)]
+pub struct SegmentCounts {
+    segments: [SegmentCounter; 4],
+}
+
+impl SegmentCounts {
+    fn segment_mut(&mut self, chat: &EnrichedChatData) -> &mut SegmentCounter {
+        let segment = classify_user_segment(&chat.user.status);
+        let index = SEGMENTS
+            .iter()
+            .position(|s| *s == segment)
+            .unwrap_or(SEGMENTS.len() - 1);
+        &mut self.segments[index]
+    }
+}
+
+impl Aggregate<EnrichedChatData> for SegmentCounts {
+    fn add(&mut self, chat: &EnrichedChatData) {
+        let counter = self.segment_mut(chat);
+        counter.chats.add(&chat.user.id);
+        counter.sentiment.add(chat);
+        counter.tokens.add(chat);
+    }
+
+    fn remove(&mut self, chat: &EnrichedChatData) {
+        // 같은 채팅이므로 추가할 때와 같은 그룹으로 분류됩니다.
+        let counter = self.segment_mut(chat);
+        counter.chats.remove(&chat.user.id);
+        counter.sentiment.remove(chat);
+        counter.tokens.remove(chat);
+    }
+}
+
+pub fn calculate_segment_stats(
+    chats: &SlidingWindow<EnrichedChatData, SegmentCounts>,
+    standard: DateTime<Utc>,
+) -> SegmentStatsData {
+    let window_seconds = chats.window().num_seconds().max(1);
+
+    let segments = SEGMENTS
+        .iter()
+        .zip(chats.aggregate().segments.iter())
+        .map(|(segment, counter)| SegmentStatsItem {
+            segment: *segment,
+            active_users: counter.chats.user_count() as u32,
+            chat_count: counter.chats.count,
+            // 윈도우가 1분이 아닌 경우 분당 개수로 환산합니다.
+            chats_per_minute: (counter.chats.count as i64 * 60 / window_seconds) as u32,
+            sentiment: calculate_sentiment_summary(&counter.sentiment),
+            top_words: counter.tokens.top_words(SEGMENT_TOP_WORD_LIMIT),
+        })
+        .collect();
+
+    SegmentStatsData {
+        timestamp: standard,
+        segments,
+    }
+}
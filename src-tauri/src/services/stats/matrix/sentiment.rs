@@ -1,25 +1,69 @@
-use chrono::{DateTime, Duration, Utc};
-use std::collections::VecDeque;
-
-use crate::services::ai::sentiment_analyzer::Sentiment;
+use crate::services::ai::sentiment_analyzer::{Sentiment, SentimentMethod};
+use crate::services::ai::SentimentAnalyzer;
 use crate::services::stats::models::EnrichedChatData;
 use crate::services::stats::sentiment_stats::SentimentSummary;
+use crate::services::stats::window::Aggregate;
+
+/// 윈도우 내 감정 분석 결과의 합계
+#[derive(Default)]
+pub struct SentimentCounter {
+    positive_count: u32,
+    negative_count: u32,
+    neutral_count: u32,
+    lexicon_count: u32,
+    // 더하고 빼기를 반복하므로 오차를 줄이기 위해 f64로 보관합니다.
+    score_sum: f64,
+}
+
+impl SentimentCounter {
+    fn total_count(&self) -> u32 {
+        self.positive_count + self.negative_count + self.neutral_count
+    }
+}
+
+impl Aggregate<EnrichedChatData> for SentimentCounter {
+    fn add(&mut self, chat: &EnrichedChatData) {
+        let Some(analysis) = &chat.sentiment_analysis else {
+            return;
+        };
+
+        match analysis.sentiment {
+            Sentiment::Positive => self.positive_count += 1,
+            Sentiment::Negative => self.negative_count += 1,
+            Sentiment::Neutral => self.neutral_count += 1,
+        }
+        if analysis.method == SentimentMethod::Lexicon {
+            self.lexicon_count += 1;
+        }
+        self.score_sum += analysis.score as f64;
+    }
 
-/// 최근 15초간의 감정분석 통계를 계산합니다.
-pub fn calculate_sentiment_summary(
-    chat_data: &VecDeque<EnrichedChatData>,
-    current_time: DateTime<Utc>,
-) -> SentimentSummary {
-    let fifteen_seconds_ago = current_time - Duration::seconds(15);
+    fn remove(&mut self, chat: &EnrichedChatData) {
+        let Some(analysis) = &chat.sentiment_analysis else {
+            return;
+        };
+
+        match analysis.sentiment {
+            Sentiment::Positive => self.positive_count -= 1,
+            Sentiment::Negative => self.negative_count -= 1,
+            Sentiment::Neutral => self.neutral_count -= 1,
+        }
+        if analysis.method == SentimentMethod::Lexicon {
+            self.lexicon_count -= 1;
+        }
+        self.score_sum -= analysis.score as f64;
+
+        if self.total_count() == 0 {
+            self.score_sum = 0.0;
+        }
+    }
+}
 
-    // 최근 15초간의 감정분석 데이터만 필터링
-    let recent_sentiments: Vec<_> = chat_data
-        .iter()
-        .filter(|chat| chat.timestamp >= fifteen_seconds_ago)
-        .filter_map(|chat| chat.sentiment_analysis.as_ref())
-        .collect();
+/// 최근 window 동안의 감정분석 통계를 계산합니다.
+pub fn calculate_sentiment_summary(counter: &SentimentCounter) -> SentimentSummary {
+    let total_count = counter.total_count();
 
-    if recent_sentiments.is_empty() {
+    if total_count == 0 {
         return SentimentSummary {
             positive_count: 0,
             negative_count: 0,
@@ -29,61 +73,28 @@ pub fn calculate_sentiment_summary(
             negative_ratio: 0.0,
             neutral_ratio: 0.0,
             average_score: 0.0,
+            lexicon_count: 0,
+            model_version: None,
         };
     }
 
-    let positive_count = recent_sentiments
-        .iter()
-        .filter(|analysis| matches!(analysis.sentiment, Sentiment::Positive))
-        .count() as u32;
-
-    let negative_count = recent_sentiments
-        .iter()
-        .filter(|analysis| matches!(analysis.sentiment, Sentiment::Negative))
-        .count() as u32;
-
-    let neutral_count = recent_sentiments
-        .iter()
-        .filter(|analysis| matches!(analysis.sentiment, Sentiment::Neutral))
-        .count() as u32;
-
-    let total_count = recent_sentiments.len() as u32;
-
-    let positive_ratio = if total_count > 0 {
-        positive_count as f32 / total_count as f32
-    } else {
-        0.0
-    };
-    let negative_ratio = if total_count > 0 {
-        negative_count as f32 / total_count as f32
-    } else {
-        0.0
-    };
-    let neutral_ratio = if total_count > 0 {
-        neutral_count as f32 / total_count as f32
-    } else {
-        0.0
-    };
-
-    // 평균 감정 점수 계산
-    let average_score = if total_count > 0 {
-        recent_sentiments
-            .iter()
-            .map(|analysis| analysis.score)
-            .sum::<f32>()
-            / total_count as f32
-    } else {
-        0.0
-    };
+    let total = total_count as f32;
 
     SentimentSummary {
-        positive_count,
-        negative_count,
-        neutral_count,
+        positive_count: counter.positive_count,
+        negative_count: counter.negative_count,
+        neutral_count: counter.neutral_count,
         total_count,
-        positive_ratio,
-        negative_ratio,
-        neutral_ratio,
-        average_score,
+        positive_ratio: counter.positive_count as f32 / total,
+        negative_ratio: counter.negative_count as f32 / total,
+        neutral_ratio: counter.neutral_count as f32 / total,
+        // 평균 감정 점수
+        average_score: (counter.score_sum / total_count as f64) as f32,
+        lexicon_count: counter.lexicon_count,
+        model_version: if counter.lexicon_count < total_count {
+            SentimentAnalyzer::global().map(SentimentAnalyzer::model_version)
+        } else {
+            None
+        },
     }
 }
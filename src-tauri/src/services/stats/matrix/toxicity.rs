@@ -0,0 +1,65 @@
+use chrono::{DateTime, Utc};
+use std::cmp::Reverse;
+
+use crate::services::stats::{
+    interface::{ToxicityAlert, ToxicityData},
+    models::EnrichedChatData,
+    window::{Aggregate, SlidingWindow},
+};
+
+/// 한 번에 전달할 최대 경고 수
+const MAX_ALERTS: usize = 20;
+
+/// 윈도우 내 유해 표현 분류를 거친 채팅 수
+#[derive(Default)]
+pub struct ToxicityCounts {
+    analyzed: u32,
+}
+
+impl Aggregate<EnrichedChatData> for ToxicityCounts {
+    fn add(&mut self, chat: &EnrichedChatData) {
+        if chat.toxicity.is_some() {
+            self.analyzed += 1;
+        }
+    }
+
+    fn remove(&mut self, chat: &EnrichedChatData) {
+        if chat.toxicity.is_some() {
+            self.analyzed = self.analyzed.saturating_sub(1);
+        }
+    }
+}
+
+/// 가장 높은 라벨 점수가 threshold 이상인 채팅을 경고로 모읍니다.
+/// 기준은 설정에서 바로 바뀔 수 있으므로 집계 값 대신 매번 윈도우를 확인합니다.
+pub fn calculate_toxicity(
+    chats: &SlidingWindow<EnrichedChatData, ToxicityCounts>,
+    threshold: f32,
+    standard: DateTime<Utc>,
+) -> ToxicityData {
+    let mut alerts: Vec<ToxicityAlert> = chats
+        .iter()
+        .filter_map(|chat| {
+            let max = chat.toxicity.as_ref()?.max()?;
+            (max.score >= threshold).then(|| ToxicityAlert {
+                event_id: chat.event_id.to_string(),
+                user: chat.user.clone(),
+                message: chat.message.clone(),
+                label: max.label.clone(),
+                score: max.score,
+                timestamp: chat.timestamp,
+            })
+        })
+        .collect();
+
+    let flagged_count = alerts.len() as u32;
+    alerts.sort_by_key(|alert| Reverse(alert.timestamp));
+    alerts.truncate(MAX_ALERTS);
+
+    ToxicityData {
+        timestamp: standard,
+        analyzed_count: chats.aggregate().analyzed,
+        flagged_count,
+        alerts,
+    }
+}
@@ -0,0 +1,110 @@
+use chrono::{DateTime, Duration, Utc};
+use std::collections::HashMap;
+
+use crate::services::stats::{
+    interface::{TrendingData, TrendingTerm},
+    matrix::word_count::TokenCounts,
+    models::EnrichedChatData,
+    window::{Aggregate, SlidingWindow, UserCounter},
+};
+
+/// 전체 윈도우 중 최근 1/RATIO 구간을 "지금"으로 보고 나머지 구간과 비교합니다.
+const RECENT_WINDOW_RATIO: i32 = 10;
+
+/// 최근 구간에서 이 수 이상의 사용자가 언급한 단어만 대상으로 합니다.
+const MIN_RECENT_USERS: usize = 3;
+
+/// 최근 구간의 언급 빈도가 기준 구간의 몇 배 이상이어야 급상승으로 볼지를 의미합니다.
+const MIN_BURST_SCORE: f32 = 3.0;
+
+/// 기준 구간의 채팅이 이보다 적으면 (방송 초반 등) 비교하지 않습니다.
+const MIN_BASELINE_CHATS: usize = 50;
+
+const TRENDING_TERM_LIMIT: usize = 10;
+
+/// 전체 윈도우 중 "지금"으로 볼 최근 구간의 길이
+pub fn recent_window(window: Duration) -> Duration {
+    window / RECENT_WINDOW_RATIO
+}
+
+/// 최근 구간의 단어별 언급 횟수와 언급한 사용자
+#[derive(Default)]
+pub struct RecentTokens {
+    tokens: HashMap<String, UserCounter>,
+}
+
+impl Aggregate<EnrichedChatData> for RecentTokens {
+    fn add(&mut self, chat: &EnrichedChatData) {
+        for token in &chat.tokens {
+            self.tokens
+                .entry(token.clone())
+                .or_default()
+                .add(&chat.user.id);
+        }
+    }
+
+    fn remove(&mut self, chat: &EnrichedChatData) {
+        for token in &chat.tokens {
+            if let Some(counter) = self.tokens.get_mut(token) {
+                if counter.remove(&chat.user.id) {
+                    self.tokens.remove(token);
+                }
+            }
+        }
+    }
+}
+
+/**
+ * 최근 구간과 기준 구간의 단어 빈도를 비교해 급상승한 단어를 찾습니다.
+ * 평소에도 자주 나오는 단어는 점수가 낮아지므로 지금 채팅이 반응하는 단어만 남습니다.
+ */
+pub fn calculate_trending(
+    recent: &SlidingWindow<EnrichedChatData, RecentTokens>,
+    all: &SlidingWindow<EnrichedChatData, TokenCounts>,
+    standard: DateTime<Utc>,
+) -> TrendingData {
+    // 기준 구간 = 전체 윈도우 - 최근 구간
+    let baseline_chat_count = all.len().saturating_sub(recent.len());
+    if baseline_chat_count < MIN_BASELINE_CHATS {
+        return TrendingData {
+            timestamp: standard,
+            terms: Vec::new(),
+        };
+    }
+
+    let recent_seconds = recent.window().num_seconds().max(1) as f32;
+    let baseline_seconds = (all.window() - recent.window()).num_seconds().max(1) as f32;
+
+    let mut terms: Vec<TrendingTerm> = recent
+        .aggregate()
+        .tokens
+        .iter()
+        .filter(|(word, counter)| {
+            counter.user_count() >= MIN_RECENT_USERS && word.chars().count() >= 2
+        })
+        .filter_map(|(word, counter)| {
+            let recent_count = counter.count;
+            let baseline_count = all.aggregate().get(word).saturating_sub(recent_count);
+            // 기준 구간에 없던 단어도 계산할 수 있도록 1을 더해 보정합니다.
+            let recent_rate = recent_count as f32 / recent_seconds;
+            let baseline_rate = (baseline_count + 1) as f32 / baseline_seconds;
+            let score = recent_rate / baseline_rate;
+
+            (score >= MIN_BURST_SCORE).then(|| TrendingTerm {
+                word: word.clone(),
+                recent_count,
+                user_count: counter.user_count() as u32,
+                baseline_count,
+                score,
+            })
+        })
+        .collect();
+
+    terms.sort_by(|a, b| b.score.total_cmp(&a.score));
+    terms.truncate(TRENDING_TERM_LIMIT);
+
+    TrendingData {
+        timestamp: standard,
+        terms,
+    }
+}
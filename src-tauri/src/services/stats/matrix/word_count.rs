@@ -1,41 +1,62 @@
-use chrono::{Duration, Utc};
-use std::collections::{HashMap, VecDeque};
+use chrono::{DateTime, Utc};
+use std::cmp::Reverse;
+use std::collections::HashMap;
 
 use crate::services::stats::interface::{WordCountData, WordCountItem};
 use crate::services::stats::models::*;
+use crate::services::stats::window::{decrement, Aggregate};
 
-pub fn calculate_word_count(
-    chat_data: &VecDeque<EnrichedChatData>,
-    time_window_seconds: i64,
-) -> WordCountData {
-    let current_time = Utc::now();
-    let cutoff_time = current_time - Duration::seconds(time_window_seconds);
-
-    let mut word_counts: HashMap<String, u32> = HashMap::new();
-
-    // 최근 time_window_minutes 분 데이터에서 토큰 수집
-    for chat in chat_data {
-        if chat.timestamp >= cutoff_time {
-            for token in &chat.tokens {
-                if !token.trim().is_empty() {
-                    *word_counts.entry(token.clone()).or_insert(0) += 1;
-                }
+/// 윈도우 내 토큰별 등장 횟수
+#[derive(Default)]
+pub struct TokenCounts {
+    counts: HashMap<String, u32>,
+}
+
+impl TokenCounts {
+    pub fn get(&self, token: &str) -> u32 {
+        self.counts.get(token).copied().unwrap_or(0)
+    }
+
+    /// 빈도 순으로 상위 limit개 단어를 반환합니다.
+    pub fn top_words(&self, limit: usize) -> Vec<WordCountItem> {
+        let mut words: Vec<(&String, u32)> = self
+            .counts
+            .iter()
+            .map(|(word, count)| (word, *count))
+            .collect();
+
+        words.sort_by_key(|(_, count)| Reverse(*count));
+        words
+            .into_iter()
+            .take(limit)
+            .map(|(word, count)| WordCountItem {
+                word: word.clone(),
+                count,
+            })
+            .collect()
+    }
+}
+
+impl Aggregate<EnrichedChatData> for TokenCounts {
+    fn add(&mut self, chat: &EnrichedChatData) {
+        for token in &chat.tokens {
+            if !token.trim().is_empty() {
+                *self.counts.entry(token.clone()).or_insert(0) += 1;
             }
         }
     }
 
-    // 상위 100개 단어로 제한 (워드클라우드용)
-    let mut word_list: Vec<WordCountItem> = word_counts
-        .into_iter()
-        .map(|(word, count)| WordCountItem { word, count })
-        .collect();
-
-    // 빈도 순으로 정렬
-    word_list.sort_by(|a, b| b.count.cmp(&a.count));
-    word_list.truncate(100);
+    fn remove(&mut self, chat: &EnrichedChatData) {
+        for token in &chat.tokens {
+            decrement(&mut self.counts, token);
+        }
+    }
+}
 
+pub fn calculate_word_count(counts: &TokenCounts, standard: DateTime<Utc>) -> WordCountData {
     WordCountData {
-        timestamp: current_time,
-        words: word_list,
+        timestamp: standard,
+        // 상위 100개 단어로 제한 (워드클라우드용)
+        words: counts.top_words(100),
     }
 }
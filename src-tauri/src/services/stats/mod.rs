@@ -1,11 +1,22 @@
 pub mod active_chatter_ranking_stats;
 pub mod active_viewer_stats;
+pub mod anomaly_stats;
 pub mod chat_per_minute_stats;
+pub mod config;
 pub mod core_stats_service;
+pub mod donation_stats;
+pub mod emoticon_stats;
 pub mod interface;
 pub mod lol_stats;
 pub mod matrix;
 pub mod models;
+pub mod reaction_stats;
+pub mod registry;
+pub mod segment_stats;
 pub mod sentiment_stats;
+pub mod snapshot_recorder;
 pub mod stats_trait;
+pub mod toxicity_stats;
+pub mod trending_stats;
+pub mod window;
 pub mod word_count_stats;
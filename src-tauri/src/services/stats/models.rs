@@ -1,9 +1,11 @@
 use chrono::{DateTime, Utc};
 use serde::Serialize;
-use soup_sdk::chat::types::User;
+use soup_sdk::chat::types::{Emoticon, User};
 use uuid::Uuid;
 
 use crate::services::ai::sentiment_analyzer::AnalysisResult;
+use crate::services::ai::toxicity_classifier::ToxicityResult;
+use crate::util::emoticon::SignatureEmoticonInfo;
 
 #[derive(Debug, Clone, Serialize)]
 pub struct EnrichedChatData {
@@ -16,7 +18,20 @@ pub struct EnrichedChatData {
     pub word_count: usize,
     pub character_count: usize,
     pub is_lol: bool,
+    /**
+     * 반응 분류 규칙에 일치한 분류 ID 목록입니다. (웃음, 놀람 등)
+     */
+    pub reactions: Vec<String>,
     pub sentiment_analysis: Option<AnalysisResult>,
+    /**
+     * 유해 표현 분류 결과입니다. (분류 모델이 있는 경우에만 채워집니다)
+     */
+    pub toxicity: Option<ToxicityResult>,
+    /**
+     * 메시지에 포함된 시그니처 이모티콘입니다. (스트리머 이모티콘 목록을 불러온 경우에만 채워집니다)
+     */
+    pub signature_emoticons: Vec<SignatureEmoticonInfo>,
+    pub ogq: Option<Emoticon>,
 }
 
 #[derive(Debug, Clone, Serialize)]
@@ -0,0 +1,41 @@
+use chrono::{DateTime, Duration, Utc};
+use std::sync::Arc;
+
+use crate::services::addons::data_enrichment::reaction_rules::ReactionRuleStore;
+use crate::services::stats::interface::StatsMatrix;
+use crate::services::stats::matrix::reaction::{calculate_reaction, ReactionCounts};
+use crate::services::stats::window::SlidingWindow;
+
+use super::models::*;
+use super::stats_trait::Stats;
+
+pub struct ReactionStats {
+    interval_cycles: u64,
+    chats: SlidingWindow<EnrichedChatData, ReactionCounts>,
+}
+
+impl ReactionStats {
+    pub fn new(interval_cycles: u64, window: Duration) -> Self {
+        Self {
+            interval_cycles,
+            chats: SlidingWindow::new(window, ReactionCounts::default()),
+        }
+    }
+}
+
+impl Stats for ReactionStats {
+    fn interval_cycles(&self) -> u64 {
+        self.interval_cycles
+    }
+
+    fn on_chat(&mut self, chat: &Arc<EnrichedChatData>) {
+        self.chats.push(chat);
+    }
+
+    fn evaluate(&mut self, now: DateTime<Utc>) -> StatsMatrix {
+        self.chats.advance(now);
+        let rules = ReactionRuleStore::global().get();
+        let reaction = calculate_reaction(self.chats.aggregate(), &rules, now);
+        StatsMatrix::Reaction(reaction)
+    }
+}
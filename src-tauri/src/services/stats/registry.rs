@@ -0,0 +1,91 @@
+use chrono::Duration;
+
+use crate::services::stats::{
+    active_chatter_ranking_stats::ActiveChatterRankingStats,
+    active_viewer_stats::ActiveViewerStats, anomaly_stats::AnomalyStats,
+    chat_per_minute_stats::ChatPerMinuteStats, config::StatsProcessorConfig,
+    donation_stats::DonationStats, emoticon_stats::EmoticonStats, lol_stats::LOLStats,
+    reaction_stats::ReactionStats, segment_stats::SegmentStats, sentiment_stats::SentimentStats,
+    stats_trait::Stats, toxicity_stats::ToxicityStats, trending_stats::TrendingStats,
+    word_count_stats::WordCountStats,
+};
+
+pub const STATS_CHAT_PER_MINUTE: &str = "ChatPerMinute";
+pub const STATS_LOL: &str = "LOL";
+pub const STATS_ACTIVE_VIEWER: &str = "ActiveViewer";
+pub const STATS_ACTIVE_CHATTER_RANKING: &str = "ActiveChatterRanking";
+pub const STATS_WORD_COUNT: &str = "WordCount";
+pub const STATS_SENTIMENT: &str = "Sentiment";
+pub const STATS_DONATION: &str = "Donation";
+pub const STATS_ANOMALY: &str = "Anomaly";
+pub const STATS_EMOTICON: &str = "Emoticon";
+pub const STATS_TRENDING: &str = "Trending";
+pub const STATS_SEGMENT: &str = "Segment";
+pub const STATS_REACTION: &str = "Reaction";
+pub const STATS_TOXICITY: &str = "Toxicity";
+
+/// (id, interval_cycles, window_seconds)
+const DEFAULT_PROCESSORS: [(&str, u64, i64); 13] = [
+    (STATS_CHAT_PER_MINUTE, 1, 60),
+    (STATS_LOL, 1, 10),
+    (STATS_ACTIVE_VIEWER, 1, 120),
+    (STATS_ACTIVE_CHATTER_RANKING, 2, 120),
+    (STATS_WORD_COUNT, 2, 30),
+    (STATS_SENTIMENT, 1, 15),
+    (STATS_DONATION, 2, 300),
+    (STATS_ANOMALY, 1, 15),
+    (STATS_EMOTICON, 2, 60),
+    (STATS_TRENDING, 2, 300),
+    (STATS_SEGMENT, 2, 60),
+    (STATS_REACTION, 1, 10),
+    (STATS_TOXICITY, 1, 30),
+];
+
+pub fn default_processor_configs() -> Vec<StatsProcessorConfig> {
+    DEFAULT_PROCESSORS
+        .iter()
+        .map(
+            |(id, interval_cycles, window_seconds)| StatsProcessorConfig {
+                id: id.to_string(),
+                enabled: true,
+                interval_cycles: *interval_cycles,
+                window_seconds: *window_seconds,
+            },
+        )
+        .collect()
+}
+
+pub fn is_known_processor(id: &str) -> bool {
+    DEFAULT_PROCESSORS.iter().any(|(known, _, _)| *known == id)
+}
+
+/// 설정으로부터 통계 프로세서를 생성합니다. 비활성화된 경우 None을 반환합니다.
+pub fn create_stats_processor(config: &StatsProcessorConfig) -> Option<Box<dyn Stats>> {
+    if !config.enabled {
+        return None;
+    }
+
+    let interval_cycles = config.interval_cycles;
+    let window = Duration::seconds(config.window_seconds);
+
+    let processor: Box<dyn Stats> = match config.id.as_str() {
+        STATS_CHAT_PER_MINUTE => Box::new(ChatPerMinuteStats::new(interval_cycles, window)),
+        STATS_LOL => Box::new(LOLStats::new(interval_cycles, window)),
+        STATS_ACTIVE_VIEWER => Box::new(ActiveViewerStats::new(interval_cycles, window)),
+        STATS_ACTIVE_CHATTER_RANKING => {
+            Box::new(ActiveChatterRankingStats::new(interval_cycles, window))
+        }
+        STATS_WORD_COUNT => Box::new(WordCountStats::new(interval_cycles, window)),
+        STATS_SENTIMENT => Box::new(SentimentStats::new(interval_cycles, window)),
+        STATS_DONATION => Box::new(DonationStats::new(interval_cycles, window)),
+        STATS_ANOMALY => Box::new(AnomalyStats::new(interval_cycles, window)),
+        STATS_EMOTICON => Box::new(EmoticonStats::new(interval_cycles, window)),
+        STATS_TRENDING => Box::new(TrendingStats::new(interval_cycles, window)),
+        STATS_SEGMENT => Box::new(SegmentStats::new(interval_cycles, window)),
+        STATS_REACTION => Box::new(ReactionStats::new(interval_cycles, window)),
+        STATS_TOXICITY => Box::new(ToxicityStats::new(interval_cycles, window)),
+        _ => return None,
+    };
+
+    Some(processor)
+}
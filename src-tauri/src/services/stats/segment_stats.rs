@@ -0,0 +1,39 @@
+use chrono::{DateTime, Duration, Utc};
+use std::sync::Arc;
+
+use crate::services::stats::interface::StatsMatrix;
+use crate::services::stats::matrix::segment::{calculate_segment_stats, SegmentCounts};
+use crate::services::stats::window::SlidingWindow;
+
+use super::models::*;
+use super::stats_trait::Stats;
+
+pub struct SegmentStats {
+    interval_cycles: u64,
+    chats: SlidingWindow<EnrichedChatData, SegmentCounts>,
+}
+
+impl SegmentStats {
+    pub fn new(interval_cycles: u64, window: Duration) -> Self {
+        Self {
+            interval_cycles,
+            chats: SlidingWindow::new(window, SegmentCounts::default()),
+        }
+    }
+}
+
+impl Stats for SegmentStats {
+    fn interval_cycles(&self) -> u64 {
+        self.interval_cycles
+    }
+
+    fn on_chat(&mut self, chat: &Arc<EnrichedChatData>) {
+        self.chats.push(chat);
+    }
+
+    fn evaluate(&mut self, now: DateTime<Utc>) -> StatsMatrix {
+        self.chats.advance(now);
+        let segment_stats = calculate_segment_stats(&self.chats, now);
+        StatsMatrix::Segment(segment_stats)
+    }
+}
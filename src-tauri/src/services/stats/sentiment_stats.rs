@@ -1,31 +1,39 @@
-use chrono::Utc;
-use std::collections::VecDeque;
+use chrono::{DateTime, Duration, Utc};
+use std::sync::Arc;
 
 use crate::services::stats::interface::StatsMatrix;
-use crate::services::stats::matrix::sentiment::calculate_sentiment_summary;
+use crate::services::stats::matrix::sentiment::{calculate_sentiment_summary, SentimentCounter};
+use crate::services::stats::window::SlidingWindow;
 
 use super::models::*;
 use super::stats_trait::Stats;
 
-pub struct SentimentStats;
+pub struct SentimentStats {
+    interval_cycles: u64,
+    chats: SlidingWindow<EnrichedChatData, SentimentCounter>,
+}
 
 impl SentimentStats {
-    pub fn new() -> Self {
-        Self
+    pub fn new(interval_cycles: u64, window: Duration) -> Self {
+        Self {
+            interval_cycles,
+            chats: SlidingWindow::new(window, SentimentCounter::default()),
+        }
     }
 }
 
 impl Stats for SentimentStats {
     fn interval_cycles(&self) -> u64 {
-        1 // 1 cycle = 2500ms, 자주 업데이트하여 실시간 감정 변화 추적
+        self.interval_cycles
+    }
+
+    fn on_chat(&mut self, chat: &Arc<EnrichedChatData>) {
+        self.chats.push(chat);
     }
 
-    fn evaluate(
-        &self,
-        chat_data: &VecDeque<EnrichedChatData>,
-        _donation_data: &VecDeque<EnrichedDonationData>,
-    ) -> StatsMatrix {
-        let sentiment_summary = calculate_sentiment_summary(chat_data, Utc::now());
+    fn evaluate(&mut self, now: DateTime<Utc>) -> StatsMatrix {
+        self.chats.advance(now);
+        let sentiment_summary = calculate_sentiment_summary(self.chats.aggregate());
         StatsMatrix::Sentiment(sentiment_summary)
     }
 }
@@ -41,4 +49,12 @@ pub struct SentimentSummary {
     pub negative_ratio: f32,
     pub neutral_ratio: f32,
     pub average_score: f32,
+    /**
+     * total_count 중 모델 대신 사전 기반으로 분석한 채팅 수입니다.
+     */
+    pub lexicon_count: u32,
+    /**
+     * 모델로 분석한 채팅이 있으면 해당 모델 버전(name@version)입니다.
+     */
+    pub model_version: Option<String>,
 }
@@ -0,0 +1,130 @@
+use chrono::{DateTime, Duration, Utc};
+use std::collections::HashMap;
+use std::sync::Arc;
+use tokio::sync::Mutex;
+
+use crate::services::addons::interface::BroadcastMetadata;
+use crate::services::db::{commands::StatsSnapshotData, service::DBService};
+use crate::services::stats::interface::StatsMatrix;
+
+/// 통계 종류별로 이 간격(초)마다 한 번만 저장합니다. (리포트 청크 크기와 같습니다)
+const SNAPSHOT_INTERVAL_SECONDS: i64 = 30;
+
+/// 이만큼 쌓이면 DB에 한 번에 저장합니다.
+const SNAPSHOT_BATCH_SIZE: usize = 20;
+
+#[derive(Default)]
+struct RecorderState {
+    db: Option<Arc<DBService>>,
+    broadcast_id: Option<i64>,
+    // 현재 연결된 방송 (channel_id, started_at)
+    session_key: Option<(String, DateTime<Utc>)>,
+    last_recorded: HashMap<String, DateTime<Utc>>,
+    pending: Vec<StatsSnapshotData>,
+}
+
+/**
+ * 실시간 통계 결과를 다운샘플링해 방송 단위로 저장합니다.
+ * 방송이 연결되기 전(메타데이터가 없는 경우)에는 아무것도 저장하지 않습니다.
+ */
+#[derive(Default)]
+pub struct StatsSnapshotRecorder {
+    state: Mutex<RecorderState>,
+}
+
+impl StatsSnapshotRecorder {
+    /**
+     * 통계를 저장할 방송을 지정합니다.
+     * 방송 세션은 (channel_id, started_at)으로 식별되므로 DB 로거와 같은 세션을 가리킵니다.
+     */
+    pub async fn bind(&self, db: Arc<DBService>, metadata: &BroadcastMetadata) {
+        let mut state = self.state.lock().await;
+        let session_key = (metadata.channel_id.clone(), metadata.started_at);
+        if state.session_key.as_ref() == Some(&session_key) {
+            return;
+        }
+
+        let broadcast_id = match db
+            .create_broadcast_session(
+                metadata.channel_id.clone(),
+                metadata.title.clone(),
+                metadata.started_at,
+            )
+            .await
+        {
+            Ok(broadcast_id) => broadcast_id,
+            Err(e) => {
+                eprintln!("[StatsSnapshotRecorder] Failed to resolve broadcast: {}", e);
+                return;
+            }
+        };
+
+        // 이전 방송에 남은 스냅샷을 먼저 저장합니다.
+        Self::flush_pending(&mut state).await;
+
+        state.db = Some(db);
+        state.broadcast_id = Some(broadcast_id);
+        state.session_key = Some(session_key);
+        state.last_recorded.clear();
+    }
+
+    pub async fn record(&self, stats: &[StatsMatrix], now: DateTime<Utc>) {
+        let mut state = self.state.lock().await;
+        let Some(broadcast_id) = state.broadcast_id else {
+            return;
+        };
+
+        for stat in stats {
+            // {"type": ..., "payload": ...} 형태로 직렬화됩니다.
+            let Ok(serde_json::Value::Object(mut value)) = serde_json::to_value(stat) else {
+                continue;
+            };
+            let Some(serde_json::Value::String(stats_type)) = value.remove("type") else {
+                continue;
+            };
+
+            let is_due = state.last_recorded.get(&stats_type).map_or(true, |last| {
+                now.signed_duration_since(*last) >= Duration::seconds(SNAPSHOT_INTERVAL_SECONDS)
+            });
+            if !is_due {
+                continue;
+            }
+
+            let payload = value
+                .remove("payload")
+                .unwrap_or(serde_json::Value::Null)
+                .to_string();
+            state.last_recorded.insert(stats_type.clone(), now);
+            state.pending.push(StatsSnapshotData {
+                broadcast_id,
+                stats_type,
+                payload,
+                timestamp: now,
+            });
+        }
+
+        if state.pending.len() >= SNAPSHOT_BATCH_SIZE {
+            Self::flush_pending(&mut state).await;
+        }
+    }
+
+    /// 남은 스냅샷을 저장하고 방송 연결을 해제합니다.
+    pub async fn finish(&self) {
+        let mut state = self.state.lock().await;
+        Self::flush_pending(&mut state).await;
+        *state = RecorderState::default();
+    }
+
+    async fn flush_pending(state: &mut RecorderState) {
+        if state.pending.is_empty() {
+            return;
+        }
+
+        let snapshots = std::mem::take(&mut state.pending);
+        if let Some(db) = &state.db {
+            if let Err(e) = db.insert_stats_snapshots(snapshots).await {
+                eprintln!("[StatsSnapshotRecorder] Failed to save snapshots: {}", e);
+            }
+        }
+    }
+}
@@ -1,17 +1,20 @@
-use std::collections::VecDeque;
+use chrono::{DateTime, Utc};
+use std::sync::Arc;
 
 use crate::services::stats::interface::StatsMatrix;
 
 use super::models::*;
 
 pub trait Stats: Send + Sync {
-    /// 계산 주기 (사이클 단위, 1 사이클 = 2500ms)
+    /// 계산 주기 (사이클 단위, 1 사이클 = StatsConfig.cycle_duration_ms)
     fn interval_cycles(&self) -> u64;
 
-    /// 통계 평가/계산 실행
-    fn evaluate(
-        &self,
-        chat_data: &VecDeque<EnrichedChatData>,
-        donation_data: &VecDeque<EnrichedDonationData>,
-    ) -> StatsMatrix;
+    /// 새 채팅을 집계에 반영합니다.
+    fn on_chat(&mut self, _chat: &Arc<EnrichedChatData>) {}
+
+    /// 새 후원을 집계에 반영합니다.
+    fn on_donation(&mut self, _donation: &Arc<EnrichedDonationData>) {}
+
+    /// 윈도우를 벗어난 데이터를 정리하고 통계를 계산합니다.
+    fn evaluate(&mut self, now: DateTime<Utc>) -> StatsMatrix;
 }
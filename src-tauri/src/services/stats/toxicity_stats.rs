@@ -0,0 +1,41 @@
+use chrono::{DateTime, Duration, Utc};
+use std::sync::Arc;
+
+use crate::services::stats::config::StatsConfigStore;
+use crate::services::stats::interface::StatsMatrix;
+use crate::services::stats::matrix::toxicity::{calculate_toxicity, ToxicityCounts};
+use crate::services::stats::window::SlidingWindow;
+
+use super::models::*;
+use super::stats_trait::Stats;
+
+pub struct ToxicityStats {
+    interval_cycles: u64,
+    chats: SlidingWindow<EnrichedChatData, ToxicityCounts>,
+}
+
+impl ToxicityStats {
+    pub fn new(interval_cycles: u64, window: Duration) -> Self {
+        Self {
+            interval_cycles,
+            chats: SlidingWindow::new(window, ToxicityCounts::default()),
+        }
+    }
+}
+
+impl Stats for ToxicityStats {
+    fn interval_cycles(&self) -> u64 {
+        self.interval_cycles
+    }
+
+    fn on_chat(&mut self, chat: &Arc<EnrichedChatData>) {
+        self.chats.push(chat);
+    }
+
+    fn evaluate(&mut self, now: DateTime<Utc>) -> StatsMatrix {
+        self.chats.advance(now);
+        let threshold = StatsConfigStore::global().get().toxicity_alert_threshold;
+        let toxicity = calculate_toxicity(&self.chats, threshold, now);
+        StatsMatrix::Toxicity(toxicity)
+    }
+}
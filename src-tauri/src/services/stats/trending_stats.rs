@@ -0,0 +1,45 @@
+use chrono::{DateTime, Duration, Utc};
+use std::sync::Arc;
+
+use crate::services::stats::interface::StatsMatrix;
+use crate::services::stats::matrix::trending::{calculate_trending, recent_window, RecentTokens};
+use crate::services::stats::matrix::word_count::TokenCounts;
+use crate::services::stats::window::SlidingWindow;
+
+use super::models::*;
+use super::stats_trait::Stats;
+
+pub struct TrendingStats {
+    interval_cycles: u64,
+    // 최근 구간과 전체 윈도우를 따로 유지하고, 기준 구간은 둘의 차이로 계산합니다.
+    recent: SlidingWindow<EnrichedChatData, RecentTokens>,
+    all: SlidingWindow<EnrichedChatData, TokenCounts>,
+}
+
+impl TrendingStats {
+    pub fn new(interval_cycles: u64, window: Duration) -> Self {
+        Self {
+            interval_cycles,
+            recent: SlidingWindow::new(recent_window(window), RecentTokens::default()),
+            all: SlidingWindow::new(window, TokenCounts::default()),
+        }
+    }
+}
+
+impl Stats for TrendingStats {
+    fn interval_cycles(&self) -> u64 {
+        self.interval_cycles
+    }
+
+    fn on_chat(&mut self, chat: &Arc<EnrichedChatData>) {
+        self.recent.push(chat);
+        self.all.push(chat);
+    }
+
+    fn evaluate(&mut self, now: DateTime<Utc>) -> StatsMatrix {
+        self.recent.advance(now);
+        self.all.advance(now);
+        let trending = calculate_trending(&self.recent, &self.all, now);
+        StatsMatrix::Trending(trending)
+    }
+}
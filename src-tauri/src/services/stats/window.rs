@@ -0,0 +1,151 @@
+use chrono::{DateTime, Duration, Utc};
+use std::collections::{HashMap, VecDeque};
+use std::sync::Arc;
+
+use soup_sdk::chat::types::User;
+
+use super::models::HasTimestamp;
+
+/**
+ * 윈도우에 데이터가 들어오고 나갈 때마다 갱신되는 집계 값입니다.
+ * 매 사이클 전체 데이터를 다시 훑지 않도록 변경분만 반영합니다.
+ */
+pub trait Aggregate<T> {
+    fn add(&mut self, item: &T);
+    fn remove(&mut self, item: &T);
+}
+
+/// 개수만 필요한 경우 (SlidingWindow::len 사용)
+impl<T> Aggregate<T> for () {
+    fn add(&mut self, _item: &T) {}
+    fn remove(&mut self, _item: &T) {}
+}
+
+/**
+ * 최근 window 동안의 데이터를 시간순으로 보관하고, 집계 값을 함께 유지합니다.
+ * 데이터는 프로세서끼리 Arc로 공유하므로 복사하지 않습니다.
+ */
+pub struct SlidingWindow<T, A> {
+    items: VecDeque<Arc<T>>,
+    window: Duration,
+    aggregate: A,
+}
+
+impl<T: HasTimestamp, A: Aggregate<T>> SlidingWindow<T, A> {
+    pub fn new(window: Duration, aggregate: A) -> Self {
+        Self {
+            items: VecDeque::new(),
+            window,
+            aggregate,
+        }
+    }
+
+    pub fn push(&mut self, item: &Arc<T>) {
+        self.aggregate.add(item);
+        self.items.push_back(item.clone());
+    }
+
+    /// 윈도우를 벗어난 데이터를 앞쪽부터 제거합니다.
+    pub fn advance(&mut self, now: DateTime<Utc>) {
+        let cutoff_time = now - self.window;
+        while let Some(front) = self.items.front() {
+            if front.get_timestamp() >= cutoff_time {
+                break;
+            }
+            if let Some(item) = self.items.pop_front() {
+                self.aggregate.remove(&item);
+            }
+        }
+    }
+
+    pub fn aggregate(&self) -> &A {
+        &self.aggregate
+    }
+
+    pub fn window(&self) -> Duration {
+        self.window
+    }
+
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Arc<T>> {
+        self.items.iter()
+    }
+}
+
+/**
+ * 항목별 사용 횟수와 사용자 수를 함께 셉니다.
+ * 사용자별 횟수를 기억해야 윈도우에서 빠질 때 사용자 수를 정확히 줄일 수 있습니다.
+ */
+#[derive(Debug, Default)]
+pub struct UserCounter {
+    pub count: u32,
+    users: HashMap<String, u32>,
+}
+
+impl UserCounter {
+    pub fn add(&mut self, user_id: &str) {
+        self.count += 1;
+        *self.users.entry(user_id.to_string()).or_insert(0) += 1;
+    }
+
+    /// 더 이상 남은 사용이 없으면 true를 반환합니다.
+    pub fn remove(&mut self, user_id: &str) -> bool {
+        self.count = self.count.saturating_sub(1);
+        if let Some(count) = self.users.get_mut(user_id) {
+            *count -= 1;
+            if *count == 0 {
+                self.users.remove(user_id);
+            }
+        }
+        self.count == 0
+    }
+
+    pub fn user_count(&self) -> usize {
+        self.users.len()
+    }
+}
+
+/// 사용자별 채팅 수를 셉니다. (결과에 사용자 정보를 포함해야 하는 경우)
+#[derive(Debug, Default)]
+pub struct ChatterCounts {
+    users: HashMap<String, (User, u32)>,
+}
+
+impl ChatterCounts {
+    pub fn add(&mut self, user: &User) {
+        self.users
+            .entry(user.id.clone())
+            .and_modify(|(_, count)| *count += 1)
+            .or_insert_with(|| (user.clone(), 1));
+    }
+
+    pub fn remove(&mut self, user_id: &str) {
+        if let Some((_, count)) = self.users.get_mut(user_id) {
+            *count -= 1;
+            if *count == 0 {
+                self.users.remove(user_id);
+            }
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.users.len()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&User, u32)> {
+        self.users.values().map(|(user, count)| (user, *count))
+    }
+}
+
+/// HashMap에 담긴 카운터를 줄이고, 0이 되면 항목을 제거합니다.
+pub fn decrement<K: std::hash::Hash + Eq>(counts: &mut HashMap<K, u32>, key: &K) {
+    if let Some(count) = counts.get_mut(key) {
+        *count -= 1;
+        if *count == 0 {
+            counts.remove(key);
+        }
+    }
+}
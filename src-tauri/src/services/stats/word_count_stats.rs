@@ -1,29 +1,38 @@
-use std::collections::VecDeque;
+use chrono::{DateTime, Duration, Utc};
+use std::sync::Arc;
 
 use crate::services::stats::interface::StatsMatrix;
-use crate::services::stats::matrix::word_count::calculate_word_count;
+use crate::services::stats::matrix::word_count::{calculate_word_count, TokenCounts};
 use crate::services::stats::models::*;
 use crate::services::stats::stats_trait::Stats;
+use crate::services::stats::window::SlidingWindow;
 
-pub struct WordCountStats;
+pub struct WordCountStats {
+    interval_cycles: u64,
+    chats: SlidingWindow<EnrichedChatData, TokenCounts>,
+}
 
 impl WordCountStats {
-    pub fn new() -> Self {
-        Self {}
+    pub fn new(interval_cycles: u64, window: Duration) -> Self {
+        Self {
+            interval_cycles,
+            chats: SlidingWindow::new(window, TokenCounts::default()),
+        }
     }
 }
 
 impl Stats for WordCountStats {
     fn interval_cycles(&self) -> u64 {
-        2
+        self.interval_cycles
+    }
+
+    fn on_chat(&mut self, chat: &Arc<EnrichedChatData>) {
+        self.chats.push(chat);
     }
 
-    fn evaluate(
-        &self,
-        chat_data: &VecDeque<EnrichedChatData>,
-        _donation_data: &VecDeque<EnrichedDonationData>,
-    ) -> StatsMatrix {
-        let word_count_data = calculate_word_count(chat_data, 30);
+    fn evaluate(&mut self, now: DateTime<Utc>) -> StatsMatrix {
+        self.chats.advance(now);
+        let word_count_data = calculate_word_count(self.chats.aggregate(), now);
         StatsMatrix::WordCount(word_count_data)
     }
 }
@@ -0,0 +1,85 @@
+use std::fmt::Write;
+
+use crate::services::db::commands::BroadcastSessionResult;
+use crate::services::subtitle_exporter::exporter::{split_millis, SubtitleCue};
+
+/**
+ * 하이라이트 영상에 입힐 수 있도록 1080p 기준 왼쪽 아래에 채팅창처럼 쌓이는 ASS 자막을 생성합니다.
+ * 후원 줄은 Donation 스타일로 색을 바꿉니다.
+ */
+pub fn render_ass(session: &BroadcastSessionResult, cues: &[SubtitleCue]) -> String {
+    let mut ass = String::new();
+
+    let _ = writeln!(ass, "[Script Info]");
+    let _ = writeln!(ass, "Title: {}", escape_text(&session.title));
+    let _ = writeln!(ass, "ScriptType: v4.00+");
+    let _ = writeln!(ass, "WrapStyle: 0");
+    let _ = writeln!(ass, "ScaledBorderAndShadow: yes");
+    let _ = writeln!(ass, "PlayResX: 1920");
+    let _ = writeln!(ass, "PlayResY: 1080");
+
+    let _ = writeln!(ass, "\n[V4+ Styles]");
+    let _ = writeln!(
+        ass,
+        "Format: Name, Fontname, Fontsize, PrimaryColour, SecondaryColour, OutlineColour, BackColour, Bold, Italic, Underline, StrikeOut, ScaleX, ScaleY, Spacing, Angle, BorderStyle, Outline, Shadow, Alignment, MarginL, MarginR, MarginV, Encoding"
+    );
+    let _ = writeln!(
+        ass,
+        "Style: Default,Malgun Gothic,40,&H00FFFFFF,&H00FFFFFF,&H00000000,&H80000000,0,0,0,0,100,100,0,0,1,2,0,1,40,960,40,1"
+    );
+    let _ = writeln!(
+        ass,
+        "Style: Donation,Malgun Gothic,40,&H004FD5FF,&H004FD5FF,&H00000000,&H80000000,-1,0,0,0,100,100,0,0,1,2,0,1,40,960,40,1"
+    );
+
+    let _ = writeln!(ass, "\n[Events]");
+    let _ = writeln!(
+        ass,
+        "Format: Layer, Start, End, Style, Name, MarginL, MarginR, MarginV, Effect, Text"
+    );
+    for cue in cues {
+        let text = cue
+            .lines
+            .iter()
+            .map(|line| {
+                let text = format!(
+                    "{}: {}",
+                    escape_text(&line.username),
+                    escape_text(&line.text)
+                );
+                if line.is_donation {
+                    format!("{{\\rDonation}}{}{{\\r}}", text)
+                } else {
+                    text
+                }
+            })
+            .collect::<Vec<_>>()
+            .join("\\N");
+
+        let _ = writeln!(
+            ass,
+            "Dialogue: 0,{},{},Default,,0,0,0,,{}",
+            format_timestamp(cue.start_millis),
+            format_timestamp(cue.end_millis),
+            text
+        );
+    }
+
+    ass
+}
+
+/// 0:00:00.00 (ASS는 1/100초 단위입니다)
+fn format_timestamp(millis: i64) -> String {
+    let (hours, minutes, seconds, millis) = split_millis(millis);
+    format!("{}:{:02}:{:02}.{:02}", hours, minutes, seconds, millis / 10)
+}
+
+/// 중괄호와 역슬래시는 서식 태그로 해석되므로 전각 문자로 바꾸고 줄바꿈을 공백으로 바꿉니다.
+fn escape_text(text: &str) -> String {
+    text.split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .replace('\\', "＼")
+        .replace('{', "｛")
+        .replace('}', "｝")
+}
@@ -0,0 +1,246 @@
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use soup_sdk::chat::types::DonationType;
+use std::collections::HashSet;
+
+use crate::models::events::{DonationEvent, EVENT_TYPE_DONATION};
+use crate::services::db::service::DBService;
+use crate::services::subtitle_exporter::{
+    ass_renderer::render_ass, srt_renderer::render_srt, vtt_renderer::render_vtt,
+};
+
+pub const SUBTITLE_EXPORT_FORMAT_SRT: &str = "SRT";
+pub const SUBTITLE_EXPORT_FORMAT_ASS: &str = "ASS";
+pub const SUBTITLE_EXPORT_FORMAT_VTT: &str = "VTT";
+
+/// 채팅 한 줄이 화면에 남아 있는 시간 (밀리초)
+const SUBTITLE_DISPLAY_MILLIS: i64 = 5000;
+
+/// 한 화면에 동시에 보여줄 최대 채팅 수 (넘으면 오래된 채팅부터 내립니다)
+const MAX_VISIBLE_LINES: usize = 5;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SubtitleExportOptions {
+    pub broadcast_id: i64,
+    /**
+     * SRT, ASS, VTT 중 하나입니다.
+     */
+    pub format: String,
+    pub output_path: String,
+    /**
+     * VOD 시간에 더할 보정값(초)입니다. VOD가 방송 시작보다 늦게 시작했다면 음수로 지정합니다.
+     */
+    #[serde(default)]
+    pub offset_seconds: f64,
+    #[serde(default)]
+    pub include_donations: bool,
+    /**
+     * 관심 사용자로 등록된 사용자의 채팅과 후원만 내보냅니다.
+     */
+    #[serde(default)]
+    pub target_users_only: bool,
+}
+
+/// VOD 기준 시각이 정해진 채팅 또는 후원 한 줄
+#[derive(Debug, Clone)]
+pub struct SubtitleLine {
+    pub millis: i64,
+    pub username: String,
+    pub text: String,
+    pub is_donation: bool,
+}
+
+/// 화면 구성이 바뀌지 않는 구간과 그 동안 보여줄 줄 (오래된 순)
+#[derive(Debug)]
+pub struct SubtitleCue<'a> {
+    pub start_millis: i64,
+    pub end_millis: i64,
+    pub lines: &'a [SubtitleLine],
+}
+
+#[derive(Debug)]
+pub struct SubtitleExporter {
+    db_service: DBService,
+}
+
+impl SubtitleExporter {
+    pub fn new(db_service: DBService) -> Self {
+        Self { db_service }
+    }
+
+    /**
+     * 방송 세션의 채팅을 VOD에 맞춘 자막 파일로 내보냅니다.
+     * VOD 시간은 리포트의 VOD 이동과 같이 방송 시작 시각으로부터 지난 시간에 보정값을 더한 값입니다.
+     */
+    pub async fn export_subtitle(&self, options: SubtitleExportOptions) -> Result<String, String> {
+        let session = self
+            .db_service
+            .get_broadcast_session(options.broadcast_id)
+            .await?
+            .ok_or_else(|| format!("Broadcast session not found: {}", options.broadcast_id))?;
+
+        if session.vod_id == 0 {
+            return Err(format!(
+                "VOD is not linked to broadcast session: {}",
+                options.broadcast_id
+            ));
+        }
+
+        // 종료 시각과 같은 시각의 로그도 포함하도록 1초 여유를 둡니다.
+        let end_time = session.ended_at.unwrap_or_else(Utc::now) + Duration::seconds(1);
+        let offset_millis = (options.offset_seconds * 1000.0).round() as i64;
+        let vod_millis = |timestamp: DateTime<Utc>| {
+            timestamp
+                .signed_duration_since(session.started_at)
+                .num_milliseconds()
+                + offset_millis
+        };
+
+        let target_user_ids: Option<HashSet<String>> = if options.target_users_only {
+            Some(
+                self.db_service
+                    .get_target_users()
+                    .await?
+                    .into_iter()
+                    .map(|user| user.user_id)
+                    .collect(),
+            )
+        } else {
+            None
+        };
+        let is_included = |user_id: &str| {
+            target_user_ids
+                .as_ref()
+                .map_or(true, |user_ids| user_ids.contains(user_id))
+        };
+
+        let mut lines: Vec<SubtitleLine> = self
+            .db_service
+            .get_chat_logs_for_report(options.broadcast_id, session.started_at, end_time)
+            .await?
+            .into_iter()
+            .filter(|chat| is_included(&chat.user.id) && !chat.message.trim().is_empty())
+            .map(|chat| SubtitleLine {
+                millis: vod_millis(chat.timestamp),
+                username: chat.user.label,
+                text: chat.message,
+                is_donation: false,
+            })
+            .collect();
+
+        if options.include_donations {
+            let event_logs = self
+                .db_service
+                .get_event_logs_for_report(options.broadcast_id, session.started_at, end_time)
+                .await?;
+
+            for event_log in event_logs {
+                if event_log.event_type != EVENT_TYPE_DONATION {
+                    continue;
+                }
+                let event: DonationEvent = serde_json::from_str(&event_log.payload)
+                    .map_err(|e| format!("Failed to parse donation event: {}", e))?;
+                if !is_included(&event.from) {
+                    continue;
+                }
+
+                let mut text = format!(
+                    "{} {}개 후원",
+                    donation_label(&event.donation_type),
+                    event.amount
+                );
+                if let Some(message) = event.message.filter(|message| !message.trim().is_empty()) {
+                    text.push_str(": ");
+                    text.push_str(&message);
+                }
+
+                lines.push(SubtitleLine {
+                    millis: vod_millis(event.timestamp),
+                    username: event.from_label,
+                    text,
+                    is_donation: true,
+                });
+            }
+        }
+
+        // 보정 후 VOD 시작 전에 해당하는 줄은 버립니다.
+        lines.retain(|line| line.millis >= 0);
+        lines.sort_by_key(|line| line.millis);
+
+        let cues = build_cues(&lines);
+        let content = match options.format.as_str() {
+            SUBTITLE_EXPORT_FORMAT_SRT => render_srt(&cues),
+            SUBTITLE_EXPORT_FORMAT_ASS => render_ass(&session, &cues),
+            SUBTITLE_EXPORT_FORMAT_VTT => render_vtt(&cues),
+            _ => return Err(format!("Unsupported subtitle format: {}", options.format)),
+        };
+
+        std::fs::write(&options.output_path, content).map_err(|e| {
+            let error = format!("Failed to write subtitle file: {}", e);
+            println!("SubtitleExporter: {}", error);
+            error
+        })?;
+
+        Ok(options.output_path)
+    }
+}
+
+/**
+ * 채팅이 올라오거나 사라지는 시각마다 구간을 나눠, 구간마다 화면에 남아 있는 최근 채팅을 묶습니다.
+ * 구간이 겹치지 않으므로 겹치는 자막을 지원하지 않는 플레이어에서도 채팅창처럼 보입니다.
+ */
+fn build_cues(lines: &[SubtitleLine]) -> Vec<SubtitleCue<'_>> {
+    let mut boundaries: Vec<i64> = lines
+        .iter()
+        .flat_map(|line| [line.millis, line.millis + SUBTITLE_DISPLAY_MILLIS])
+        .collect();
+    boundaries.sort_unstable();
+    boundaries.dedup();
+
+    let mut cues = Vec::new();
+    // lines[first..next]가 구간 시작 시각에 화면에 남아 있는 줄입니다.
+    let mut first = 0;
+    let mut next = 0;
+
+    for window in boundaries.windows(2) {
+        let (start_millis, end_millis) = (window[0], window[1]);
+
+        while next < lines.len() && lines[next].millis <= start_millis {
+            next += 1;
+        }
+        while first < next && lines[first].millis + SUBTITLE_DISPLAY_MILLIS <= start_millis {
+            first += 1;
+        }
+        if first == next {
+            continue;
+        }
+
+        cues.push(SubtitleCue {
+            start_millis,
+            end_millis,
+            lines: &lines[first.max(next.saturating_sub(MAX_VISIBLE_LINES))..next],
+        });
+    }
+
+    cues
+}
+
+fn donation_label(donation_type: &DonationType) -> &'static str {
+    match donation_type {
+        DonationType::Balloon => "별풍선",
+        DonationType::ADBalloon => "애드벌룬",
+        DonationType::VODBalloon => "VOD 별풍선",
+    }
+}
+
+/// 밀리초를 시, 분, 초, 밀리초로 나눕니다.
+pub fn split_millis(millis: i64) -> (i64, i64, i64, i64) {
+    let millis = millis.max(0);
+    (
+        millis / 3_600_000,
+        (millis % 3_600_000) / 60_000,
+        (millis % 60_000) / 1000,
+        millis % 1000,
+    )
+}
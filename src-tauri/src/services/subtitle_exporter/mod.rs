@@ -0,0 +1,4 @@
+pub mod ass_renderer;
+pub mod exporter;
+pub mod srt_renderer;
+pub mod vtt_renderer;
@@ -0,0 +1,45 @@
+use std::fmt::Write;
+
+use crate::services::subtitle_exporter::exporter::{split_millis, SubtitleCue};
+
+/// SRT는 서식을 지원하지 않는 플레이어가 많아 후원 줄은 기호로만 구분합니다.
+pub fn render_srt(cues: &[SubtitleCue]) -> String {
+    let mut srt = String::new();
+
+    for (index, cue) in cues.iter().enumerate() {
+        let _ = writeln!(srt, "{}", index + 1);
+        let _ = writeln!(
+            srt,
+            "{} --> {}",
+            format_timestamp(cue.start_millis),
+            format_timestamp(cue.end_millis)
+        );
+        for line in cue.lines {
+            let prefix = if line.is_donation { "★ " } else { "" };
+            let _ = writeln!(
+                srt,
+                "{}{}: {}",
+                prefix,
+                single_line(&line.username),
+                single_line(&line.text)
+            );
+        }
+        srt.push('\n');
+    }
+
+    srt
+}
+
+/// 00:00:00,000
+fn format_timestamp(millis: i64) -> String {
+    let (hours, minutes, seconds, millis) = split_millis(millis);
+    format!("{:02}:{:02}:{:02},{:03}", hours, minutes, seconds, millis)
+}
+
+/// 빈 줄은 자막의 끝으로, -->는 시간 줄로 해석되므로 줄바꿈은 공백으로, -->는 →로 바꿉니다.
+fn single_line(text: &str) -> String {
+    text.split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .replace("-->", "→")
+}
@@ -0,0 +1,50 @@
+use std::fmt::Write;
+
+use crate::services::subtitle_exporter::exporter::{split_millis, SubtitleCue};
+
+/// 후원 줄은 donation 클래스로 감싸고 STYLE 블록에서 색을 지정합니다.
+pub fn render_vtt(cues: &[SubtitleCue]) -> String {
+    let mut vtt = String::from("WEBVTT\n\n");
+    vtt.push_str("STYLE\n::cue(.donation) {\n  color: #ffd54f;\n}\n\n");
+
+    for cue in cues {
+        let _ = writeln!(
+            vtt,
+            "{} --> {} align:start line:100%",
+            format_timestamp(cue.start_millis),
+            format_timestamp(cue.end_millis)
+        );
+        for line in cue.lines {
+            let text = format!(
+                "{}: {}",
+                escape_text(&line.username),
+                escape_text(&line.text)
+            );
+            if line.is_donation {
+                let _ = writeln!(vtt, "<c.donation>{}</c>", text);
+            } else {
+                let _ = writeln!(vtt, "{}", text);
+            }
+        }
+        vtt.push('\n');
+    }
+
+    vtt
+}
+
+/// 00:00:00.000
+fn format_timestamp(millis: i64) -> String {
+    let (hours, minutes, seconds, millis) = split_millis(millis);
+    format!("{:02}:{:02}:{:02}.{:03}", hours, minutes, seconds, millis)
+}
+
+/// 태그로 해석되는 문자를 이스케이프하고 줄바꿈을 공백으로 바꿉니다. (자막 내용에 -->는 쓸 수 없습니다)
+fn escape_text(text: &str) -> String {
+    text.split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .replace("-->", "→")
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
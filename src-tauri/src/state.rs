@@ -2,9 +2,17 @@ use std::sync::Arc;
 
 use tokio::sync::Mutex;
 
-use crate::{controllers::main_controller::MainController, services::db::service::DBService};
+use crate::{
+    controllers::main_controller::MainController,
+    services::{
+        db::service::DBService, report_queue::ReportQueue,
+        sentiment_backfill::SentimentBackfillQueue,
+    },
+};
 
 pub struct AppState {
     pub db: Arc<DBService>,
     pub main_controller: Arc<Mutex<MainController>>,
+    pub report_queue: Arc<ReportQueue>,
+    pub sentiment_backfill_queue: Arc<SentimentBackfillQueue>,
 }
@@ -0,0 +1,108 @@
+use serde::Serialize;
+use std::collections::HashMap;
+
+use soup_sdk::models::SignatureEmoticonData;
+
+/// 채팅에서 `/제목/` 형태로 인식할 이모티콘 제목의 최대 길이
+const MAX_EMOTICON_TITLE_LENGTH: usize = 20;
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SignatureEmoticonInfo {
+    pub title: String,
+    /**
+     * 구독 티어 (1 또는 2)
+     */
+    pub tier: u8,
+    pub image_url: String,
+}
+
+/**
+ * 스트리머의 시그니처 이모티콘 목록입니다. (fetch_streamer_emoticon 데이터)
+ * 채팅 속 `/제목/` 후보 중 실제 시그니처 이모티콘만 골라내는 데 사용합니다.
+ */
+#[derive(Debug, Clone, Default)]
+pub struct SignatureEmoticonCatalog {
+    emoticons: HashMap<String, SignatureEmoticonInfo>,
+}
+
+impl SignatureEmoticonCatalog {
+    pub fn new(streamer_id: &str, data: &SignatureEmoticonData) -> Self {
+        let mut emoticons = HashMap::new();
+
+        for (tier, list) in [(1u8, &data.tier_1), (2u8, &data.tier_2)] {
+            for emoticon in list {
+                emoticons
+                    .entry(emoticon.title.clone())
+                    .or_insert_with(|| SignatureEmoticonInfo {
+                        title: emoticon.title.clone(),
+                        tier,
+                        image_url: signature_image_url(streamer_id, &emoticon.pc_img),
+                    });
+            }
+        }
+
+        Self { emoticons }
+    }
+
+    pub fn get(&self, title: &str) -> Option<&SignatureEmoticonInfo> {
+        self.emoticons.get(title)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &SignatureEmoticonInfo> {
+        self.emoticons.values()
+    }
+
+    /// 메시지에 포함된 시그니처 이모티콘 제목을 등장 순서대로 반환합니다.
+    pub fn extract(&self, message: &str) -> Vec<String> {
+        extract_emoticon_titles(message)
+            .into_iter()
+            .filter(|title| self.emoticons.contains_key(title))
+            .collect()
+    }
+}
+
+/**
+ * 메시지에서 `/제목/` 형태의 이모티콘 후보를 추출합니다.
+ * 공백이 포함되거나 너무 긴 구간은 이모티콘으로 보지 않습니다.
+ */
+pub fn extract_emoticon_titles(message: &str) -> Vec<String> {
+    let mut titles = Vec::new();
+    let mut rest = message;
+
+    while let Some(start) = rest.find('/') {
+        let after = &rest[start + 1..];
+        let Some(end) = after.find('/') else {
+            break;
+        };
+
+        let candidate = &after[..end];
+        let length = candidate.chars().count();
+        if length > 0
+            && length <= MAX_EMOTICON_TITLE_LENGTH
+            && !candidate.chars().any(char::is_whitespace)
+        {
+            titles.push(candidate.to_string());
+            rest = &after[end + 1..];
+        } else {
+            // 닫는 '/'가 다음 이모티콘의 여는 '/'일 수 있으므로 거기서 다시 찾습니다.
+            rest = &after[end..];
+        }
+    }
+
+    titles
+}
+
+pub fn signature_image_url(streamer_id: &str, pc_img: &str) -> String {
+    format!(
+        "https://static.file.sooplive.co.kr/signature_emoticon/{}/{}",
+        streamer_id, pc_img
+    )
+}
+
+pub fn ogq_image_url(id: &str, number: &str, ext: &str, version: &str) -> String {
+    format!(
+        "https://ogq-sticker-global-cdn-z01.sooplive.co.kr/sticker/{}/{}_80.{}?ver={}",
+        id, number, ext, version
+    )
+}
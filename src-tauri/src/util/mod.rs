@@ -1,2 +1,5 @@
+pub mod emoticon;
 pub mod hangul;
 pub mod reports;
+pub mod summary;
+pub mod topics;
@@ -1,26 +1,91 @@
 use chrono::{DateTime, Utc};
 use soup_sdk::chat::types::UserStatus;
+use std::cmp::Reverse;
 use std::collections::{HashMap, HashSet};
 
 use crate::{
     models::{
         events::{
-            DonationEvent, MetadataEvent, MissionEvent, MuteEvent, SubscribeEvent, UserEvent,
+            DonationEvent, FreezeEvent, GiftEvent, MetadataEvent, MissionEvent, MuteEvent,
+            SimplifiedUserEvent, SlowEvent, StickerEvent, SubscribeEvent, UserEvent,
         },
         reports::{
-            ChatAnalysis, ChatVital, ChatterRank, DonatorRank, EventAnalysis, EventVital, Matrix,
-            ModerationAnalysis, ModerationVital, ReportChunk, ReportData, ReportMetadata,
-            UserAnalysis, UserHistory, UserVital, WordCount,
+            AmountBucket, BlackHistory, ChatAnalysis, ChatVital, ChatterRank, DonationAnalysis,
+            DonationMessage, DonationTypeBreakdown, DonatorRank, DonorRetention, EmoticonAnalysis,
+            EventAnalysis, EventVital, FanClubJoin, FreezeInterval, GiftTypeBreakdown, Matrix,
+            ModerationAction, ModerationAnalysis, ModerationStats, ModerationVital,
+            ModeratorActionCount, OgqStickerUsage, PrecedingChat, ReactionSummary, RepeatOffender,
+            ReportChunk, ReportData, ReportMetadata, SentimentAnalysis, SentimentMoment,
+            SentimentVital, SignatureEmoticonUsage, SlowInterval, StickerBreakdown, ToxicMessage,
+            ToxicUserRank, ToxicityAnalysis, ToxicityLabelSummary, UserAnalysis, UserHistory,
+            UserVital, WordCount,
         },
     },
     services::{
-        addons::data_enrichment::token_analyzer::TokenAnalyzer,
-        db::commands::{ChatLogResult, EventLogResult},
+        addons::data_enrichment::{
+            reaction_rules::{ReactionRules, LAUGH_REACTION_ID},
+            token_analyzer::TokenAnalyzer,
+        },
+        ai::sentiment_analyzer::{AnalysisResult, Sentiment, SentimentMethod},
+        db::commands::{ChatLogResult, ChatToxicityResult, EventLogResult, StatsSnapshotResult},
+    },
+    util::{
+        emoticon::{ogq_image_url, SignatureEmoticonCatalog},
+        summary::create_chat_summary,
+        topics::create_topic_analysis,
     },
 };
 
 pub const CHUNK_SIZE: u32 = 30;
 
+/// 감정 분석을 한 번에 몇 개의 채팅씩 수행할지를 의미합니다.
+pub const SENTIMENT_BATCH_SIZE: usize = 64;
+
+/// 감정 분석 대표 구간으로 선정되기 위한 최소 분석 채팅 수
+const MIN_SENTIMENT_MOMENT_COUNT: u32 = 5;
+
+/// 대표 구간을 몇 개까지 보여줄지를 의미합니다.
+const SENTIMENT_MOMENT_LIMIT: usize = 5;
+
+/// 후원 금액 분포 구간의 하한값 목록입니다. (마지막 구간은 상한 없음)
+const DONATION_AMOUNT_BUCKETS: [u32; 7] = [1, 10, 100, 500, 1000, 5000, 10000];
+
+/// 조치 기록에 함께 보여줄 대상 사용자의 직전 채팅 수
+const MODERATION_PRECEDING_CHAT_LIMIT: usize = 3;
+
+/// 이모티콘/스티커 순위를 각각 몇 개까지 보여줄지를 의미합니다.
+const EMOTICON_RANK_LIMIT: usize = 20;
+
+/// 유해 표현 경고 채팅/사용자를 각각 몇 개까지 보여줄지를 의미합니다.
+const TOXICITY_RANK_LIMIT: usize = 20;
+
+/// 긍정/부정 비율 차이가 이 값 이하면 전체 감정을 중립으로 판단합니다.
+const OVERALL_SENTIMENT_MARGIN: f64 = 0.05;
+
+/// 리포트 생성에 필요한 방송 전체 데이터입니다.
+pub struct ReportSource<'a> {
+    pub chat_logs: &'a [ChatLogResult],
+    pub event_logs: &'a [EventLogResult],
+    /// 같은 채널의 이전 방송에서 후원한 사용자 ID
+    pub previous_donor_ids: &'a HashSet<String>,
+    /// 스트리머의 시그니처 이모티콘 목록 (불러오지 못한 경우 None)
+    pub emoticon_catalog: Option<&'a SignatureEmoticonCatalog>,
+    /// 청크별 반응 분류에 사용한 규칙
+    pub reaction_rules: &'a ReactionRules,
+    /// 방송 중 저장된 채팅별 유해 표현 점수
+    pub chat_toxicity: &'a [ChatToxicityResult],
+    /// 유해 표현 경고 기준 (0 ~ 1)
+    pub toxicity_threshold: f32,
+    /// 감정 분석 모델 버전 (모델이 없으면 None)
+    pub sentiment_model_version: Option<String>,
+}
+
+/// 감정 분석이 완료된 채팅 한 건입니다.
+pub struct SentimentSample {
+    pub message: String,
+    pub analysis: AnalysisResult,
+}
+
 fn get_user_counts(chat_logs: &[ChatLogResult]) -> [u32; 3] {
     let mut user_set = HashSet::with_capacity(chat_logs.len());
     let mut counts = [0u32; 3]; // [subscriber, fan, normal]
@@ -95,7 +160,7 @@ fn create_popular_words(
     let mut word_counts: HashMap<String, u64> = HashMap::new();
 
     for chat in chat_logs {
-        let tokens = token_analyzer.tokenize(&chat.message);
+        let tokens = token_analyzer.tokenize_for_channel(&chat.channel_id, &chat.message);
         for token in tokens {
             if token.len() >= 2 {
                 *word_counts.entry(token).or_insert(0) += 1;
@@ -119,6 +184,9 @@ fn create_event_vital(event_logs: &[EventLogResult]) -> EventVital {
     let mut mission_donation_amount = 0u64;
     let mut subscribe_count = 0u32;
     let mut subscribe_renew_count = 0u32;
+    let mut sticker_count = 0u32;
+    let mut sticker_amount = 0u64;
+    let mut gift_count = 0u32;
 
     for event in event_logs {
         match event.event_type.as_str() {
@@ -144,6 +212,15 @@ fn create_event_vital(event_logs: &[EventLogResult]) -> EventVital {
                     }
                 }
             }
+            "Sticker" => {
+                if let Ok(sticker) = serde_json::from_str::<StickerEvent>(&event.payload) {
+                    sticker_count += 1;
+                    sticker_amount += sticker.amount as u64;
+                }
+            }
+            "Gift" if serde_json::from_str::<GiftEvent>(&event.payload).is_ok() => {
+                gift_count += 1;
+            }
             _ => {}
         }
     }
@@ -155,6 +232,9 @@ fn create_event_vital(event_logs: &[EventLogResult]) -> EventVital {
         mission_donation_amount,
         subscribe_count,
         subscribe_renew_count,
+        sticker_count,
+        sticker_amount,
+        gift_count,
     }
 }
 
@@ -163,6 +243,10 @@ fn create_moderation_vital(event_logs: &[EventLogResult]) -> ModerationVital {
     let mut mute_histories: Vec<UserHistory> = Vec::new();
     let mut kick_count: u32 = 0;
     let mut kick_histories: Vec<UserHistory> = Vec::new();
+    let mut kick_cancel_count: u32 = 0;
+    let mut black_count: u32 = 0;
+    let mut freeze_count: u32 = 0;
+    let mut slow_count: u32 = 0;
 
     for event in event_logs {
         match event.event_type.as_str() {
@@ -186,6 +270,22 @@ fn create_moderation_vital(event_logs: &[EventLogResult]) -> ModerationVital {
                     });
                 }
             }
+            "KickCancel" => kick_cancel_count += 1,
+            "Black" => black_count += 1,
+            "Freeze" => {
+                if let Ok(e) = serde_json::from_str::<FreezeEvent>(&event.payload) {
+                    if e.freezed {
+                        freeze_count += 1;
+                    }
+                }
+            }
+            "Slow" => {
+                if let Ok(e) = serde_json::from_str::<SlowEvent>(&event.payload) {
+                    if e.duration > 0 {
+                        slow_count += 1;
+                    }
+                }
+            }
             _ => {}
         }
     }
@@ -195,31 +295,115 @@ fn create_moderation_vital(event_logs: &[EventLogResult]) -> ModerationVital {
         mute_histories,
         kick_count,
         kick_histories,
+        kick_cancel_count,
+        black_count,
+        freeze_count,
+        slow_count,
     }
 }
 
-fn calculate_lol_score(chat_logs: &[ChatLogResult]) -> usize {
-    chat_logs
+/**
+ * 반응 분류별 채팅 수를 셉니다.
+ * 실시간 통계와 같은 규칙으로 BJ/매니저 채팅 제외 여부를 정합니다.
+ */
+fn count_reactions(chat_logs: &[ChatLogResult], rules: &ReactionRules) -> HashMap<String, u32> {
+    let mut counts = HashMap::new();
+
+    for chat in chat_logs
         .iter()
-        .filter(|v| v.message.contains("ㅋ"))
-        .count()
+        .filter(|chat| !rules.should_skip(chat.user.status.is_bj, chat.user.status.is_manager))
+    {
+        for reaction in rules.detect(&chat.message) {
+            *counts.entry(reaction).or_insert(0) += 1;
+        }
+    }
+
+    counts
 }
 
-fn create_chat_vital(chat_logs: &[ChatLogResult], token_analyzer: &TokenAnalyzer) -> ChatVital {
+fn create_chat_vital(
+    chat_logs: &[ChatLogResult],
+    token_analyzer: &TokenAnalyzer,
+    reaction_rules: &ReactionRules,
+) -> ChatVital {
+    let reaction_counts = count_reactions(chat_logs, reaction_rules);
+
     ChatVital {
         total_count: chat_logs.len(),
-        lol_score: calculate_lol_score(chat_logs),
+        // 실시간 LOL 통계와 같이 웃음 반응 규칙으로 셉니다.
+        lol_score: reaction_counts
+            .get(LAUGH_REACTION_ID)
+            .copied()
+            .unwrap_or_default() as usize,
+        reaction_counts,
         top_chatters: create_top_chatters(chat_logs),
         popular_words: create_popular_words(chat_logs, token_analyzer),
     }
 }
 
+/**
+ * 감정 분석 대상 채팅인지 확인합니다.
+ * 실시간 분석과 동일하게 BJ와 매니저 채팅은 제외합니다.
+ */
+pub fn is_sentiment_target(chat: &ChatLogResult) -> bool {
+    !(chat.user.status.is_bj || chat.user.status.is_manager || chat.message.trim().is_empty())
+}
+
+fn create_sentiment_vital(samples: &[SentimentSample]) -> Option<SentimentVital> {
+    if samples.is_empty() {
+        return None;
+    }
+
+    let mut counts = [0u32; 3]; // [positive, negative, neutral]
+    let mut score_sum = 0f64;
+    let mut most_positive: Option<&SentimentSample> = None;
+    let mut most_negative: Option<&SentimentSample> = None;
+
+    for sample in samples {
+        match sample.analysis.sentiment {
+            Sentiment::Positive => counts[0] += 1,
+            Sentiment::Negative => counts[1] += 1,
+            Sentiment::Neutral => counts[2] += 1,
+        }
+        score_sum += sample.analysis.score as f64;
+
+        if most_positive.map_or(true, |s| sample.analysis.score > s.analysis.score) {
+            most_positive = Some(sample);
+        }
+        if most_negative.map_or(true, |s| sample.analysis.score < s.analysis.score) {
+            most_negative = Some(sample);
+        }
+    }
+
+    let total = samples.len() as f64;
+
+    Some(SentimentVital {
+        positive_count: counts[0],
+        negative_count: counts[1],
+        neutral_count: counts[2],
+        positive_ratio: counts[0] as f64 / total,
+        negative_ratio: counts[1] as f64 / total,
+        neutral_ratio: counts[2] as f64 / total,
+        average_score: score_sum / total,
+        // 리포트 생성 시 모든 채팅을 같은 방식으로 분석합니다. (generate_report 참고)
+        method: samples[0].analysis.method,
+        most_positive_message: most_positive
+            .filter(|s| matches!(s.analysis.sentiment, Sentiment::Positive))
+            .map(|s| s.message.clone()),
+        most_negative_message: most_negative
+            .filter(|s| matches!(s.analysis.sentiment, Sentiment::Negative))
+            .map(|s| s.message.clone()),
+    })
+}
+
 pub fn create_report_chunk(
     chunk_index: usize,
     timestamp: DateTime<Utc>,
     chat_logs: &[ChatLogResult],
     event_logs: &[EventLogResult],
     token_analyzer: &TokenAnalyzer,
+    reaction_rules: &ReactionRules,
+    sentiment_samples: Option<&[SentimentSample]>,
 ) -> ReportChunk {
     let viewer_count = extract_viewer_count_from_events(event_logs);
 
@@ -235,10 +419,34 @@ pub fn create_report_chunk(
             )
         },
         user: create_user_vital(chat_logs),
-        chat: create_chat_vital(chat_logs, token_analyzer),
+        chat: create_chat_vital(chat_logs, token_analyzer, reaction_rules),
         event: create_event_vital(event_logs),
         moderation: create_moderation_vital(event_logs),
+        sentiment: sentiment_samples.and_then(create_sentiment_vital),
         viewer_count,
+        active_viewer_count: None,
+    }
+}
+
+/**
+ * 방송 중 저장된 ActiveViewer 스냅샷을 청크에 채워 넣습니다.
+ * 청크 구간에 스냅샷이 여러 개면 가장 큰 값을 사용합니다.
+ */
+pub fn apply_active_viewer_snapshots(
+    chunks: &mut [ReportChunk],
+    snapshots: &[StatsSnapshotResult],
+    chunk_size: u32,
+) {
+    let chunk_duration = chrono::Duration::seconds(chunk_size as i64);
+
+    for chunk in chunks.iter_mut() {
+        let chunk_end = chunk.timestamp + chunk_duration;
+        chunk.active_viewer_count = snapshots
+            .iter()
+            .filter(|s| s.timestamp >= chunk.timestamp && s.timestamp < chunk_end)
+            .filter_map(|s| s.payload.get("total").and_then(|total| total.as_u64()))
+            .max()
+            .map(|total| total as u32);
     }
 }
 
@@ -332,7 +540,142 @@ fn create_event_analysis(
     }
 }
 
-fn create_moderation_analysis(chunks: &[ReportChunk]) -> ModerationAnalysis {
+fn create_donation_analysis(
+    all_event_logs: &[EventLogResult],
+    previous_donor_ids: &HashSet<String>,
+) -> DonationAnalysis {
+    let mut by_type: HashMap<String, (u64, u64)> = HashMap::new(); // donation_type -> (count, amount)
+    let mut sticker_senders: HashSet<String> = HashSet::new();
+    let mut sticker = StickerBreakdown::default();
+    let mut gifts: HashMap<String, u64> = HashMap::new();
+    let mut amount_distribution = create_amount_buckets();
+    let mut donor_amounts: HashMap<String, u64> = HashMap::new();
+    let mut fan_club_joins = Vec::new();
+    let mut donation_messages = Vec::new();
+
+    for event in all_event_logs {
+        match event.event_type.as_str() {
+            "Donation" => {
+                if let Ok(donation) = serde_json::from_str::<DonationEvent>(&event.payload) {
+                    let entry = by_type
+                        .entry(format!("{:?}", donation.donation_type))
+                        .or_insert((0, 0));
+                    entry.0 += 1;
+                    entry.1 += donation.amount as u64;
+
+                    add_to_amount_bucket(&mut amount_distribution, donation.amount);
+                    *donor_amounts.entry(donation.from.clone()).or_insert(0) +=
+                        donation.amount as u64;
+
+                    if donation.fan_club_ordinal > 0 || donation.become_top_fan {
+                        fan_club_joins.push(FanClubJoin {
+                            user_id: donation.from.clone(),
+                            user_label: donation.from_label.clone(),
+                            ordinal: donation.fan_club_ordinal,
+                            become_top_fan: donation.become_top_fan,
+                            timestamp: donation.timestamp,
+                        });
+                    }
+
+                    if let Some(message) = donation.message {
+                        donation_messages.push(DonationMessage {
+                            user_id: donation.from,
+                            user_label: donation.from_label,
+                            amount: donation.amount,
+                            message,
+                            timestamp: donation.timestamp,
+                        });
+                    }
+                }
+            }
+            "MissionDonation" => {
+                if let Ok(mission) = serde_json::from_str::<MissionEvent>(&event.payload) {
+                    add_to_amount_bucket(&mut amount_distribution, mission.amount);
+                    *donor_amounts.entry(mission.from).or_insert(0) += mission.amount as u64;
+                }
+            }
+            "Sticker" => {
+                if let Ok(e) = serde_json::from_str::<StickerEvent>(&event.payload) {
+                    sticker.count += 1;
+                    sticker.amount += e.amount as u64;
+                    sticker_senders.insert(e.from);
+                }
+            }
+            "Gift" => {
+                if let Ok(e) = serde_json::from_str::<GiftEvent>(&event.payload) {
+                    *gifts.entry(format!("{:?}", e.gift_type)).or_insert(0) += 1;
+                }
+            }
+            _ => {}
+        }
+    }
+
+    sticker.unique_sender_count = sticker_senders.len() as u64;
+
+    let mut donor_retention = DonorRetention::default();
+    for (user_id, amount) in donor_amounts {
+        if previous_donor_ids.contains(&user_id) {
+            donor_retention.repeat_count += 1;
+            donor_retention.repeat_amount += amount;
+        } else {
+            donor_retention.first_time_count += 1;
+            donor_retention.first_time_amount += amount;
+        }
+    }
+
+    let mut by_type: Vec<_> = by_type
+        .into_iter()
+        .map(|(donation_type, (count, amount))| DonationTypeBreakdown {
+            donation_type,
+            count,
+            amount,
+        })
+        .collect();
+    by_type.sort_by_key(|b| Reverse(b.amount));
+
+    let mut gifts: Vec<_> = gifts
+        .into_iter()
+        .map(|(gift_type, count)| GiftTypeBreakdown { gift_type, count })
+        .collect();
+    gifts.sort_by_key(|b| Reverse(b.count));
+
+    DonationAnalysis {
+        by_type,
+        sticker,
+        gifts,
+        amount_distribution,
+        donor_retention,
+        fan_club_joins,
+        donation_messages,
+    }
+}
+
+fn create_amount_buckets() -> Vec<AmountBucket> {
+    DONATION_AMOUNT_BUCKETS
+        .iter()
+        .enumerate()
+        .map(|(i, &min)| AmountBucket {
+            min,
+            max: DONATION_AMOUNT_BUCKETS.get(i + 1).map(|next| next - 1),
+            count: 0,
+            amount: 0,
+        })
+        .collect()
+}
+
+fn add_to_amount_bucket(buckets: &mut [AmountBucket], amount: u32) {
+    if let Some(bucket) = buckets.iter_mut().rev().find(|bucket| amount >= bucket.min) {
+        bucket.count += 1;
+        bucket.amount += amount as u64;
+    }
+}
+
+fn create_moderation_analysis(
+    chunks: &[ReportChunk],
+    chat_logs: &[ChatLogResult],
+    event_logs: &[EventLogResult],
+    end_time: DateTime<Utc>,
+) -> ModerationAnalysis {
     let total_mute_count = chunks.iter().map(|c| c.moderation.mute_count).sum();
     let total_mute_histories = chunks.iter().fold(Vec::new(), |mut acc, chunk| {
         acc.extend(chunk.moderation.mute_histories.iter().cloned());
@@ -344,11 +687,230 @@ fn create_moderation_analysis(chunks: &[ReportChunk]) -> ModerationAnalysis {
         acc
     });
 
+    // 사용자별 채팅 (chat_logs는 시간순 정렬)
+    let mut user_chats: HashMap<&str, Vec<&ChatLogResult>> = HashMap::new();
+    for chat in chat_logs {
+        user_chats
+            .entry(chat.user.id.as_str())
+            .or_default()
+            .push(chat);
+    }
+    let user_label = |user_id: &str| -> Option<String> {
+        user_chats
+            .get(user_id)
+            .and_then(|chats| chats.last())
+            .map(|chat| chat.user.label.clone())
+    };
+    let preceding_chats = |user_id: &str, timestamp: DateTime<Utc>| -> Vec<PrecedingChat> {
+        let Some(chats) = user_chats.get(user_id) else {
+            return Vec::new();
+        };
+        let end = chats.partition_point(|chat| chat.timestamp <= timestamp);
+        let start = end.saturating_sub(MODERATION_PRECEDING_CHAT_LIMIT);
+        chats[start..end]
+            .iter()
+            .map(|chat| PrecedingChat {
+                message: chat.message.clone(),
+                timestamp: chat.timestamp,
+            })
+            .collect()
+    };
+
+    let mut stats = ModerationStats::default();
+    let mut black_list: Vec<BlackHistory> = Vec::new();
+    let mut freeze_intervals: Vec<FreezeInterval> = Vec::new();
+    let mut slow_intervals: Vec<SlowInterval> = Vec::new();
+    let mut moderator_map: HashMap<String, ModeratorActionCount> = HashMap::new();
+    let mut offender_map: HashMap<String, RepeatOffender> = HashMap::new();
+    let mut actions: Vec<ModerationAction> = Vec::new();
+
+    let mut open_freeze: Option<FreezeEvent> = None;
+    let mut open_slow: Option<SlowEvent> = None;
+
+    for event in event_logs {
+        let (action_type, user_id, label, by) = match event.event_type.as_str() {
+            "Mute" => {
+                let Ok(e) = serde_json::from_str::<MuteEvent>(&event.payload) else {
+                    continue;
+                };
+                stats.mutes += 1;
+                let moderator =
+                    moderator_map
+                        .entry(e.by.clone())
+                        .or_insert_with(|| ModeratorActionCount {
+                            moderator: e.by.clone(),
+                            mute_count: 0,
+                            total_mute_seconds: 0,
+                        });
+                moderator.mute_count += 1;
+                moderator.total_mute_seconds += e.seconds as u64;
+                ("Mute", e.user.id, Some(e.user.label), Some(e.by))
+            }
+            "Kick" => {
+                let Ok(e) = serde_json::from_str::<UserEvent>(&event.payload) else {
+                    continue;
+                };
+                stats.kicks += 1;
+                ("Kick", e.user.id, Some(e.user.label), None)
+            }
+            "KickCancel" => {
+                let Ok(e) = serde_json::from_str::<SimplifiedUserEvent>(&event.payload) else {
+                    continue;
+                };
+                stats.kick_cancels += 1;
+                let label = user_label(&e.user_id);
+                ("KickCancel", e.user_id, label, None)
+            }
+            "Black" => {
+                let Ok(e) = serde_json::from_str::<SimplifiedUserEvent>(&event.payload) else {
+                    continue;
+                };
+                stats.bans += 1;
+                let label = user_label(&e.user_id);
+                black_list.push(BlackHistory {
+                    user_id: e.user_id.clone(),
+                    user_label: label.clone(),
+                    timestamp: e.timestamp,
+                });
+                ("Black", e.user_id, label, None)
+            }
+            "Freeze" => {
+                let Ok(e) = serde_json::from_str::<FreezeEvent>(&event.payload) else {
+                    continue;
+                };
+                // 설정 변경도 새 구간으로 취급합니다.
+                if let Some(open) = open_freeze.take() {
+                    freeze_intervals.push(create_freeze_interval(
+                        open,
+                        Some(e.timestamp),
+                        end_time,
+                    ));
+                }
+                if e.freezed {
+                    stats.freezes += 1;
+                    open_freeze = Some(e);
+                }
+                continue;
+            }
+            "Slow" => {
+                let Ok(e) = serde_json::from_str::<SlowEvent>(&event.payload) else {
+                    continue;
+                };
+                if let Some(open) = open_slow.take() {
+                    slow_intervals.push(create_slow_interval(open, Some(e.timestamp), end_time));
+                }
+                // duration이 0이면 저속 모드 해제
+                if e.duration > 0 {
+                    stats.slows += 1;
+                    open_slow = Some(e);
+                }
+                continue;
+            }
+            _ => continue,
+        };
+
+        if action_type != "KickCancel" {
+            let offender = offender_map
+                .entry(user_id.clone())
+                .or_insert_with(|| RepeatOffender {
+                    user_id: user_id.clone(),
+                    user_label: label.clone(),
+                    mute_count: 0,
+                    kick_count: 0,
+                    black_count: 0,
+                    total_count: 0,
+                });
+            match action_type {
+                "Mute" => offender.mute_count += 1,
+                "Kick" => offender.kick_count += 1,
+                _ => offender.black_count += 1,
+            }
+            offender.total_count += 1;
+        }
+
+        actions.push(ModerationAction {
+            action_type: action_type.to_string(),
+            preceding_chats: preceding_chats(&user_id, event.timestamp),
+            user_id,
+            user_label: label,
+            by,
+            timestamp: event.timestamp,
+        });
+    }
+
+    // 방송 종료까지 해제되지 않은 구간
+    if let Some(open) = open_freeze {
+        freeze_intervals.push(create_freeze_interval(open, None, end_time));
+    }
+    if let Some(open) = open_slow {
+        slow_intervals.push(create_slow_interval(open, None, end_time));
+    }
+
+    stats.freeze_seconds = freeze_intervals.iter().map(|i| i.duration_seconds).sum();
+    stats.slow_seconds = slow_intervals.iter().map(|i| i.duration_seconds).sum();
+
+    let mut moderator_actions: Vec<ModeratorActionCount> = moderator_map.into_values().collect();
+    moderator_actions.sort_by_key(|m| Reverse(m.mute_count));
+
+    let mut repeat_offenders: Vec<RepeatOffender> = offender_map
+        .into_values()
+        .filter(|o| o.total_count >= 2)
+        .collect();
+    repeat_offenders.sort_by_key(|o| Reverse(o.total_count));
+
     ModerationAnalysis {
         total_mute_count,
         total_mute_histories,
         total_kick_count,
         total_kick_histories,
+        stats,
+        black_list,
+        freeze_intervals,
+        slow_intervals,
+        moderator_actions,
+        repeat_offenders,
+        actions,
+    }
+}
+
+/// 해제 시각이 없으면 방송 종료 시각까지의 시간을 계산합니다.
+fn interval_seconds(
+    started_at: DateTime<Utc>,
+    ended_at: Option<DateTime<Utc>>,
+    end_time: DateTime<Utc>,
+) -> u64 {
+    ended_at
+        .unwrap_or(end_time)
+        .signed_duration_since(started_at)
+        .num_seconds()
+        .max(0) as u64
+}
+
+fn create_freeze_interval(
+    event: FreezeEvent,
+    ended_at: Option<DateTime<Utc>>,
+    end_time: DateTime<Utc>,
+) -> FreezeInterval {
+    FreezeInterval {
+        started_at: event.timestamp,
+        ended_at,
+        duration_seconds: interval_seconds(event.timestamp, ended_at, end_time),
+        limit_subscription_month: event.limit_subscription_month,
+        limit_balloons: event.limit_balloons,
+        targets: event.targets,
+    }
+}
+
+fn create_slow_interval(
+    event: SlowEvent,
+    ended_at: Option<DateTime<Utc>>,
+    end_time: DateTime<Utc>,
+) -> SlowInterval {
+    SlowInterval {
+        started_at: event.timestamp,
+        ended_at,
+        duration_seconds: interval_seconds(event.timestamp, ended_at, end_time),
+        slow_seconds: event.duration,
     }
 }
 
@@ -357,15 +919,37 @@ pub fn create_report_data(
     start_time: DateTime<Utc>,
     end_time: DateTime<Utc>,
     chunk_size: u32,
-    all_chat_logs: &[ChatLogResult],
-    all_event_logs: &[EventLogResult],
+    source: &ReportSource,
     token_analyzer: &TokenAnalyzer,
 ) -> Result<ReportData, String> {
     let duration_seconds = end_time.signed_duration_since(start_time).num_seconds() as u64;
-    let user_analysis = create_user_analysis(&chunks, all_chat_logs);
-    let chat_analysis = create_chat_analysis(&chunks, all_chat_logs, token_analyzer);
-    let event_analysis = create_event_analysis(&chunks, all_event_logs);
-    let moderation_analysis = create_moderation_analysis(&chunks);
+    let user_analysis = create_user_analysis(&chunks, source.chat_logs);
+    let chat_analysis = create_chat_analysis(
+        &chunks,
+        source.chat_logs,
+        token_analyzer,
+        source.reaction_rules,
+    );
+    let event_analysis = create_event_analysis(&chunks, source.event_logs);
+    let donation_analysis = create_donation_analysis(source.event_logs, source.previous_donor_ids);
+    let moderation_analysis =
+        create_moderation_analysis(&chunks, source.chat_logs, source.event_logs, end_time);
+    let sentiment_analysis =
+        create_sentiment_analysis(&chunks, source.sentiment_model_version.as_deref());
+    let emoticon_analysis = create_emoticon_analysis(source.chat_logs, source.emoticon_catalog);
+    let toxicity_analysis = create_toxicity_analysis(
+        source.chat_logs,
+        source.chat_toxicity,
+        source.toxicity_threshold,
+    );
+    let topic_analysis =
+        create_topic_analysis(source.chat_logs, start_time, end_time, token_analyzer);
+    let chat_summary = create_chat_summary(
+        source.chat_logs,
+        &topic_analysis.segments,
+        token_analyzer,
+        source.reaction_rules,
+    );
 
     Ok(ReportData {
         metadata: ReportMetadata {
@@ -377,11 +961,299 @@ pub fn create_report_data(
         user_analysis,
         chat_analysis,
         event_analysis,
+        donation_analysis,
         moderation_analysis,
+        sentiment_analysis,
+        emoticon_analysis,
+        toxicity_analysis,
+        topic_analysis,
+        chat_summary,
         chunks,
     })
 }
 
+/**
+ * 방송 중 저장된 유해 표현 점수로 라벨별 경고 수와 경고 채팅/사용자 순위를 계산합니다.
+ * 경고 기준은 리포트 생성 시점의 설정을 따릅니다.
+ */
+fn create_toxicity_analysis(
+    chat_logs: &[ChatLogResult],
+    chat_toxicity: &[ChatToxicityResult],
+    threshold: f32,
+) -> ToxicityAnalysis {
+    let chats: HashMap<&str, &ChatLogResult> = chat_logs
+        .iter()
+        .map(|chat| (chat.id.as_str(), chat))
+        .collect();
+
+    let mut labels: Vec<ToxicityLabelSummary> = Vec::new();
+    let mut messages: Vec<ToxicMessage> = Vec::new();
+    // 사용자 ID -> 순위 항목
+    let mut users: HashMap<&str, ToxicUserRank> = HashMap::new();
+
+    for toxicity in chat_toxicity {
+        for score in &toxicity.scores {
+            let summary = match labels.iter_mut().position(|l| l.label == score.label) {
+                Some(index) => &mut labels[index],
+                None => {
+                    labels.push(ToxicityLabelSummary {
+                        label: score.label.clone(),
+                        flagged_count: 0,
+                        max_score: 0.0,
+                    });
+                    labels.last_mut().unwrap()
+                }
+            };
+            summary.max_score = summary.max_score.max(score.score);
+            if score.score >= threshold {
+                summary.flagged_count += 1;
+            }
+        }
+
+        if toxicity.max_score < threshold {
+            continue;
+        }
+        let Some(chat) = chats.get(toxicity.chat_log_id.as_str()) else {
+            continue;
+        };
+
+        messages.push(ToxicMessage {
+            user: chat.user.clone(),
+            message: chat.message.clone(),
+            label: toxicity.max_label.clone(),
+            score: toxicity.max_score,
+            timestamp: chat.timestamp,
+        });
+
+        let rank = users
+            .entry(chat.user.id.as_str())
+            .or_insert_with(|| ToxicUserRank {
+                user: chat.user.clone(),
+                flagged_count: 0,
+                max_score: 0.0,
+            });
+        rank.flagged_count += 1;
+        rank.max_score = rank.max_score.max(toxicity.max_score);
+    }
+
+    let flagged_count = messages.len() as u64;
+    messages.sort_by(|a, b| b.score.total_cmp(&a.score));
+    messages.truncate(TOXICITY_RANK_LIMIT);
+
+    let mut top_users: Vec<ToxicUserRank> = users.into_values().collect();
+    top_users.sort_by(|a, b| {
+        b.flagged_count
+            .cmp(&a.flagged_count)
+            .then(b.max_score.total_cmp(&a.max_score))
+    });
+    top_users.truncate(TOXICITY_RANK_LIMIT);
+
+    ToxicityAnalysis {
+        analyzed_count: chat_toxicity.len() as u64,
+        flagged_count,
+        threshold,
+        labels,
+        top_messages: messages,
+        top_users,
+    }
+}
+
+/**
+ * 시그니처 이모티콘과 OGQ 스티커 사용 순위를 계산합니다.
+ * 시그니처 이모티콘은 스트리머 이모티콘 목록에 있는 제목만 집계합니다.
+ */
+fn create_emoticon_analysis(
+    chat_logs: &[ChatLogResult],
+    catalog: Option<&SignatureEmoticonCatalog>,
+) -> EmoticonAnalysis {
+    // 제목 -> (사용 횟수, 사용자)
+    let mut signature_counts: HashMap<String, (u64, HashSet<&str>)> = HashMap::new();
+    // (패키지 id, 번호) -> (스티커 정보, 사용 횟수, 사용자)
+    let mut ogq_counts: HashMap<(&str, &str), (OgqStickerUsage, HashSet<&str>)> = HashMap::new();
+
+    for chat in chat_logs {
+        if let Some(catalog) = catalog {
+            for title in catalog.extract(&chat.message) {
+                let (count, users) = signature_counts.entry(title).or_default();
+                *count += 1;
+                users.insert(chat.user.id.as_str());
+            }
+        }
+
+        if let Some(ogq) = chat.metadata.as_ref().and_then(|m| m.emoticon.as_ref()) {
+            let (usage, users) = ogq_counts
+                .entry((ogq.id.as_str(), ogq.number.as_str()))
+                .or_insert_with(|| {
+                    (
+                        OgqStickerUsage {
+                            id: ogq.id.clone(),
+                            number: ogq.number.clone(),
+                            ext: ogq.ext.clone(),
+                            version: ogq.version.clone(),
+                            image_url: ogq_image_url(&ogq.id, &ogq.number, &ogq.ext, &ogq.version),
+                            count: 0,
+                            user_count: 0,
+                        },
+                        HashSet::new(),
+                    )
+                });
+            usage.count += 1;
+            users.insert(chat.user.id.as_str());
+        }
+    }
+
+    let mut signature_emoticons: Vec<SignatureEmoticonUsage> = Vec::new();
+    let mut unused_signature_emoticons: Vec<SignatureEmoticonUsage> = Vec::new();
+
+    if let Some(catalog) = catalog {
+        for info in catalog.iter() {
+            let (count, user_count) = signature_counts
+                .get(&info.title)
+                .map_or((0, 0), |(count, users)| (*count, users.len() as u64));
+            let usage = SignatureEmoticonUsage {
+                title: info.title.clone(),
+                tier: info.tier,
+                image_url: info.image_url.clone(),
+                count,
+                user_count,
+            };
+
+            if count > 0 {
+                signature_emoticons.push(usage);
+            } else {
+                unused_signature_emoticons.push(usage);
+            }
+        }
+    }
+
+    let total_signature_count = signature_emoticons.iter().map(|e| e.count).sum();
+    signature_emoticons.sort_by_key(|e| Reverse(e.count));
+    signature_emoticons.truncate(EMOTICON_RANK_LIMIT);
+    unused_signature_emoticons.sort_by(|a, b| a.tier.cmp(&b.tier).then(a.title.cmp(&b.title)));
+
+    let mut ogq_stickers: Vec<OgqStickerUsage> = ogq_counts
+        .into_values()
+        .map(|(mut usage, users)| {
+            usage.user_count = users.len() as u64;
+            usage
+        })
+        .collect();
+    let total_ogq_count = ogq_stickers.iter().map(|s| s.count).sum();
+    ogq_stickers.sort_by_key(|s| Reverse(s.count));
+    ogq_stickers.truncate(EMOTICON_RANK_LIMIT);
+
+    EmoticonAnalysis {
+        total_signature_count,
+        total_ogq_count,
+        signature_emoticons,
+        ogq_stickers,
+        unused_signature_emoticons,
+        catalog_loaded: catalog.is_some(),
+    }
+}
+
+fn create_sentiment_analysis(
+    chunks: &[ReportChunk],
+    model_version: Option<&str>,
+) -> Option<SentimentAnalysis> {
+    let analyzed: Vec<(&ReportChunk, &SentimentVital)> = chunks
+        .iter()
+        .filter_map(|chunk| chunk.sentiment.as_ref().map(|s| (chunk, s)))
+        .collect();
+
+    let analyzed_method = match analyzed.first() {
+        Some((_, sentiment)) => sentiment.method,
+        None => return None,
+    };
+
+    let mut counts = [0u32; 3]; // [positive, negative, neutral]
+    let mut score_sum = 0f64;
+
+    for (_, sentiment) in analyzed.iter() {
+        counts[0] += sentiment.positive_count;
+        counts[1] += sentiment.negative_count;
+        counts[2] += sentiment.neutral_count;
+        score_sum += sentiment.average_score * chunk_analyzed_count(sentiment) as f64;
+    }
+
+    let total: u32 = counts.iter().sum();
+    if total == 0 {
+        return None;
+    }
+    let total = total as f64;
+
+    let positive_ratio = counts[0] as f64 / total;
+    let negative_ratio = counts[1] as f64 / total;
+    let neutral_ratio = counts[2] as f64 / total;
+
+    let ratio_diff = positive_ratio - negative_ratio;
+    let overall_sentiment = if ratio_diff > OVERALL_SENTIMENT_MARGIN {
+        Sentiment::Positive
+    } else if ratio_diff < -OVERALL_SENTIMENT_MARGIN {
+        Sentiment::Negative
+    } else {
+        Sentiment::Neutral
+    };
+
+    // 표본이 너무 적은 구간은 대표 구간에서 제외
+    let mut candidates: Vec<_> = analyzed
+        .into_iter()
+        .filter(|(_, s)| chunk_analyzed_count(s) >= MIN_SENTIMENT_MOMENT_COUNT)
+        .collect();
+
+    candidates.sort_by(|a, b| b.1.average_score.total_cmp(&a.1.average_score));
+
+    let most_positive_moments = candidates
+        .iter()
+        .filter(|(_, s)| s.average_score > 0.0)
+        .take(SENTIMENT_MOMENT_LIMIT)
+        .map(|(chunk, s)| create_sentiment_moment(chunk, s, s.most_positive_message.clone()))
+        .collect();
+
+    let most_negative_moments = candidates
+        .iter()
+        .rev()
+        .filter(|(_, s)| s.average_score < 0.0)
+        .take(SENTIMENT_MOMENT_LIMIT)
+        .map(|(chunk, s)| create_sentiment_moment(chunk, s, s.most_negative_message.clone()))
+        .collect();
+
+    Some(SentimentAnalysis {
+        positive_ratio,
+        negative_ratio,
+        neutral_ratio,
+        average_score: score_sum / total,
+        overall_sentiment: format!("{:?}", overall_sentiment),
+        // 리포트 생성 시 모든 채팅을 현재 모델 또는 사전 기반 중 한 가지로만 분석하므로
+        // 첫 구간의 방식을 따릅니다.
+        method: analyzed_method,
+        model_version: match analyzed_method {
+            SentimentMethod::Model => model_version.map(str::to_string),
+            SentimentMethod::Lexicon => None,
+        },
+        most_positive_moments,
+        most_negative_moments,
+    })
+}
+
+fn chunk_analyzed_count(sentiment: &SentimentVital) -> u32 {
+    sentiment.positive_count + sentiment.negative_count + sentiment.neutral_count
+}
+
+fn create_sentiment_moment(
+    chunk: &ReportChunk,
+    sentiment: &SentimentVital,
+    message: Option<String>,
+) -> SentimentMoment {
+    SentimentMoment {
+        timestamp: chunk.timestamp,
+        relative_timestamp: chunk.relative_timestamp.clone(),
+        average_score: sentiment.average_score,
+        analyzed_count: chunk_analyzed_count(sentiment),
+        message,
+    }
+}
+
 fn extract_viewer_count_from_events(event_logs: &[EventLogResult]) -> Option<u64> {
     event_logs
         .iter()
@@ -398,18 +1270,53 @@ fn create_chat_analysis(
     chunks: &[ReportChunk],
     all_chat_logs: &[ChatLogResult],
     token_analyzer: &TokenAnalyzer,
+    reaction_rules: &ReactionRules,
 ) -> ChatAnalysis {
     let total_count = chunks.iter().map(|c| c.chat.total_count as u64).sum();
     let top_chatters = create_top_chatters(all_chat_logs);
     let popular_words = create_popular_words(all_chat_logs, token_analyzer);
+    let reactions = create_reaction_summaries(chunks, reaction_rules);
 
     ChatAnalysis {
         total_count,
         top_chatters,
         popular_words,
+        reactions,
     }
 }
 
+fn create_reaction_summaries(
+    chunks: &[ReportChunk],
+    reaction_rules: &ReactionRules,
+) -> Vec<ReactionSummary> {
+    reaction_rules
+        .enabled_categories()
+        .map(|category| {
+            let counts = chunks.iter().map(|chunk| {
+                let count = chunk
+                    .chat
+                    .reaction_counts
+                    .get(&category.id)
+                    .copied()
+                    .unwrap_or(0);
+                (chunk.timestamp, count)
+            });
+            let total_count = counts.clone().map(|(_, count)| count).sum();
+            let peak = counts
+                .filter(|(_, count)| *count > 0)
+                .max_by_key(|(_, count)| *count);
+
+            ReactionSummary {
+                id: category.id.clone(),
+                label: category.label.clone(),
+                total_count,
+                peak_count: peak.map_or(0, |(_, count)| count),
+                peak_timestamp: peak.map(|(timestamp, _)| timestamp),
+            }
+        })
+        .collect()
+}
+
 fn create_user_analysis(chunks: &[ReportChunk], chat_logs: &[ChatLogResult]) -> UserAnalysis {
     let overall = get_user_counts(chat_logs);
 
@@ -0,0 +1,294 @@
+use chrono::Duration;
+use std::cmp::Reverse;
+use std::collections::{HashMap, HashSet};
+
+use crate::{
+    models::reports::{ChatSummary, ChatSummarySegment, SummaryMessage, TopicSegment},
+    services::{
+        addons::data_enrichment::{reaction_rules::ReactionRules, token_analyzer::TokenAnalyzer},
+        db::commands::ChatLogResult,
+    },
+};
+
+/// 구간마다 고를 대표 채팅 수
+const SUMMARY_MESSAGE_LIMIT: usize = 3;
+
+/// 대표 채팅 후보가 되기 위한 최소 명사 수 (짧은 반응 채팅 제외)
+const MIN_SUMMARY_TERM_COUNT: usize = 2;
+
+/// 채팅 후 반응을 세는 시간 (초)
+const REACTION_WINDOW_SECONDS: i64 = 15;
+
+/// 반응 수가 점수에 미치는 정도
+const REACTION_WEIGHT: f64 = 0.5;
+
+/// 구간 후보의 이 비율 이상에 나오는 단어는 겹침 계산에서 제외합니다. (모든 채팅이 연결되는 것을 방지)
+const COMMON_TERM_RATIO: f64 = 0.5;
+
+/// 중심성을 계산할 최대 후보 수 (넘으면 보낸 사용자와 반응이 많은 후보만 남깁니다)
+const MAX_SUMMARY_CANDIDATES: usize = 2000;
+
+/// 이미 고른 대표 채팅과 단어가 이만큼 겹치면(Jaccard) 같은 내용으로 보고 건너뜁니다.
+const REDUNDANT_SIMILARITY: f64 = 0.5;
+
+/// 같은 내용의 채팅을 하나로 묶은 대표 채팅 후보
+struct Candidate<'a> {
+    chat: &'a ChatLogResult,
+    terms: HashSet<String>,
+    users: HashSet<&'a str>,
+    reaction_count: u32,
+}
+
+/**
+ * 주제 구간마다 대표 채팅을 뽑는 추출 요약입니다.
+ * 도배를 정규화된 내용 기준으로 합친 뒤, 구간 내 다른 채팅과 명사가 많이 겹치는(중심성이 높은) 채팅에
+ * 뒤따른 반응 수를 가중치로 곱해 점수를 매깁니다. 외부 모델이나 네트워크 없이 동작합니다.
+ */
+pub fn create_chat_summary(
+    chat_logs: &[ChatLogResult],
+    segments: &[TopicSegment],
+    token_analyzer: &TokenAnalyzer,
+    reaction_rules: &ReactionRules,
+) -> ChatSummary {
+    let is_reaction: Vec<bool> = chat_logs
+        .iter()
+        .map(|chat| {
+            !reaction_rules.should_skip(chat.user.status.is_bj, chat.user.status.is_manager)
+                && !reaction_rules.detect(&chat.message).is_empty()
+        })
+        .collect();
+
+    let segments = segments
+        .iter()
+        .filter_map(|segment| {
+            let start = chat_logs.partition_point(|chat| chat.timestamp < segment.start_time);
+            let end = chat_logs.partition_point(|chat| chat.timestamp < segment.end_time);
+
+            let messages = summarize_range(chat_logs, start..end, &is_reaction, token_analyzer);
+            if messages.is_empty() {
+                return None;
+            }
+
+            Some(ChatSummarySegment {
+                start_time: segment.start_time,
+                end_time: segment.end_time,
+                start_offset_seconds: segment.start_offset_seconds,
+                end_offset_seconds: segment.end_offset_seconds,
+                chat_count: (end - start) as u32,
+                messages,
+            })
+        })
+        .collect();
+
+    ChatSummary { segments }
+}
+
+fn summarize_range(
+    chat_logs: &[ChatLogResult],
+    range: std::ops::Range<usize>,
+    is_reaction: &[bool],
+    token_analyzer: &TokenAnalyzer,
+) -> Vec<SummaryMessage> {
+    let mut candidates: Vec<Candidate> = Vec::new();
+    // 정규화된 내용 -> 후보 인덱스
+    let mut by_content: HashMap<String, usize> = HashMap::new();
+
+    for index in range {
+        let chat = &chat_logs[index];
+        if chat.user.status.is_bj || chat.user.status.is_manager {
+            continue;
+        }
+
+        let terms: HashSet<String> = token_analyzer
+            .tokenize_for_channel(&chat.channel_id, &chat.message)
+            .into_iter()
+            .filter(|token| token.chars().count() >= 2)
+            .collect();
+        if terms.len() < MIN_SUMMARY_TERM_COUNT {
+            continue;
+        }
+
+        let reaction_count = count_following_reactions(chat_logs, index, is_reaction);
+        let key = normalize_message(&chat.message);
+        match by_content.get(&key) {
+            Some(&existing) => {
+                let candidate = &mut candidates[existing];
+                candidate.users.insert(chat.user.id.as_str());
+                candidate.reaction_count = candidate.reaction_count.max(reaction_count);
+            }
+            None => {
+                by_content.insert(key, candidates.len());
+                candidates.push(Candidate {
+                    chat,
+                    terms,
+                    users: HashSet::from([chat.user.id.as_str()]),
+                    reaction_count,
+                });
+            }
+        }
+    }
+
+    if candidates.is_empty() {
+        return Vec::new();
+    }
+    if candidates.len() > MAX_SUMMARY_CANDIDATES {
+        candidates
+            .sort_by_key(|candidate| Reverse((candidate.users.len(), candidate.reaction_count)));
+        candidates.truncate(MAX_SUMMARY_CANDIDATES);
+    }
+
+    let centrality = calculate_centrality(&candidates);
+    let max_centrality = centrality.iter().copied().fold(0.0, f64::max);
+
+    let mut scored: Vec<(usize, f64)> = centrality
+        .iter()
+        .enumerate()
+        .map(|(index, value)| {
+            // 겹치는 채팅이 없는 구간은 반응 수로만 고릅니다.
+            let base = if max_centrality > 0.0 {
+                value / max_centrality
+            } else {
+                1.0
+            };
+            let reactions = candidates[index].reaction_count as f64;
+            (
+                index,
+                base * (1.0 + REACTION_WEIGHT * (1.0 + reactions).ln()),
+            )
+        })
+        .collect();
+    scored.sort_by(|a, b| {
+        b.1.total_cmp(&a.1).then_with(|| {
+            candidates[a.0]
+                .chat
+                .timestamp
+                .cmp(&candidates[b.0].chat.timestamp)
+        })
+    });
+
+    let max_score = scored.first().map(|(_, score)| *score).unwrap_or(1.0);
+    let mut selected: Vec<(usize, f64)> = Vec::new();
+    for (index, score) in scored {
+        if selected.len() >= SUMMARY_MESSAGE_LIMIT {
+            break;
+        }
+        let is_redundant = selected.iter().any(|&(other, _)| {
+            jaccard(&candidates[index].terms, &candidates[other].terms) >= REDUNDANT_SIMILARITY
+        });
+        if !is_redundant {
+            selected.push((index, score));
+        }
+    }
+
+    selected
+        .into_iter()
+        .map(|(index, score)| {
+            let candidate = &candidates[index];
+            SummaryMessage {
+                user: candidate.chat.user.clone(),
+                message: candidate.chat.message.clone(),
+                timestamp: candidate.chat.timestamp,
+                score: ((score / max_score) * 1000.0).round() / 1000.0,
+                user_count: candidate.users.len() as u32,
+                reaction_count: candidate.reaction_count,
+            }
+        })
+        .collect()
+}
+
+/**
+ * 후보마다 다른 후보와의 명사 겹침(TextRank 유사도)을 상대 후보의 사용자 수만큼 더한 값입니다.
+ * 같은 내용을 여러 사용자가 보냈다면 그만큼 중심성이 높아집니다.
+ */
+fn calculate_centrality(candidates: &[Candidate]) -> Vec<f64> {
+    let mut document_frequency: HashMap<&str, usize> = HashMap::new();
+    for candidate in candidates {
+        for term in &candidate.terms {
+            *document_frequency.entry(term.as_str()).or_insert(0) += 1;
+        }
+    }
+
+    let common_limit = ((candidates.len() as f64 * COMMON_TERM_RATIO) as usize).max(2);
+    let mut index: HashMap<&str, Vec<usize>> = HashMap::new();
+    for (position, candidate) in candidates.iter().enumerate() {
+        for term in &candidate.terms {
+            if document_frequency[term.as_str()] <= common_limit {
+                index.entry(term.as_str()).or_default().push(position);
+            }
+        }
+    }
+
+    candidates
+        .iter()
+        .enumerate()
+        .map(|(position, candidate)| {
+            let mut shared: HashMap<usize, u32> = HashMap::new();
+            for term in &candidate.terms {
+                for &other in index.get(term.as_str()).into_iter().flatten() {
+                    if other != position {
+                        *shared.entry(other).or_insert(0) += 1;
+                    }
+                }
+            }
+
+            let size = (1.0 + candidate.terms.len() as f64).ln();
+            let similarity: f64 = shared
+                .into_iter()
+                .map(|(other, count)| {
+                    let other = &candidates[other];
+                    let other_size = (1.0 + other.terms.len() as f64).ln();
+                    count as f64 / (size + other_size) * other.users.len() as f64
+                })
+                .sum();
+
+            similarity + (candidate.users.len() - 1) as f64
+        })
+        .collect()
+}
+
+/// 채팅 후 REACTION_WINDOW_SECONDS 안에 다른 사용자가 보낸 반응 채팅 수를 셉니다.
+fn count_following_reactions(
+    chat_logs: &[ChatLogResult],
+    index: usize,
+    is_reaction: &[bool],
+) -> u32 {
+    let chat = &chat_logs[index];
+    let window_end = chat.timestamp + Duration::seconds(REACTION_WINDOW_SECONDS);
+
+    chat_logs[index + 1..]
+        .iter()
+        .zip(&is_reaction[index + 1..])
+        .take_while(|(next, _)| next.timestamp <= window_end)
+        .filter(|(next, is_reaction)| **is_reaction && next.user.id != chat.user.id)
+        .count() as u32
+}
+
+/// 도배 판단용 정규화: 공백과 기호를 없애고 같은 글자가 3번 이상 반복되면 2번으로 줄입니다.
+fn normalize_message(message: &str) -> String {
+    let mut normalized = String::with_capacity(message.len());
+    let mut last: Option<char> = None;
+    let mut repeat = 0;
+
+    for ch in message.chars().filter(|ch| ch.is_alphanumeric()) {
+        let ch = ch.to_lowercase().next().unwrap_or(ch);
+        if last == Some(ch) {
+            repeat += 1;
+        } else {
+            last = Some(ch);
+            repeat = 1;
+        }
+        if repeat <= 2 {
+            normalized.push(ch);
+        }
+    }
+
+    normalized
+}
+
+fn jaccard(a: &HashSet<String>, b: &HashSet<String>) -> f64 {
+    let union = a.union(b).count();
+    if union == 0 {
+        return 0.0;
+    }
+    a.intersection(b).count() as f64 / union as f64
+}
@@ -0,0 +1,262 @@
+use chrono::{DateTime, Duration, Utc};
+use std::collections::{HashMap, HashSet};
+
+use crate::{
+    models::reports::{TopicAnalysis, TopicSegment, TopicTerm},
+    services::{
+        addons::data_enrichment::token_analyzer::TokenAnalyzer, db::commands::ChatLogResult,
+    },
+};
+
+/// 주제를 나누는 최소 단위 구간 (5분)
+pub const TOPIC_WINDOW_SECONDS: u32 = 300;
+
+/// 채팅이 이보다 적은 구간은 주제를 판단하지 않고 이웃 구간에 합칩니다.
+const MIN_SEGMENT_CHAT_COUNT: u32 = 20;
+
+/// 인접 구간의 유사도가 이 값 이상이면 같은 주제로 보고 합칩니다.
+const SEGMENT_MERGE_SIMILARITY: f64 = 0.3;
+
+/// 리포트에 남길 최대 주제 구간 수 (넘으면 유사도가 낮아도 가장 비슷한 구간부터 합칩니다)
+const MAX_TOPIC_SEGMENTS: usize = 12;
+
+/// 떨어진 구간끼리 유사도가 이 값 이상이면 같은 주제 번호를 붙입니다.
+const SAME_TOPIC_SIMILARITY: f64 = 0.5;
+
+/// 구간마다 보여줄 핵심 단어 수
+const KEY_TERM_LIMIT: usize = 5;
+
+/// 핵심 단어로 인정하기 위한 최소 채팅 수
+const MIN_KEY_TERM_COUNT: u32 = 2;
+
+/// 합쳐지는 중인 주제 구간 (window 범위와 단어별 채팅 수)
+struct Segment {
+    start_window: usize,
+    end_window: usize,
+    chat_count: u32,
+    term_counts: HashMap<String, u32>,
+    vector: HashMap<String, f64>,
+}
+
+impl Segment {
+    fn absorb(&mut self, other: Segment) {
+        self.start_window = self.start_window.min(other.start_window);
+        self.end_window = self.end_window.max(other.end_window);
+        self.chat_count += other.chat_count;
+        for (term, count) in other.term_counts {
+            *self.term_counts.entry(term).or_insert(0) += count;
+        }
+    }
+}
+
+/**
+ * 채팅 명사를 TF-IDF로 가중치를 매겨 방송을 주제 구간으로 나눕니다.
+ * 5분 단위 구간에서 시작해 인접 구간 중 가장 비슷한 쌍을 반복해서 합치고(시간 순서를 유지하는 병합 군집화),
+ * 합쳐진 구간끼리 다시 비교해 같은 주제로 돌아온 구간에는 같은 번호를 붙입니다.
+ */
+pub fn create_topic_analysis(
+    chat_logs: &[ChatLogResult],
+    start_time: DateTime<Utc>,
+    end_time: DateTime<Utc>,
+    token_analyzer: &TokenAnalyzer,
+) -> TopicAnalysis {
+    let duration_seconds = end_time
+        .signed_duration_since(start_time)
+        .num_seconds()
+        .max(0);
+    let window_count = (duration_seconds as f64 / TOPIC_WINDOW_SECONDS as f64).ceil() as usize;
+
+    if chat_logs.is_empty() || window_count == 0 {
+        return TopicAnalysis {
+            window_seconds: TOPIC_WINDOW_SECONDS,
+            segments: Vec::new(),
+        };
+    }
+
+    let mut segments: Vec<Segment> = (0..window_count)
+        .map(|window| Segment {
+            start_window: window,
+            end_window: window,
+            chat_count: 0,
+            term_counts: HashMap::new(),
+            vector: HashMap::new(),
+        })
+        .collect();
+
+    for chat in chat_logs {
+        let offset = chat
+            .timestamp
+            .signed_duration_since(start_time)
+            .num_seconds();
+        let window = (offset.max(0) as usize / TOPIC_WINDOW_SECONDS as usize).min(window_count - 1);
+        let segment = &mut segments[window];
+        segment.chat_count += 1;
+
+        // 같은 단어를 반복한 채팅은 한 번만 셉니다.
+        let terms: HashSet<String> = token_analyzer
+            .tokenize_for_channel(&chat.channel_id, &chat.message)
+            .into_iter()
+            .filter(|token| token.chars().count() >= 2)
+            .collect();
+        for term in terms {
+            *segment.term_counts.entry(term).or_insert(0) += 1;
+        }
+    }
+
+    // 문서 빈도는 5분 구간 기준으로 계산해 방송 내내 나오는 단어의 가중치를 낮춥니다.
+    let idf = inverse_document_frequency(&segments);
+    for segment in &mut segments {
+        segment.vector = tf_idf_vector(&segment.term_counts, &idf);
+    }
+
+    merge_adjacent_segments(&mut segments, &idf);
+
+    let mut topic_vectors: Vec<&HashMap<String, f64>> = Vec::new();
+    let topic_segments = segments
+        .iter()
+        .map(|segment| {
+            let topic_id = match topic_vectors.iter().position(|vector| {
+                cosine_similarity(vector, &segment.vector) >= SAME_TOPIC_SIMILARITY
+            }) {
+                Some(index) => index,
+                None => {
+                    topic_vectors.push(&segment.vector);
+                    topic_vectors.len() - 1
+                }
+            };
+
+            let start_offset_seconds = segment.start_window as u64 * TOPIC_WINDOW_SECONDS as u64;
+            let end_offset_seconds = ((segment.end_window + 1) as u64
+                * TOPIC_WINDOW_SECONDS as u64)
+                .min(duration_seconds as u64);
+
+            TopicSegment {
+                topic_id: topic_id as u32,
+                start_time: start_time + Duration::seconds(start_offset_seconds as i64),
+                end_time: start_time + Duration::seconds(end_offset_seconds as i64),
+                start_offset_seconds,
+                end_offset_seconds,
+                chat_count: segment.chat_count,
+                key_terms: create_key_terms(segment),
+            }
+        })
+        .collect();
+
+    TopicAnalysis {
+        window_seconds: TOPIC_WINDOW_SECONDS,
+        segments: topic_segments,
+    }
+}
+
+/**
+ * 인접 구간 중 유사도가 가장 높은 쌍을 합치는 것을 반복합니다.
+ * 채팅이 적은 구간은 유사도와 관계없이 먼저 합치고,
+ * 남은 쌍의 유사도가 기준 미만이고 구간 수가 최대 개수 이하이면 멈춥니다.
+ */
+fn merge_adjacent_segments(segments: &mut Vec<Segment>, idf: &HashMap<String, f64>) {
+    while segments.len() > 1 {
+        let Some((index, similarity)) = segments
+            .windows(2)
+            .map(|pair| {
+                if pair[0].chat_count < MIN_SEGMENT_CHAT_COUNT
+                    || pair[1].chat_count < MIN_SEGMENT_CHAT_COUNT
+                {
+                    // 채팅이 적은 구간끼리 먼저 합쳐지도록 채팅 수가 적을수록 높은 값을 줍니다.
+                    let chat_count = pair[0].chat_count.min(pair[1].chat_count);
+                    (2.0 - chat_count as f64 / MIN_SEGMENT_CHAT_COUNT as f64).max(1.0)
+                } else {
+                    cosine_similarity(&pair[0].vector, &pair[1].vector)
+                }
+            })
+            .enumerate()
+            .max_by(|a, b| a.1.total_cmp(&b.1))
+        else {
+            break;
+        };
+
+        if similarity < SEGMENT_MERGE_SIMILARITY && segments.len() <= MAX_TOPIC_SEGMENTS {
+            break;
+        }
+
+        let next = segments.remove(index + 1);
+        let segment = &mut segments[index];
+        segment.absorb(next);
+        segment.vector = tf_idf_vector(&segment.term_counts, idf);
+    }
+}
+
+fn inverse_document_frequency(segments: &[Segment]) -> HashMap<String, f64> {
+    let mut document_frequency: HashMap<&str, u32> = HashMap::new();
+    for segment in segments {
+        for term in segment.term_counts.keys() {
+            *document_frequency.entry(term.as_str()).or_insert(0) += 1;
+        }
+    }
+
+    let document_count = segments.len() as f64;
+    document_frequency
+        .into_iter()
+        .map(|(term, frequency)| {
+            let idf = ((document_count + 1.0) / (frequency as f64 + 1.0)).ln() + 1.0;
+            (term.to_string(), idf)
+        })
+        .collect()
+}
+
+/// 로그 스케일 단어 빈도와 IDF를 곱한 뒤 길이 1로 정규화한 벡터
+fn tf_idf_vector(
+    term_counts: &HashMap<String, u32>,
+    idf: &HashMap<String, f64>,
+) -> HashMap<String, f64> {
+    let mut vector: HashMap<String, f64> = term_counts
+        .iter()
+        .map(|(term, count)| {
+            let weight = (1.0 + *count as f64).ln() * idf.get(term).copied().unwrap_or(1.0);
+            (term.clone(), weight)
+        })
+        .collect();
+
+    let norm = vector
+        .values()
+        .map(|weight| weight * weight)
+        .sum::<f64>()
+        .sqrt();
+    if norm > 0.0 {
+        for weight in vector.values_mut() {
+            *weight /= norm;
+        }
+    }
+
+    vector
+}
+
+fn cosine_similarity(a: &HashMap<String, f64>, b: &HashMap<String, f64>) -> f64 {
+    let (small, large) = if a.len() <= b.len() { (a, b) } else { (b, a) };
+    small
+        .iter()
+        .filter_map(|(term, weight)| large.get(term).map(|other| weight * other))
+        .sum()
+}
+
+fn create_key_terms(segment: &Segment) -> Vec<TopicTerm> {
+    let mut terms: Vec<TopicTerm> = segment
+        .vector
+        .iter()
+        .filter_map(|(word, score)| {
+            let count = segment.term_counts.get(word).copied().unwrap_or(0);
+            (count >= MIN_KEY_TERM_COUNT).then(|| TopicTerm {
+                word: word.clone(),
+                score: (score * 1000.0).round() / 1000.0,
+                count,
+            })
+        })
+        .collect();
+
+    terms.sort_by(|a, b| {
+        b.score
+            .total_cmp(&a.score)
+            .then_with(|| a.word.cmp(&b.word))
+    });
+    terms.truncate(KEY_TERM_LIMIT);
+    terms
+}